@@ -1,15 +1,29 @@
-use std::path::PathBuf;
+use std::{
+    path::PathBuf,
+    sync::{Mutex, OnceLock},
+    time::{Duration, Instant},
+};
 
+use regex::Regex;
 use thiserror::Error;
 
 use crate::{Answer, Day, Part, Year};
 
+static RE_PUZZLE_TITLE: OnceLock<Regex> = OnceLock::new();
+
 #[derive(Debug, Error)]
 pub enum ServiceError {
     #[error("HTTP {}", .0)]
     HttpStatusError(u16),
+    /// The service returned HTTP 503, which usually means it is too busy to handle requests.
+    #[error("the Advent of Code service is temporarily unavailable")]
+    ServiceUnavailable,
     #[error("{}", .0)]
     ReqwestError(#[from] reqwest::Error),
+    #[error("the puzzle page did not contain a puzzle title")]
+    PuzzleTitleNotFound,
+    #[error("{}", .0)]
+    ProxyConfigError(String),
 }
 
 /// Abstraction of the communication protocol used to communicate with the
@@ -24,23 +38,136 @@ pub trait ServiceConnector {
         year: Year,
         session: &str,
     ) -> Result<String, ServiceError>;
+    /// Get the HTML of the puzzle description page for the given day and year.
+    fn get_puzzle_page(&self, day: Day, year: Year, session: &str) -> Result<String, ServiceError>;
+    /// Get the title of the puzzle for the given day and year, without the
+    /// `--- Day N: ` prefix and ` ---` suffix shown on the puzzle page.
+    fn get_puzzle_title(
+        &self,
+        day: Day,
+        year: Year,
+        session: &str,
+    ) -> Result<String, ServiceError> {
+        parse_puzzle_title(&self.get_puzzle_page(day, year, session)?)
+            .ok_or(ServiceError::PuzzleTitleNotFound)
+    }
+}
+
+/// Extracts the puzzle title from the first `<h2>` heading in a puzzle page.
+/// The heading is formatted as `--- Day N: Title ---`, and only the `Title`
+/// part is returned.
+pub fn parse_puzzle_title(html: &str) -> Option<String> {
+    let regex = RE_PUZZLE_TITLE.get_or_init(|| {
+        Regex::new(r"<h2[^>]*>\s*---\s*Day\s+\d+:\s*(.*?)\s*---\s*</h2>")
+            .expect("puzzle title regex failed to compile")
+    });
+
+    regex
+        .captures(html)
+        .map(|c| c.get(1).unwrap().as_str().to_string())
+}
+
+/// Returns the text to write to the server response log for a puzzle input
+/// response. Puzzle input is only stored in the encrypted cache, so successful
+/// responses are logged as their status and length. Any other response is an
+/// error page and is logged as is to help diagnose the failure.
+fn input_response_log(status: reqwest::StatusCode, text: &str) -> String {
+    if status == reqwest::StatusCode::OK {
+        format!(
+            "HTTP {status}, {} bytes of puzzle input not logged",
+            text.len()
+        )
+    } else {
+        text.to_string()
+    }
+}
+
+/// Enforces a minimum amount of time between requests by blocking the calling
+/// thread until enough time has passed since the previous request.
+#[derive(Debug)]
+pub struct RateLimiter {
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
 }
 
+impl RateLimiter {
+    pub fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// Sleeps until at least `min_interval` has passed since the last call to
+    /// `wait`, and then records the current time as the last request.
+    pub fn wait(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+
+        if let Some(last_request) = *last_request {
+            let elapsed = last_request.elapsed();
+
+            if elapsed < self.min_interval {
+                let delay = self.min_interval - elapsed;
+                tracing::debug!("waiting {delay:?} before sending the next request");
+                std::thread::sleep(delay);
+            }
+        }
+
+        *last_request = Some(Instant::now());
+    }
+}
+
+/// The `User-Agent` header sent to the Advent of Code service when no custom user agent is set.
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    "advent-of-code-data/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/smacdo/advent-of-code-rust; dev@smacdo.com)"
+);
+
 #[derive(Debug)]
 pub struct AdventOfCodeService {
     pub log_dir: Option<PathBuf>,
+    /// URL of the HTTP proxy that all requests are sent through, if any.
+    pub proxy_url: Option<String>,
+    /// The `User-Agent` header sent with every request, or `DEFAULT_USER_AGENT` when `None`.
+    pub user_agent: Option<String>,
+    rate_limiter: RateLimiter,
 }
 
 impl AdventOfCodeService {
     const ADVENT_OF_CODE_DOMAIN: &'static str = "adventofcode.com";
     const ADVENT_OF_CODE_URL: &'static str = "https://adventofcode.com";
 
+    /// Creates a service that writes server responses to `log_dir` when set,
+    /// and waits at least `min_request_interval` between HTTP requests.
+    pub fn new(log_dir: Option<PathBuf>, min_request_interval: Duration) -> Self {
+        Self {
+            log_dir,
+            proxy_url: None,
+            user_agent: None,
+            rate_limiter: RateLimiter::new(min_request_interval),
+        }
+    }
+
+    /// Send all requests through the HTTP proxy at `proxy_url`. An invalid URL
+    /// is reported as `ServiceError::ProxyConfigError` when making a request.
+    pub fn with_proxy<S: Into<String>>(mut self, proxy_url: S) -> Self {
+        self.proxy_url = Some(proxy_url.into());
+        self
+    }
+
+    /// Send `user_agent` as the `User-Agent` header of every request. The Advent of Code
+    /// maintainers ask that automated tools include contact information in their user agent.
+    pub fn with_user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
     fn create_http_client(
         &self,
         session: Option<&str>,
     ) -> Result<reqwest::blocking::Client, ServiceError> {
         // Create an HTTP client for interacting with the Advent of Code website.
-        // TODO: verify dev@smacdo.com email OK
         let cookies: reqwest::cookie::Jar = Default::default();
 
         if let Some(session) = session {
@@ -58,34 +185,68 @@ impl AdventOfCodeService {
             );
         }
 
-        Ok(reqwest::blocking::ClientBuilder::new()
+        let mut builder = reqwest::blocking::ClientBuilder::new()
             .cookie_provider(cookies.into())
-            .user_agent("github.com/smacdo/advent-of-code-rust [email: dev@smacdo.com]")
-            .build()?)
+            .user_agent(self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT));
+
+        if let Some(proxy_url) = &self.proxy_url {
+            tracing::debug!("sending requests through proxy `{proxy_url}`");
+
+            let proxy = reqwest::Proxy::all(proxy_url).map_err(|e| {
+                ServiceError::ProxyConfigError(format!("invalid proxy url `{proxy_url}`: {e}"))
+            })?;
+
+            builder = builder.proxy(proxy);
+        }
+
+        Ok(builder.build()?)
     }
 
-    /// Write a server response to the given directory for debugging purposes.
-    pub fn log_server_response(&self, status: u16, text: &str) {
+    /// Write a server response to the log directory for debugging purposes.
+    /// `request` names the kind of request that was made (eg "submit"), and is
+    /// used as the prefix of the log file name.
+    ///
+    /// Logging is skipped when the service has no log directory, and errors
+    /// writing the log are reported as warnings rather than returned.
+    pub fn log_server_response(&self, request: &str, text: &str) {
         if let Some(log_dir) = &self.log_dir {
             // Create log directory if it doesn't exist before writing.
-            if !log_dir.exists() {
-                std::fs::create_dir_all(log_dir).unwrap_or_else(|e| {
-                    tracing::error!("error create server response log dir: {e}")
-                });
+            if let Err(e) = std::fs::create_dir_all(log_dir) {
+                tracing::warn!("failed to create server response log dir: {e}");
+                return;
             }
 
-            // Write to a file that has a unique name taken from the current time.
-            // Also add the HTTP status code as a suffix to the file name.
+            // Write to a file named after the current time. A counter is added to the name when
+            // another response was logged at the same time so that earlier logs are never
+            // overwritten.
             let now: chrono::DateTime<chrono::Local> = chrono::Local::now();
-            let log_filename = format!("{}_{}.html", now.format("%Y_%m_%d-%H_%M_%S"), status);
+            let file_stem = format!("{request}_{}", now.format("%Y_%m_%d_%H%M%S_%6f"));
 
-            tracing::debug!(
-                "logging server response to: {}",
-                log_dir.join(&log_filename).to_string_lossy()
-            );
+            for attempt in 0.. {
+                let log_path = match attempt {
+                    0 => log_dir.join(format!("{file_stem}.html")),
+                    n => log_dir.join(format!("{file_stem}_{n}.html")),
+                };
+
+                let result = std::fs::OpenOptions::new()
+                    .write(true)
+                    .create_new(true)
+                    .open(&log_path)
+                    .and_then(|mut file| std::io::Write::write_all(&mut file, text.as_bytes()));
 
-            std::fs::write(log_dir.join(log_filename), text)
-                .unwrap_or_else(|e| tracing::error!("error when logging server response: {e}"));
+                match result {
+                    Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                    Err(e) => tracing::warn!(
+                        "failed to log server response to {}: {e}",
+                        log_path.to_string_lossy()
+                    ),
+                    Ok(()) => {
+                        tracing::debug!("logged server response to: {}", log_path.to_string_lossy())
+                    }
+                }
+
+                break;
+            }
         }
     }
 }
@@ -101,13 +262,45 @@ impl ServiceConnector for AdventOfCodeService {
             url
         );
 
+        self.rate_limiter.wait();
+
         let response = self.create_http_client(Some(session))?.get(url).send()?;
-        tracing::debug!("server responed with HTTP {}", response.status());
+        let status = response.status();
+        tracing::debug!("server responed with HTTP {}", status);
+
+        let text = response.text()?;
+        self.log_server_response("input", &input_response_log(status, &text));
 
-        if response.status() == reqwest::StatusCode::OK {
-            Ok(response.text().unwrap()) // TODO: Handle this!
-        } else {
-            Err(ServiceError::HttpStatusError(response.status().as_u16()))
+        match status {
+            reqwest::StatusCode::OK => Ok(text),
+            reqwest::StatusCode::SERVICE_UNAVAILABLE => Err(ServiceError::ServiceUnavailable),
+            _ => Err(ServiceError::HttpStatusError(status.as_u16())),
+        }
+    }
+
+    fn get_puzzle_page(&self, day: Day, year: Year, session: &str) -> Result<String, ServiceError> {
+        let url = format!("{}/{}/day/{}", Self::ADVENT_OF_CODE_URL, year, day);
+
+        tracing::debug!(
+            "url to get puzzle page for day {} year {} is `{}`",
+            day,
+            year,
+            url
+        );
+
+        self.rate_limiter.wait();
+
+        let response = self.create_http_client(Some(session))?.get(url).send()?;
+        let status = response.status();
+        tracing::debug!("server responed with HTTP {}", status);
+
+        let text = response.text()?;
+        self.log_server_response("puzzle", &text);
+
+        match status {
+            reqwest::StatusCode::OK => Ok(text),
+            reqwest::StatusCode::SERVICE_UNAVAILABLE => Err(ServiceError::ServiceUnavailable),
+            _ => Err(ServiceError::HttpStatusError(status.as_u16())),
         }
     }
 
@@ -131,18 +324,13 @@ impl ServiceConnector for AdventOfCodeService {
             url
         );
 
+        self.rate_limiter.wait();
+
         let response = self
             .create_http_client(Some(session))?
             .post(url)
             .form(&[
-                (
-                    "level",
-                    if part == Part::One {
-                        "1".to_string()
-                    } else {
-                        "2".to_string()
-                    },
-                ),
+                ("level", part.as_number().to_string()),
                 ("answer", answer.to_string()),
             ])
             .send()?;
@@ -154,14 +342,227 @@ impl ServiceConnector for AdventOfCodeService {
         });
 
         tracing::debug!("server responed with HTTP {}", status);
-        self.log_server_response(status.as_u16(), &text);
+        self.log_server_response("submit", &text);
 
-        if status == reqwest::StatusCode::OK {
-            Ok(text)
-        } else {
-            Err(ServiceError::HttpStatusError(status.as_u16()))
+        match status {
+            reqwest::StatusCode::OK => Ok(text),
+            reqwest::StatusCode::SERVICE_UNAVAILABLE => Err(ServiceError::ServiceUnavailable),
+            _ => Err(ServiceError::HttpStatusError(status.as_u16())),
         }
     }
 }
 
-impl AdventOfCodeService {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log_files(log_dir: &std::path::Path) -> Vec<PathBuf> {
+        std::fs::read_dir(log_dir)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect()
+    }
+
+    #[test]
+    fn input_response_log_omits_puzzle_input() {
+        let log = input_response_log(reqwest::StatusCode::OK, "secret\ninput\n");
+
+        assert_eq!(log, "HTTP 200 OK, 13 bytes of puzzle input not logged");
+        assert!(!log.contains("secret"));
+    }
+
+    #[test]
+    fn input_response_log_keeps_error_pages() {
+        let page =
+            "<html>Puzzle inputs differ by user.  Please log in to get your puzzle input.</html>";
+
+        assert_eq!(
+            input_response_log(reqwest::StatusCode::BAD_REQUEST, page),
+            page
+        );
+        assert_eq!(
+            input_response_log(reqwest::StatusCode::NOT_FOUND, "<html>404</html>"),
+            "<html>404</html>"
+        );
+    }
+
+    #[test]
+    fn log_server_response_writes_file_when_enabled() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let log_dir = temp_dir.path().join("responses");
+        let service = AdventOfCodeService::new(Some(log_dir.clone()), Duration::ZERO);
+
+        service.log_server_response("submit", "<html>That's the right answer!</html>");
+
+        let files = log_files(&log_dir);
+        assert_eq!(files.len(), 1);
+
+        let file_name = files[0].file_name().unwrap().to_string_lossy();
+        assert!(file_name.starts_with("submit_"), "{file_name}");
+        assert!(file_name.ends_with(".html"), "{file_name}");
+
+        assert_eq!(
+            std::fs::read_to_string(&files[0]).unwrap(),
+            "<html>That's the right answer!</html>"
+        );
+    }
+
+    #[test]
+    fn log_server_response_does_nothing_when_disabled() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let log_dir = temp_dir.path().join("responses");
+        let mut service = AdventOfCodeService::new(Some(log_dir.clone()), Duration::ZERO);
+
+        // Logging to `log_dir` works until logging is disabled.
+        service.log_server_response("input", "1 2 3");
+        assert_eq!(log_files(&log_dir).len(), 1);
+
+        service.log_dir = None;
+        service.log_server_response("input", "4 5 6");
+
+        assert_eq!(log_files(&log_dir).len(), 1);
+    }
+
+    #[test]
+    fn log_server_response_keeps_responses_logged_at_same_time() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let service = AdventOfCodeService::new(Some(temp_dir.path().to_path_buf()), Duration::ZERO);
+
+        for i in 0..5 {
+            service.log_server_response("submit", &format!("response {i}"));
+        }
+
+        let mut responses: Vec<String> = log_files(temp_dir.path())
+            .iter()
+            .map(|f| std::fs::read_to_string(f).unwrap())
+            .collect();
+        responses.sort();
+
+        assert_eq!(
+            responses,
+            (0..5).map(|i| format!("response {i}")).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parse_puzzle_title_strips_day_prefix() {
+        assert_eq!(
+            parse_puzzle_title(
+                "<article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is wrong"
+            ),
+            Some("Trebuchet?!".to_string())
+        );
+        assert_eq!(
+            parse_puzzle_title("<h2>--- Day 25: Snowverload ---</h2>"),
+            Some("Snowverload".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_puzzle_title_ignores_other_headings() {
+        assert_eq!(
+            parse_puzzle_title("<h2 id=\"part2\">--- Part Two ---</h2>"),
+            None
+        );
+        assert_eq!(parse_puzzle_title("<p>no title here</p>"), None);
+    }
+
+    #[test]
+    fn rate_limiter_does_not_delay_first_request() {
+        let rate_limiter = RateLimiter::new(Duration::from_secs(60));
+        let start = Instant::now();
+
+        rate_limiter.wait();
+
+        assert!(start.elapsed() < Duration::from_secs(60));
+    }
+
+    #[test]
+    fn rate_limiter_delays_consecutive_requests() {
+        let interval = Duration::from_millis(100);
+        let rate_limiter = RateLimiter::new(interval);
+
+        rate_limiter.wait();
+        let first_request = Instant::now();
+        rate_limiter.wait();
+
+        assert!(first_request.elapsed() >= interval);
+    }
+
+    /// Send a `get_input` request from `service` through a local mock proxy, and return the lines
+    /// of the request that the proxy received. The proxy refuses to open a tunnel so the request
+    /// never leaves the machine.
+    fn capture_proxy_request(service: AdventOfCodeService) -> Vec<String> {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let proxy_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let proxy = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request: Vec<String> = BufReader::new(&stream)
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect();
+
+            stream
+                .write_all(b"HTTP/1.1 502 Bad Gateway\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+            request
+        });
+
+        let service = service.with_proxy(proxy_url);
+        assert!(service.get_input(Day(1), Year(2024), "12345").is_err());
+
+        proxy.join().unwrap()
+    }
+
+    fn user_agent_header(request: &[String]) -> Option<&str> {
+        request.iter().find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("user-agent")
+                .then_some(value.trim())
+        })
+    }
+
+    #[test]
+    fn requests_are_sent_through_proxy() {
+        let request = capture_proxy_request(AdventOfCodeService::new(None, Duration::ZERO));
+        assert_eq!(request[0], "CONNECT adventofcode.com:443 HTTP/1.1");
+    }
+
+    #[test]
+    fn default_user_agent_includes_crate_version() {
+        assert!(DEFAULT_USER_AGENT.starts_with(&format!(
+            "advent-of-code-data/{} ",
+            env!("CARGO_PKG_VERSION")
+        )));
+
+        let request = capture_proxy_request(AdventOfCodeService::new(None, Duration::ZERO));
+        assert_eq!(user_agent_header(&request), Some(DEFAULT_USER_AGENT));
+    }
+
+    #[test]
+    fn custom_user_agent_is_sent() {
+        let request = capture_proxy_request(
+            AdventOfCodeService::new(None, Duration::ZERO)
+                .with_user_agent("my-aoc-tool/1.0 (me@example.com)"),
+        );
+
+        assert_eq!(
+            user_agent_header(&request),
+            Some("my-aoc-tool/1.0 (me@example.com)")
+        );
+    }
+
+    #[test]
+    fn invalid_proxy_url_is_an_error() {
+        let service = AdventOfCodeService::new(None, Duration::ZERO).with_proxy("http://[::1");
+
+        assert!(matches!(
+            service.get_input(Day(1), Year(2024), "12345"),
+            Err(ServiceError::ProxyConfigError(_))
+        ));
+    }
+}
@@ -5,6 +5,7 @@ use std::{
 
 use base64::{prelude::BASE64_STANDARD, Engine};
 use core::str;
+use sha2::{Digest, Sha256};
 use simple_crypt::{decrypt, encrypt};
 use thiserror::Error;
 
@@ -21,22 +22,40 @@ pub enum CacheError {
     #[error("Cached input file is not encrypted but encryption passphrase was provided")]
     // TODO: Should this be a warning and not an error?
     PassphraseNotNeeded,
-    #[error("base 64 decoding failed: {}", .0)]
-    DecodeBase64(#[from] base64::DecodeError),
-    #[error("decryption failed: {}", .0)]
-    Decryption(#[source] anyhow::Error),
+    #[error("base 64 decoding of {} failed: {}", .path.display(), .source)]
+    DecodeBase64 {
+        path: PathBuf,
+        source: base64::DecodeError,
+    },
+    #[error("decryption of {} failed: {}", .path.display(), .source)]
+    Decryption {
+        path: PathBuf,
+        source: anyhow::Error,
+    },
     #[error("encryption failed: {}", .0)]
     Encryption(#[source] anyhow::Error),
     #[error("decoding utf8 failed: {}", .0)]
     DecodeUtf8(#[from] std::string::FromUtf8Error),
     #[error("serializing or deserializing failed: {}", .0)]
     JsonSerde(#[from] serde_json::Error),
-    #[error("a file i/o error occured while reading/writing the cache: {}", .0)]
-    Io(#[from] std::io::Error),
+    #[error("a file i/o error occured while reading/writing the cache file {}: {}", .path.display(), .source)]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
     #[error("an error occurred while parsing a cached answer dataset: {}", .0)]
     AnswerParsing(#[from] crate::data::AnswerDeserializationError),
 }
 
+/// Returns a function that converts an I/O error into a `CacheError::Io` for `path`, for use with
+/// `map_err`.
+fn io_error(path: &Path) -> impl FnOnce(std::io::Error) -> CacheError + '_ {
+    move |source| CacheError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
 /// Caches puzzle inputs and answers to allow retrieval without having to request data from the
 /// Advent of Code service.
 ///
@@ -52,6 +71,9 @@ pub trait PuzzleCache: Debug {
         -> Result<Option<Answers>, CacheError>;
 
     /// Save a puzzle's input and answers for both parts to the cache.
+    ///
+    /// Saving stops at the first error, so answers are never saved for a puzzle whose input could
+    /// not be saved.
     fn save(&self, puzzle: Puzzle) -> Result<(), CacheError> {
         self.save_input(&puzzle.input, puzzle.day, puzzle.year)?;
         self.save_answers(&puzzle.part_one_answers, Part::One, puzzle.day, puzzle.year)?;
@@ -60,6 +82,20 @@ pub trait PuzzleCache: Debug {
         Ok(())
     }
 
+    /// Save a puzzle's input and answers for both parts to the cache, and return every error that
+    /// happened while saving. Unlike `save()` this keeps saving after an error, which can leave a
+    /// partially saved puzzle in the cache.
+    fn save_all_parts(&self, puzzle: Puzzle) -> Vec<CacheError> {
+        [
+            self.save_input(&puzzle.input, puzzle.day, puzzle.year),
+            self.save_answers(&puzzle.part_one_answers, Part::One, puzzle.day, puzzle.year),
+            self.save_answers(&puzzle.part_two_answers, Part::Two, puzzle.day, puzzle.year),
+        ]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect()
+    }
+
     /// Save input for the given day and year. The input is encrypted before being written to disk
     /// if a passphrase is provided. Any previously saved input for this day and year will be
     /// overwritten.
@@ -187,16 +223,25 @@ impl PuzzleCache for PuzzleFsCache {
         // Read the cached input file.
         tracing::debug!("loading input for day {day} year {year} from {input_path:?}");
 
-        match std::fs::read_to_string(input_path) {
+        match std::fs::read_to_string(&input_path) {
             Ok(input_text) => {
                 // Check if the input file needs to be decrypted before returning it.
                 if let Some(passphrase) = &self.passphrase {
                     // Input needs decryption before it can be returned.
-                    let encrypted_bytes = BASE64_STANDARD
-                        .decode(input_text.as_bytes())
-                        .map_err(CacheError::DecodeBase64)?;
-                    let input_bytes = decrypt(&encrypted_bytes, passphrase.as_bytes())
-                        .map_err(CacheError::Decryption)?;
+                    let encrypted_bytes =
+                        BASE64_STANDARD
+                            .decode(input_text.as_bytes())
+                            .map_err(|source| CacheError::DecodeBase64 {
+                                path: input_path.clone(),
+                                source,
+                            })?;
+                    let input_bytes =
+                        decrypt(&encrypted_bytes, passphrase.as_bytes()).map_err(|source| {
+                            CacheError::Decryption {
+                                path: input_path.clone(),
+                                source,
+                            }
+                        })?;
                     let decrypted_input_text =
                         String::from_utf8(input_bytes).map_err(CacheError::DecodeUtf8)?;
 
@@ -209,7 +254,7 @@ impl PuzzleCache for PuzzleFsCache {
                 }
             }
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
-            Err(e) => Err(CacheError::Io(e)),
+            Err(e) => Err(io_error(&input_path)(e)),
         }
     }
 
@@ -219,17 +264,19 @@ impl PuzzleCache for PuzzleFsCache {
         day: Day,
         year: Year,
     ) -> Result<Option<Answers>, CacheError> {
-        match std::fs::read_to_string(Self::answers_file_path(&self.cache_dir, part, day, year)) {
+        let answers_path = Self::answers_file_path(&self.cache_dir, part, day, year);
+
+        match std::fs::read_to_string(&answers_path) {
             Ok(answers_data) => Ok(Some(Answers::deserialize_from_str(&answers_data)?)),
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
-            Err(e) => Err(CacheError::Io(e)),
+            Err(e) => Err(io_error(&answers_path)(e)),
         }
     }
 
     fn save(&self, puzzle: Puzzle) -> Result<(), CacheError> {
         // Create the puzzle directory in the cache if it doesn't already exist.
         let puzzle_dir = Self::dir_for_puzzle(&self.cache_dir, puzzle.day, puzzle.year);
-        std::fs::create_dir_all(puzzle_dir)?;
+        std::fs::create_dir_all(&puzzle_dir).map_err(io_error(&puzzle_dir))?;
 
         self.save_input(&puzzle.input, puzzle.day, puzzle.year)?;
         self.save_answers(&puzzle.part_one_answers, Part::One, puzzle.day, puzzle.year)?;
@@ -247,7 +294,7 @@ impl PuzzleCache for PuzzleFsCache {
         let mut puzzle_dir = input_path.clone();
         puzzle_dir.pop();
 
-        std::fs::create_dir_all(puzzle_dir)?;
+        std::fs::create_dir_all(&puzzle_dir).map_err(io_error(&puzzle_dir))?;
 
         // Write the input to disk and encrypt the input file when stored on disk.
         if let Some(passphrase) = &self.passphrase {
@@ -257,11 +304,11 @@ impl PuzzleCache for PuzzleFsCache {
             let b64_encrypted_text = BASE64_STANDARD.encode(encrypted_data);
 
             tracing::debug!("saving encrypted input for day {day} year {year} to {input_path:?}");
-            Ok(std::fs::write(input_path, b64_encrypted_text)?)
+            std::fs::write(&input_path, b64_encrypted_text).map_err(io_error(&input_path))
         } else {
             // No encryption.
             tracing::debug!("saving unencrypted input for day {day} year {year} to {input_path:?}");
-            Ok(std::fs::write(input_path, input)?)
+            std::fs::write(&input_path, input).map_err(io_error(&input_path))
         }
     }
 
@@ -278,10 +325,11 @@ impl PuzzleCache for PuzzleFsCache {
         let mut puzzle_dir = answers_path.clone();
         puzzle_dir.pop();
 
-        std::fs::create_dir_all(puzzle_dir)?;
+        std::fs::create_dir_all(&puzzle_dir).map_err(io_error(&puzzle_dir))?;
 
         tracing::debug!("saving answer for part {part} day {day} year {year} to {answers_path:?}");
-        Ok(std::fs::write(answers_path, answers.serialize_to_string())?)
+        std::fs::write(&answers_path, answers.serialize_to_string())
+            .map_err(io_error(&answers_path))
     }
 }
 
@@ -297,21 +345,51 @@ impl SessionFsCache {
         }
     }
 
-    /// Returns the cache file path for session data. The session ID is used directly as the filename.
-    /// Note: Assumes the session ID is already sanitized and safe for use as a filename.
+    /// Returns the cache file path for session data. The filename is the hex encoded SHA-256 hash
+    /// of the session ID so the secret session ID is never written to the file system as a name.
     pub fn session_data_filepath(&self, session_id: &str) -> PathBuf {
-        self.cache_dir.join(format!("{}.json", session_id))
+        let hash: String = Sha256::digest(session_id.as_bytes())
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+
+        self.cache_dir.join(format!("{hash}.json"))
+    }
+
+    /// Returns the cache file path that older versions of this crate used for session data, which
+    /// used the session ID directly as the filename.
+    fn legacy_session_data_filepath(&self, session_id: &str) -> PathBuf {
+        self.cache_dir.join(format!("{session_id}.json"))
+    }
+
+    /// Renames a session data file that uses the older naming scheme to the current naming scheme.
+    /// Returns true if a file was renamed. A legacy file is left in place when a file with the new
+    /// name already exists.
+    pub fn migrate_legacy_session_file(&self, session_id: &str) -> Result<bool, CacheError> {
+        let legacy_filepath = self.legacy_session_data_filepath(session_id);
+        let session_filepath = self.session_data_filepath(session_id);
+
+        if !legacy_filepath.is_file() || session_filepath.exists() {
+            return Ok(false);
+        }
+
+        tracing::debug!("moving session data `{legacy_filepath:?}` to `{session_filepath:?}`");
+        std::fs::rename(&legacy_filepath, &session_filepath).map_err(io_error(&legacy_filepath))?;
+
+        Ok(true)
     }
 }
 
 impl SessionCache for SessionFsCache {
     fn try_load(&self, session_id: &str) -> Result<Option<Session>, CacheError> {
+        self.migrate_legacy_session_file(session_id)?;
         let session_filepath = self.session_data_filepath(session_id);
 
         if session_filepath.is_file() {
             tracing::debug!("cached session data for {session_id} is at `{session_filepath:?}`");
 
-            let json_text = std::fs::read_to_string(session_filepath)?;
+            let json_text =
+                std::fs::read_to_string(&session_filepath).map_err(io_error(&session_filepath))?;
             let session: Session = serde_json::from_str(&json_text)?;
 
             Ok(Some(session))
@@ -328,13 +406,13 @@ impl SessionCache for SessionFsCache {
         let mut session_dir = session_filepath.clone();
         session_dir.pop();
 
-        std::fs::create_dir_all(session_dir)?;
+        std::fs::create_dir_all(&session_dir).map_err(io_error(&session_dir))?;
 
         // Write the serialized session data to disk.
         let json_text = serde_json::to_string(&session)?;
         tracing::debug!("saving session data to `{session_filepath:?}`");
 
-        std::fs::write(session_filepath, json_text)?;
+        std::fs::write(&session_filepath, json_text).map_err(io_error(&session_filepath))?;
         Ok(())
     }
 }
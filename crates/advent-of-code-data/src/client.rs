@@ -1,3 +1,5 @@
+use std::sync::OnceLock;
+
 use chrono::{Datelike, Duration};
 use regex::Regex;
 use thiserror::Error;
@@ -5,7 +7,7 @@ use thiserror::Error;
 use crate::{
     aoc_service::{AdventOfCodeService, ServiceConnector, ServiceError},
     cache::{CacheError, PuzzleCache, PuzzleFsCache, SessionCache, SessionFsCache},
-    config::{load_config, Config, ConfigError},
+    config::{load_config, Config, ConfigBuilder, ConfigError},
     data::{Answers, CheckResult, Puzzle},
     utils::get_puzzle_unlock_time,
     Answer, Day, Part, Year,
@@ -13,13 +15,21 @@ use crate::{
 
 const HTTP_BAD_REQUEST: u16 = 400;
 const HTTP_NOT_FOUND: u16 = 404;
+const HTTP_SERVICE_UNAVAILABLE: u16 = 503;
+
+/// The number of times a request is retried when the Advent of Code service is unavailable.
+const MAX_UNAVAILABLE_RETRIES: usize = 3;
+
+static RE_BOUNDS_HINT: OnceLock<Regex> = OnceLock::new();
 
 /// Errors that can occur when interacting with the Advent of Code service.
 #[derive(Debug, Error)]
 pub enum ClientError {
-    /// The answer was submitted too soon. The `DateTime` indicates when submission will be allowed.
-    #[error("the answer was submitted too soon, please wait until {} trying again", .0)]
-    TooSoon(chrono::DateTime<chrono::Utc>),
+    /// The puzzle has not been unlocked yet. `unlocks_at` is when the puzzle becomes available.
+    #[error("this puzzle unlocks at {}", .unlocks_at)]
+    PuzzleNotYetUnlocked {
+        unlocks_at: chrono::DateTime<chrono::Utc>,
+    },
     /// A session id was expected but not provided.
     #[error(
         "session cookie required; read the advent-of-code-data README for instructions on setting this"
@@ -31,9 +41,10 @@ pub enum ClientError {
     /// The puzzle for the given day and year could not be found.
     #[error("a puzzle could not be found for day {} year {}", .0, .1)]
     PuzzleNotFound(Day, Year),
-    /// A submission timeout is active; the `Duration` indicates how long to wait before retrying.
+    /// An answer was submitted too recently; the `Duration` indicates how long to wait before
+    /// submitting another answer.
     #[error("please wait {} before submitting another answer to the Advent of Code service", .0)]
-    SubmitTimeOut(chrono::Duration),
+    AnswerSubmitCooldown(chrono::Duration),
     /// A correct answer has already been submitted for this puzzle.
     #[error("a correct answer has already been submitted for this puzzle")]
     AlreadySubmittedAnswer,
@@ -48,6 +59,39 @@ pub enum ClientError {
     SettingsError(#[from] ConfigError),
     #[error("{}", .0)]
     ReqwestError(#[from] reqwest::Error),
+    /// The proxy URL in the configuration settings is not valid.
+    #[error("{}", .0)]
+    ProxyConfigError(String),
+    /// The Advent of Code service connector returned an error that does not apply to the request.
+    #[error("unexpected error from the Advent of Code service: {}", .0)]
+    UnexpectedServiceError(String),
+}
+
+/// Errors wrapping other error types (cache, config and HTTP client errors) are equal when they are
+/// the same `ClientError` variant, regardless of the wrapped error.
+impl PartialEq for ClientError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::PuzzleNotYetUnlocked { unlocks_at: a },
+                Self::PuzzleNotYetUnlocked { unlocks_at: b },
+            ) => a == b,
+            (Self::SessionIdRequired, Self::SessionIdRequired) => true,
+            (Self::BadSessionId(a), Self::BadSessionId(b)) => a == b,
+            (Self::PuzzleNotFound(a_day, a_year), Self::PuzzleNotFound(b_day, b_year)) => {
+                a_day == b_day && a_year == b_year
+            }
+            (Self::AnswerSubmitCooldown(a), Self::AnswerSubmitCooldown(b)) => a == b,
+            (Self::AlreadySubmittedAnswer, Self::AlreadySubmittedAnswer) => true,
+            (Self::ServerHttpError(a), Self::ServerHttpError(b)) => a == b,
+            (Self::ProxyConfigError(a), Self::ProxyConfigError(b)) => a == b,
+            (Self::UnexpectedServiceError(a), Self::UnexpectedServiceError(b)) => a == b,
+            (Self::CacheError(_), Self::CacheError(_))
+            | (Self::SettingsError(_), Self::SettingsError(_))
+            | (Self::ReqwestError(_), Self::ReqwestError(_)) => true,
+            _ => false,
+        }
+    }
 }
 
 /// Primary abstraction for interacting with the Advent of Code service.
@@ -99,6 +143,64 @@ pub trait Client {
     ) -> Result<CheckResult, ClientError>;
     /// Fetches the complete puzzle data (input and cached answers) for a given day and year.
     fn get_puzzle(&self, day: Day, year: Year) -> Result<Puzzle, ClientError>;
+    /// Returns the cached answers for a puzzle part. The default implementation loads the answers
+    /// with `get_puzzle`, which may fetch the puzzle input from the service.
+    fn get_answers(&self, part: Part, day: Day, year: Year) -> Result<Answers, ClientError> {
+        Ok(self.get_puzzle(day, year)?.answers(part).clone())
+    }
+    /// Returns true if the puzzle input for a given day and year is available without fetching
+    /// from the service. Clients without a cache always return false.
+    fn has_cached_input(&self, _day: Day, _year: Year) -> Result<bool, ClientError> {
+        Ok(false)
+    }
+    /// Fetches and caches the puzzle input for each of `days` in `year`, or every available day in
+    /// `year` when `days` is `None`. Inputs that are already cached are skipped.
+    ///
+    /// `on_progress` is called after each day is handled. Prefetching stops at the first error.
+    fn prefetch_year(
+        &self,
+        year: Year,
+        days: Option<&[Day]>,
+        on_progress: &mut dyn FnMut(Day, PrefetchStatus),
+    ) -> Result<PrefetchSummary, ClientError> {
+        let days = match days {
+            Some(days) => days.to_vec(),
+            None => self.days(year).unwrap_or_default(),
+        };
+
+        let mut summary = PrefetchSummary::default();
+
+        for day in days {
+            let status = if self.has_cached_input(day, year)? {
+                summary.already_cached += 1;
+                PrefetchStatus::AlreadyCached
+            } else {
+                self.get_input(day, year)?;
+                summary.fetched += 1;
+                PrefetchStatus::Fetched
+            };
+
+            on_progress(day, status);
+        }
+
+        Ok(summary)
+    }
+}
+
+/// The outcome of prefetching the input for a single puzzle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PrefetchStatus {
+    /// The input was fetched from the Advent of Code service and cached.
+    Fetched,
+    /// The input was already cached and was not fetched again.
+    AlreadyCached,
+}
+
+/// Counts of puzzle inputs handled by `Client::prefetch_year`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PrefetchSummary {
+    pub fetched: usize,
+    pub already_cached: usize,
 }
 
 /// HTTP-based implementation of the `Client` trait that talks with the Advent of Code website.
@@ -112,7 +214,10 @@ pub trait Client {
 /// 2. **`with_options(ClientOptions)`** - Creates a client with custom configuration options
 ///    (directories, passphrase, etc.). This is the standard path for most use cases.
 ///
-/// 3. **`with_custom_impl(ClientConfig, Box<dyn AdventOfCodeProtocol>)`** - For testing usage.
+/// 3. **`with_config_strict(ConfigBuilder)`** - Like `with_config`, but a missing session id or
+///    passphrase is an error instead of falling back to a default. Useful for test harnesses.
+///
+/// 4. **`with_custom_impl(ClientConfig, Box<dyn AdventOfCodeProtocol>)`** - For testing usage.
 ///    Allows callers to inject a mock HTTP implementation. Caches are still created automatically
 ///    from the config.
 ///
@@ -130,6 +235,8 @@ pub struct WebClient {
     pub puzzle_cache: Box<dyn PuzzleCache>,
     /// Stores submission timeout state.
     pub session_cache: Box<dyn SessionCache>,
+    /// When the client was created, used to advance `config.start_time` to the current time.
+    created_at: std::time::Instant,
 }
 
 impl WebClient {
@@ -140,10 +247,40 @@ impl WebClient {
 
     /// Creates a client with custom configuration options.
     pub fn with_config(config: Config) -> Self {
-        let advent_protocol = Box::new(AdventOfCodeService {
-            log_dir: config.log_server_responses.clone(),
-        });
-        Self::with_custom_impl(config, advent_protocol)
+        let mut advent_protocol =
+            AdventOfCodeService::new(config.log_server_responses.clone(), config.request_interval);
+
+        if let Some(proxy_url) = &config.proxy_url {
+            advent_protocol = advent_protocol.with_proxy(proxy_url);
+        }
+
+        if let Some(user_agent) = &config.user_agent {
+            advent_protocol = advent_protocol.with_user_agent(user_agent);
+        }
+
+        Self::with_custom_impl(config, Box::new(advent_protocol))
+    }
+
+    /// Creates a client from the settings in `builder`, and returns an error if the session id or
+    /// passphrase was not set.
+    ///
+    /// Unlike `new()` this never reads config files or the `AOC_*` environment variables, and unlike
+    /// `ConfigBuilder::build()` it never falls back to a default passphrase. The system proxy
+    /// settings, including the `HTTPS_PROXY`, `HTTP_PROXY` and `NO_PROXY` environment variables,
+    /// are still used when `builder` does not set a proxy.
+    pub fn with_config_strict(builder: ConfigBuilder) -> Result<Self, ClientError> {
+        // Settings from the selected profile count towards the required settings.
+        let builder = builder.apply_profile()?;
+
+        if builder.session_id.as_ref().is_none_or(|s| s.is_empty()) {
+            return Err(ClientError::SessionIdRequired);
+        }
+
+        if builder.passphrase.is_none() {
+            return Err(ClientError::SettingsError(ConfigError::PassphraseRequired));
+        }
+
+        Ok(Self::with_config(builder.build()?))
     }
 
     /// Creates a client with a custom HTTP protocol implementation.
@@ -167,7 +304,41 @@ impl WebClient {
             protocol: advent_protocol,
             puzzle_cache: Box::new(PuzzleFsCache::new(puzzle_dir, Some(passphrase))),
             session_cache: Box::new(SessionFsCache::new(sessions_dir)),
+            created_at: std::time::Instant::now(),
+        }
+    }
+
+    /// Get the current time, which is `config.start_time` plus the time elapsed since the client
+    /// was created. A fake start time used for testing keeps moving forward like a real clock.
+    fn now(&self) -> chrono::DateTime<chrono::Utc> {
+        self.config.start_time
+            + chrono::Duration::from_std(self.created_at.elapsed()).unwrap_or(chrono::Duration::MAX)
+    }
+
+    /// Call `request` until it returns something other than `ServiceError::ServiceUnavailable`,
+    /// waiting `config.retry_backoff` between attempts. The request is retried at most
+    /// `MAX_UNAVAILABLE_RETRIES` times before the error is returned.
+    fn retry_if_unavailable<F>(&self, mut request: F) -> Result<String, ServiceError>
+    where
+        F: FnMut() -> Result<String, ServiceError>,
+    {
+        let mut result = request();
+
+        for retry in 1..=MAX_UNAVAILABLE_RETRIES {
+            if !matches!(result, Err(ServiceError::ServiceUnavailable)) {
+                break;
+            }
+
+            tracing::warn!(
+                "the Advent of Code service is unavailable, retrying ({retry}/{MAX_UNAVAILABLE_RETRIES}) in {:?}",
+                self.config.retry_backoff
+            );
+
+            std::thread::sleep(self.config.retry_backoff);
+            result = request();
         }
+
+        result
     }
 }
 
@@ -194,12 +365,16 @@ impl Client for WebClient {
             eastern_start_time.year().cmp(&requested_year),
             eastern_start_time.month() == 12,
         ) {
+            // Puzzles stop unlocking after the last day, so clamp the days listed at the end of
+            // December.
             (std::cmp::Ordering::Equal, true) => Some(
-                (1..(eastern_start_time.day() + 1))
-                    .map(|d| d.into())
-                    .collect(),
+                Day::range(
+                    Day::FIRST,
+                    Day(eastern_start_time.day() as usize).min(Day::LAST),
+                )
+                .collect(),
             ),
-            (std::cmp::Ordering::Greater, _) => Some((0..25).map(|d| d.into()).collect()),
+            (std::cmp::Ordering::Greater, _) => Some(Day::range(Day::FIRST, Day::LAST).collect()),
             _ => None,
         }
     }
@@ -223,16 +398,14 @@ impl Client for WebClient {
         // Fetch the puzzle input from the Advent of Code service. Try to catch common error cases
         // so we can return an exact `ClieError` type to the caller, rather than a generic HTTP
         // status code.
-        match self.protocol.get_input(
-            day,
-            year,
-            &self
-                .config
-                .session_id
-                .as_ref()
-                .cloned()
-                .ok_or(ClientError::SessionIdRequired)?,
-        ) {
+        let session_id = self
+            .config
+            .session_id
+            .as_ref()
+            .cloned()
+            .ok_or(ClientError::SessionIdRequired)?;
+
+        match self.retry_if_unavailable(|| self.protocol.get_input(day, year, &session_id)) {
             Ok(input_text) => {
                 assert!(!input_text.is_empty());
 
@@ -252,7 +425,14 @@ impl Client for WebClient {
                 Err(ClientError::PuzzleNotFound(day, year))
             }
             Err(ServiceError::HttpStatusError(c)) => Err(ClientError::ServerHttpError(c)),
+            Err(ServiceError::ServiceUnavailable) => {
+                Err(ClientError::ServerHttpError(HTTP_SERVICE_UNAVAILABLE))
+            }
             Err(ServiceError::ReqwestError(x)) => Err(ClientError::ReqwestError(x)),
+            Err(ServiceError::ProxyConfigError(x)) => Err(ClientError::ProxyConfigError(x)),
+            Err(e @ ServiceError::PuzzleTitleNotFound) => {
+                Err(ClientError::UnexpectedServiceError(e.to_string()))
+            }
         }
     }
 
@@ -294,30 +474,30 @@ impl Client for WebClient {
         )?;
 
         if let Some(submit_wait_until) = session.submit_wait_until {
-            if self.config.start_time <= submit_wait_until {
+            let now = self.now();
+
+            if now <= submit_wait_until {
                 tracing::warn!("you cannot submit an answer until {submit_wait_until}");
-                return Err(ClientError::SubmitTimeOut(
-                    submit_wait_until - self.config.start_time,
-                ));
+                return Err(ClientError::AnswerSubmitCooldown(submit_wait_until - now));
             } else {
-                // TODO: remove the timeout and save.
-                tracing::debug!("the submission timeout has expired, ignoring");
+                tracing::debug!("the submission timeout has expired, removing it");
+                session.submit_wait_until = None;
+                self.session_cache.save(&session)?;
             }
         }
 
         // Submit to the answer to Advent of Code service.
-        match self.protocol.submit_answer(
-            &answer,
-            part,
-            day,
-            year,
-            &self
-                .config
-                .session_id
-                .as_ref()
-                .cloned()
-                .ok_or(ClientError::SessionIdRequired)?,
-        ) {
+        let session_id = self
+            .config
+            .session_id
+            .as_ref()
+            .cloned()
+            .ok_or(ClientError::SessionIdRequired)?;
+
+        match self.retry_if_unavailable(|| {
+            self.protocol
+                .submit_answer(&answer, part, day, year, &session_id)
+        }) {
             Ok(response_text) => {
                 assert!(!response_text.is_empty());
 
@@ -327,8 +507,10 @@ impl Client for WebClient {
 
                 // Write back the amount of time to wait to avoid hitting the server
                 // on future submissions.
+                // The wait is measured from the current time rather than `config.start_time` so
+                // that a long running client does not save a timeout that expires too early.
                 if let Some(time_to_wait) = maybe_time_to_wait {
-                    let wait_until = chrono::Utc::now() + time_to_wait;
+                    let wait_until = self.now() + time_to_wait;
                     tracing::debug!("setting time to wait ({time_to_wait}) to be {wait_until}");
 
                     session.submit_wait_until = Some(wait_until);
@@ -346,11 +528,11 @@ impl Client for WebClient {
                         tracing::debug!("Setting wrong answer {answer}");
                         answers.add_wrong_answer(answer);
                     }
-                    CheckResult::TooLow => {
+                    CheckResult::TooLow { .. } => {
                         tracing::debug!("Setting low bounds wrong answer {answer}");
                         answers.set_low_bounds(answer);
                     }
-                    CheckResult::TooHigh => {
+                    CheckResult::TooHigh { .. } => {
                         tracing::debug!("Setting high bounds wrong answer {answer}");
                         answers.set_high_bounds(answer);
                     }
@@ -372,10 +554,28 @@ impl Client for WebClient {
                 Err(ClientError::PuzzleNotFound(day, year))
             }
             Err(ServiceError::HttpStatusError(c)) => Err(ClientError::ServerHttpError(c)),
+            Err(ServiceError::ServiceUnavailable) => {
+                Err(ClientError::ServerHttpError(HTTP_SERVICE_UNAVAILABLE))
+            }
             Err(ServiceError::ReqwestError(x)) => Err(ClientError::ReqwestError(x)),
+            Err(ServiceError::ProxyConfigError(x)) => Err(ClientError::ProxyConfigError(x)),
+            Err(e @ ServiceError::PuzzleTitleNotFound) => {
+                Err(ClientError::UnexpectedServiceError(e.to_string()))
+            }
         }
     }
 
+    fn has_cached_input(&self, day: Day, year: Year) -> Result<bool, ClientError> {
+        Ok(self.puzzle_cache.load_input(day, year)?.is_some())
+    }
+
+    fn get_answers(&self, part: Part, day: Day, year: Year) -> Result<Answers, ClientError> {
+        Ok(self
+            .puzzle_cache
+            .load_answers(part, day, year)?
+            .unwrap_or_default())
+    }
+
     fn get_puzzle(&self, day: Day, year: Year) -> Result<Puzzle, ClientError> {
         Ok(Puzzle {
             day,
@@ -419,7 +619,7 @@ fn parse_submit_response(
     // TODO: Look into "You don't seem to be solving the right level.  Did you already complete it?"
     //       Is this only returned for errors on solved levels?
     if response_text.contains("gave an answer too recently") {
-        return Err(ClientError::SubmitTimeOut(time_to_wait.unwrap()));
+        return Err(ClientError::AnswerSubmitCooldown(time_to_wait.unwrap()));
     }
 
     if response_text.contains("you already complete it") {
@@ -427,11 +627,13 @@ fn parse_submit_response(
     }
 
     // Translate the response text into a result.
+    //
+    // Too low and too high responses also say "not the right answer", so they are checked first.
     let responses_texts = &[
+        ("answer is too low", CheckResult::TooLow { hint: None }),
+        ("answer is too high", CheckResult::TooHigh { hint: None }),
         ("not the right answer", CheckResult::Wrong),
         ("the right answer", CheckResult::Correct),
-        ("answer is too low", CheckResult::TooLow),
-        ("answer is too high", CheckResult::TooHigh),
     ];
 
     let check_result = responses_texts
@@ -440,6 +642,17 @@ fn parse_submit_response(
             .map(|x| x.1.clone())
             .unwrap_or_else(|| panic!("expected server response text to map to predetermined response in LUT. Response:\n```\n{response_text}\n```\n"));
 
+    // Attach any bounds hint from the server to too low and too high responses.
+    let check_result = match check_result {
+        CheckResult::TooLow { .. } => CheckResult::TooLow {
+            hint: extract_bounds_hint(response_text),
+        },
+        CheckResult::TooHigh { .. } => CheckResult::TooHigh {
+            hint: extract_bounds_hint(response_text),
+        },
+        check_result => check_result,
+    };
+
     Ok((check_result, time_to_wait))
 }
 
@@ -476,13 +689,22 @@ fn extract_error_time_to_wait(response: &str) -> Option<Duration> {
     })
 }
 
+/// Parses `response` and returns the number given by the server when it says how low or high an
+/// answer should be, e.g. "your answer should be at least 1,234" returns `"1234"`.
+fn extract_bounds_hint(response: &str) -> Option<String> {
+    let regex = RE_BOUNDS_HINT.get_or_init(|| {
+        Regex::new(r"should be at (?:least|most) (-?[\d,]*\d)")
+            .expect("bounds hint regex failed to compile")
+    });
+
+    regex.captures(response).map(|c| c[1].replace(',', ""))
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{NaiveDate, NaiveTime, TimeZone};
     use chrono_tz::US::Eastern;
 
-    use crate::config::ConfigBuilder;
-
     use super::*;
 
     fn web_client_with_time(
@@ -513,6 +735,28 @@ mod tests {
         )
     }
 
+    #[test]
+    fn puzzle_not_yet_unlocked_message_includes_unlock_time() {
+        let error = ClientError::PuzzleNotYetUnlocked {
+            unlocks_at: get_puzzle_unlock_time(Year(2024)),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "this puzzle unlocks at 2024-12-01 05:00:00 UTC"
+        );
+    }
+
+    #[test]
+    fn answer_submit_cooldown_message_includes_wait_time() {
+        let error = ClientError::AnswerSubmitCooldown(chrono::Duration::seconds(90));
+
+        assert_eq!(
+            error.to_string(),
+            "please wait PT90S before submitting another answer to the Advent of Code service"
+        );
+    }
+
     #[test]
     fn list_years_when_date_is_after_start() {
         let client = web_client_with_time(2018, 12, 1, 0, 0, 0);
@@ -546,6 +790,16 @@ mod tests {
         assert_eq!(client.days(Year(2020)), Some(vec![Day(1)]));
     }
 
+    #[test]
+    fn list_days_for_past_year() {
+        let client = web_client_with_time(2021, 3, 1, 0, 0, 0);
+        let days = client.days(Year(2020)).unwrap();
+
+        assert_eq!(days.len(), 25);
+        assert_eq!(days.first(), Some(&Day(1)));
+        assert_eq!(days.last(), Some(&Day(25)));
+    }
+
     #[test]
     fn list_days_in_middle() {
         let client = web_client_with_time(2020, 12, 6, 0, 0, 0);
@@ -554,4 +808,65 @@ mod tests {
             Some(vec![Day(1), Day(2), Day(3), Day(4), Day(5), Day(6)])
         );
     }
+
+    #[test]
+    fn list_days_after_last_day() {
+        let client = web_client_with_time(2020, 12, 31, 23, 59, 59);
+        let days = client.days(Year(2020)).unwrap();
+
+        assert_eq!(days.len(), 25);
+        assert_eq!(days.last(), Some(&Day::LAST));
+    }
+
+    #[test]
+    fn client_errors_compare_by_value() {
+        assert_eq!(
+            ClientError::PuzzleNotFound(Day(5), Year(2023)),
+            ClientError::PuzzleNotFound(Day(5), Year(2023))
+        );
+        assert_ne!(
+            ClientError::PuzzleNotFound(Day(5), Year(2023)),
+            ClientError::PuzzleNotFound(Day(6), Year(2023))
+        );
+        assert_ne!(
+            ClientError::SessionIdRequired,
+            ClientError::AlreadySubmittedAnswer
+        );
+    }
+
+    #[test]
+    fn client_errors_wrapping_other_errors_compare_by_variant() {
+        assert_eq!(
+            ClientError::CacheError(CacheError::PassphraseRequired),
+            ClientError::CacheError(CacheError::PassphraseNotNeeded)
+        );
+        assert_ne!(
+            ClientError::CacheError(CacheError::PassphraseRequired),
+            ClientError::SettingsError(ConfigError::PassphraseRequired)
+        );
+    }
+
+    #[test]
+    fn parse_too_low_response_with_hint() {
+        let (check_result, _) = parse_submit_response(
+            "<p>That's not the right answer; your answer is too low. Your answer should be at least 1,234.</p>",
+        )
+        .unwrap();
+
+        assert_eq!(
+            check_result,
+            CheckResult::TooLow {
+                hint: Some("1234".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn parse_too_high_response_without_hint() {
+        let (check_result, _) =
+            parse_submit_response("<p>That's not the right answer; your answer is too high.</p>")
+                .unwrap();
+
+        assert_eq!(check_result, CheckResult::TooHigh { hint: None });
+    }
 }
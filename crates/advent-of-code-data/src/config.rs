@@ -1,6 +1,8 @@
 use std::{
+    collections::HashMap,
     fs,
     path::{Path, PathBuf},
+    time::Duration,
 };
 use thiserror::Error;
 
@@ -17,6 +19,10 @@ const CONFIG_FILENAME: &str = "aoc_settings.toml";
 const EXAMPLE_CONFIG_FILENAME: &str = "aoc_settings.example.toml";
 const HOME_DIR_CONFIG_FILENAME: &str = ".aoc_settings.toml";
 
+/// The Advent of Code FAQ asks that automated tools do not send requests too often.
+const DEFAULT_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_secs(5);
+
 const EXAMPLE_CONFIG_TEXT: &str = r#"[client]
 # passphrase = "REPLACE_ME"  # Used to encrypt/decrypt the puzzle cache.
 # session_id = "REPLACE_ME"  # See "Finding your Advent of Code session cookie" in the README for help.
@@ -27,6 +33,10 @@ const EXAMPLE_CONFIG_TEXT: &str = r#"[client]
 pub enum ConfigError {
     #[error("an passphrase for encrypting puzzle inputs is required")]
     PassphraseRequired,
+    #[error("the configuration profile `{}` was not found", .0)]
+    UnknownProfile(String),
+    #[error("a session cookie is required; read the advent-of-code-data README for instructions on setting this")]
+    SessionIdRequired,
     #[error("failed to get the default cache directory for puzzles - this OS is not supported by the `directories` crate")]
     DefaultPuzzleDirError,
     #[error("failed to get the default cache directory for sessions - this OS is not supported by the `directories` crate")]
@@ -56,6 +66,18 @@ pub struct Config {
     pub start_time: chrono::DateTime<chrono::Utc>,
     /// Flag to write server responses to a file path for debugging.
     pub log_server_responses: Option<PathBuf>,
+    /// Minimum amount of time to wait between requests to the Advent of Code service.
+    pub request_interval: Duration,
+    /// Amount of time to wait before retrying a request when the Advent of Code service is
+    /// unavailable.
+    pub retry_backoff: Duration,
+    /// URL of the HTTP proxy used for requests to the Advent of Code service, if any.
+    pub proxy_url: Option<String>,
+    /// The `User-Agent` header sent to the Advent of Code service, or `None` to use
+    /// `aoc_service::DEFAULT_USER_AGENT`.
+    pub user_agent: Option<String>,
+    /// The name of the configuration profile used to create this config, if any.
+    pub profile: Option<String>,
 }
 
 /// A builder interface for specifying configuration settings to the Advent of Client client.
@@ -70,6 +92,18 @@ pub struct Config {
 /// - `puzzle_dir`: A directory in the local user's cache dir (e.g., XDG_CACHE_HOME on Linux).
 /// - `sessions_dir`: A directory in the local user's cache dir (e.g., XDG_CACHE_HOME on Linux).
 /// - `log_server_responses`: None.
+/// - `request_interval`: One second, as requested by the Advent of Code FAQ.
+/// - `retry_backoff`: Five seconds.
+/// - `proxy_url`: None, which uses the system proxy settings (`HTTPS_PROXY`, `HTTP_PROXY` and
+///   `NO_PROXY`).
+/// - `user_agent`: None, which sends a user agent naming this library and its version.
+/// - `profile`: None.
+///
+/// # Profiles
+/// Config files can define named profiles in `[profiles.<name>]` tables, which hold the same keys
+/// as the `[client]` table. When a profile is selected with `with_profile()`, the profile's keys
+/// take priority over all other loaded settings when the `Config` is built.
+#[derive(Clone, Debug)]
 pub struct ConfigBuilder {
     pub session_id: Option<String>,
     pub puzzle_dir: Option<PathBuf>,
@@ -77,6 +111,12 @@ pub struct ConfigBuilder {
     pub passphrase: Option<String>,
     pub fake_time: Option<chrono::DateTime<chrono::Utc>>,
     pub log_server_responses: Option<PathBuf>,
+    pub request_interval: Option<Duration>,
+    pub retry_backoff: Option<Duration>,
+    pub proxy_url: Option<String>,
+    pub user_agent: Option<String>,
+    pub profile: Option<String>,
+    pub profiles: HashMap<String, ConfigBuilder>,
 }
 
 impl ConfigBuilder {
@@ -89,6 +129,12 @@ impl ConfigBuilder {
             passphrase: None,
             fake_time: None,
             log_server_responses: None,
+            request_interval: None,
+            retry_backoff: None,
+            proxy_url: None,
+            user_agent: None,
+            profile: None,
+            profiles: HashMap::new(),
         }
     }
 
@@ -96,11 +142,51 @@ impl ConfigBuilder {
     /// loaded here will overwrite previously loaded values.
     pub fn use_toml(mut self, config_text: &str) -> Result<Self, ConfigError> {
         const CLIENT_TABLE_NAME: &str = "client";
+        const PROFILES_TABLE_NAME: &str = "profiles";
+
+        let toml: toml::Table = config_text.parse::<toml::Table>()?;
+
+        match toml.get(CLIENT_TABLE_NAME) {
+            Some(toml::Value::Table(client_config)) => self.read_toml_table(client_config),
+            _ if toml.contains_key(PROFILES_TABLE_NAME) => {}
+            _ => {
+                tracing::warn!(
+                    "TOML table {CLIENT_TABLE_NAME} was missing; this config will be skipped!"
+                );
+            }
+        }
+
+        match toml.get(PROFILES_TABLE_NAME) {
+            Some(toml::Value::Table(profiles)) => {
+                for (name, profile) in profiles {
+                    match profile {
+                        toml::Value::Table(profile_config) => {
+                            tracing::debug!("found TOML profile `{name}`");
+                            self.profiles
+                                .entry(name.clone())
+                                .or_default()
+                                .read_toml_table(profile_config);
+                        }
+                        _ => tracing::warn!("TOML profile {name} must be a table"),
+                    }
+                }
+            }
+            Some(_) => tracing::warn!("TOML key {PROFILES_TABLE_NAME} must be a table"),
+            None => {}
+        }
+
+        Ok(self)
+    }
+
+    /// Reads the client configuration keys in `table` into this builder.
+    fn read_toml_table(&mut self, table: &toml::Table) {
         const SESSIONS_DIR_KEY: &str = "sessions_dir";
         const SESSION_ID_KEY: &str = "session_id";
         const PUZZLE_DIR_KEY: &str = "puzzle_dir";
         const PASSPHRASE_KEY: &str = "passphrase";
         const LOG_SERVER_RESPONSES: &str = "log_server_responses";
+        const PROXY_URL_KEY: &str = "proxy_url";
+        const USER_AGENT_KEY: &str = "user_agent";
         const REPLACE_ME: &str = "REPLACE_ME";
 
         fn try_read_key<F: FnOnce(&str)>(table: &toml::Table, key: &str, setter: F) {
@@ -122,38 +208,33 @@ impl ConfigBuilder {
             };
         }
 
-        let toml: toml::Table = config_text.parse::<toml::Table>()?;
+        try_read_key(table, PASSPHRASE_KEY, |v| {
+            self.passphrase = Some(v.to_string())
+        });
 
-        match toml.get(CLIENT_TABLE_NAME) {
-            Some(toml::Value::Table(client_config)) => {
-                try_read_key(client_config, PASSPHRASE_KEY, |v| {
-                    self.passphrase = Some(v.to_string())
-                });
-
-                try_read_key(client_config, SESSION_ID_KEY, |v| {
-                    self.session_id = Some(v.to_string())
-                });
-
-                try_read_key(client_config, PUZZLE_DIR_KEY, |v| {
-                    self.puzzle_dir = Some(PathBuf::from(v))
-                });
-
-                try_read_key(client_config, SESSIONS_DIR_KEY, |v| {
-                    self.sessions_dir = Some(PathBuf::from(v))
-                });
-
-                try_read_key(client_config, LOG_SERVER_RESPONSES, |v| {
-                    self.log_server_responses = Some(PathBuf::from(v))
-                });
-            }
-            _ => {
-                tracing::warn!(
-                    "TOML table {CLIENT_TABLE_NAME} was missing; this config will be skipped!"
-                );
-            }
-        }
+        try_read_key(table, SESSION_ID_KEY, |v| {
+            self.session_id = Some(v.to_string())
+        });
 
-        Ok(self)
+        try_read_key(table, PUZZLE_DIR_KEY, |v| {
+            self.puzzle_dir = Some(PathBuf::from(v))
+        });
+
+        try_read_key(table, SESSIONS_DIR_KEY, |v| {
+            self.sessions_dir = Some(PathBuf::from(v))
+        });
+
+        try_read_key(table, LOG_SERVER_RESPONSES, |v| {
+            self.log_server_responses = Some(PathBuf::from(v))
+        });
+
+        try_read_key(table, PROXY_URL_KEY, |v| {
+            self.proxy_url = Some(v.to_string())
+        });
+
+        try_read_key(table, USER_AGENT_KEY, |v| {
+            self.user_agent = Some(v.to_string())
+        });
     }
 
     pub fn with_session_id<S: Into<String>>(mut self, session_id: S) -> Self {
@@ -181,16 +262,160 @@ impl ConfigBuilder {
         self
     }
 
-    pub fn with_log_server_response<P: Into<PathBuf>>(mut self, log_dir: P) -> Self {
+    pub fn with_log_server_responses<P: Into<PathBuf>>(mut self, log_dir: P) -> Self {
         self.log_server_responses = Some(log_dir.into());
         self
     }
 
+    /// Set the minimum amount of time to wait between requests to the Advent of Code service.
+    pub fn with_request_interval(mut self, request_interval: Duration) -> Self {
+        self.request_interval = Some(request_interval);
+        self
+    }
+
+    /// Set the amount of time to wait before retrying a request when the Advent of Code service is
+    /// unavailable.
+    pub fn with_retry_backoff(mut self, retry_backoff: Duration) -> Self {
+        self.retry_backoff = Some(retry_backoff);
+        self
+    }
+
+    /// Send requests to the Advent of Code service through the HTTP proxy at `proxy_url`.
+    pub fn with_proxy<S: Into<String>>(mut self, proxy_url: S) -> Self {
+        self.proxy_url = Some(proxy_url.into());
+        self
+    }
+
+    /// Send `user_agent` as the `User-Agent` header of requests to the Advent of Code service.
+    pub fn with_user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Select the named profile whose settings override the other settings when building.
+    pub fn with_profile<S: Into<String>>(mut self, profile: S) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    /// Returns a copy of this builder with the selected profile's settings applied, or an error if
+    /// the selected profile was never loaded. Applying the profile more than once has no further
+    /// effect.
+    pub(crate) fn apply_profile(self) -> Result<Self, ConfigError> {
+        let Some(name) = &self.profile else {
+            return Ok(self);
+        };
+
+        let profile = self
+            .profiles
+            .get(name)
+            .cloned()
+            .ok_or_else(|| ConfigError::UnknownProfile(name.clone()))?;
+
+        Ok(self.merge(profile))
+    }
+
+    /// Combines the settings in this builder with the settings in `other`. Any setting that is
+    /// set in `other` takes priority over the same setting in this builder, and settings that are
+    /// not set in `other` are left unchanged.
+    ///
+    /// Profiles with the same name in both builders are merged using the same rules.
+    pub fn merge(mut self, other: ConfigBuilder) -> ConfigBuilder {
+        self.session_id = other.session_id.or(self.session_id);
+        self.puzzle_dir = other.puzzle_dir.or(self.puzzle_dir);
+        self.sessions_dir = other.sessions_dir.or(self.sessions_dir);
+        self.passphrase = other.passphrase.or(self.passphrase);
+        self.fake_time = other.fake_time.or(self.fake_time);
+        self.log_server_responses = other.log_server_responses.or(self.log_server_responses);
+        self.request_interval = other.request_interval.or(self.request_interval);
+        self.retry_backoff = other.retry_backoff.or(self.retry_backoff);
+        self.proxy_url = other.proxy_url.or(self.proxy_url);
+        self.user_agent = other.user_agent.or(self.user_agent);
+        self.profile = other.profile.or(self.profile);
+
+        for (name, profile) in other.profiles {
+            let merged = match self.profiles.remove(&name) {
+                Some(existing) => existing.merge(profile),
+                None => profile,
+            };
+
+            self.profiles.insert(name, merged);
+        }
+
+        self
+    }
+
+    /// Check the settings in this `ConfigBuilder` object for problems without building a `Config`,
+    /// and return every problem that was found.
+    ///
+    /// This is stricter than `build()` because it also reports settings that are only needed when
+    /// talking to the Advent of Code service, such as a missing session cookie.
+    pub fn validate(&self) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+
+        let builder = match self.clone().apply_profile() {
+            Ok(builder) => builder,
+            Err(error) => {
+                errors.push(error);
+                self.clone()
+            }
+        };
+
+        if builder.session_id.as_ref().is_none_or(|s| s.is_empty()) {
+            errors.push(ConfigError::SessionIdRequired);
+        }
+
+        // A default passphrase is only used when the puzzle directory is not customized.
+        let has_passphrase = match &builder.passphrase {
+            Some(passphrase) => !passphrase.is_empty(),
+            None => builder.puzzle_dir.is_none(),
+        };
+
+        if !has_passphrase {
+            errors.push(ConfigError::PassphraseRequired);
+        }
+
+        if builder.puzzle_dir.is_none() || builder.sessions_dir.is_none() {
+            let project_dir = directories::ProjectDirs::from(DIRS_QUALIFIER, DIRS_ORG, DIRS_APP);
+
+            if project_dir.is_none() && builder.puzzle_dir.is_none() {
+                errors.push(ConfigError::DefaultPuzzleDirError);
+            }
+
+            if project_dir.is_none() && builder.sessions_dir.is_none() {
+                errors.push(ConfigError::DefaultSessonsDirError);
+            }
+        }
+
+        errors
+    }
+
+    /// Generate a `Config` object after checking for problems with `validate()`. Every problem
+    /// found is printed to stderr, and `Err(())` is returned if there were any problems.
+    #[allow(clippy::result_unit_err)]
+    pub fn build_or_print_errors(self) -> Result<Config, ()> {
+        let errors = self.validate();
+
+        for error in &errors {
+            eprintln!("configuration error: {error}");
+        }
+
+        if !errors.is_empty() {
+            return Err(());
+        }
+
+        self.build().map_err(|error| {
+            eprintln!("configuration error: {error}");
+        })
+    }
+
     /// Generate a `Config` object from the settings in this `ConfigBuilder` object.
     pub fn build(self) -> Result<Config, ConfigError> {
+        let builder = self.apply_profile()?;
+
         // Use a default passphrase if the puzzle directory and the passphrase was not specified.
-        let passphrase = self.passphrase.unwrap_or_else(|| {
-            if self.puzzle_dir.is_none() {
+        let passphrase = builder.passphrase.unwrap_or_else(|| {
+            if builder.puzzle_dir.is_none() {
                 gethostname::gethostname().to_string_lossy().to_string()
             } else {
                 String::new()
@@ -205,22 +430,27 @@ impl ConfigBuilder {
                 directories::ProjectDirs::from(DIRS_QUALIFIER, DIRS_ORG, DIRS_APP);
 
             Ok(Config {
-                session_id: self.session_id,
-                puzzle_dir: self
+                session_id: builder.session_id,
+                puzzle_dir: builder
                     .puzzle_dir
                     .or(maybe_project_dir
                         .as_ref()
                         .map(|p| p.cache_dir().join("puzzles").to_path_buf()))
                     .ok_or(ConfigError::DefaultPuzzleDirError)?,
-                sessions_dir: self
+                sessions_dir: builder
                     .sessions_dir
                     .or(maybe_project_dir
                         .as_ref()
                         .map(|p| p.cache_dir().join("sessions").to_path_buf()))
                     .ok_or(ConfigError::DefaultPuzzleDirError)?,
-                start_time: self.fake_time.unwrap_or(chrono::Utc::now()),
+                start_time: builder.fake_time.unwrap_or(chrono::Utc::now()),
                 passphrase,
-                log_server_responses: self.log_server_responses,
+                log_server_responses: builder.log_server_responses,
+                request_interval: builder.request_interval.unwrap_or(DEFAULT_REQUEST_INTERVAL),
+                retry_backoff: builder.retry_backoff.unwrap_or(DEFAULT_RETRY_BACKOFF),
+                proxy_url: builder.proxy_url,
+                user_agent: builder.user_agent,
+                profile: builder.profile,
             })
         }
     }
@@ -236,13 +466,11 @@ impl Default for ConfigBuilder {
 ///
 /// The behavior of this function is covered in the `advent-of-code-data` [README.md](../README.md).
 pub fn load_config() -> Result<ConfigBuilder, ConfigError> {
-    let mut config: ConfigBuilder = Default::default();
-
-    config = read_config_from_user_config_dirs(Some(config))?;
-    config = read_config_from_current_dir(Some(config))?;
-    config = read_config_from_env_vars(Some(config));
-
-    Ok(config)
+    // Each source is loaded on its own and then merged in order of increasing priority.
+    Ok(ConfigBuilder::new()
+        .merge(read_config_from_user_config_dirs(None)?)
+        .merge(read_config_from_current_dir(None)?)
+        .merge(read_config_from_env_vars(None)))
 }
 
 /// Loads configuration values from a TOML file.
@@ -435,7 +663,7 @@ mod tests {
             .with_puzzle_dir("/tmp/puzzle/dir")
             .with_sessions_dir("/tmp/path/to/sessions")
             .with_passphrase("this is my password")
-            .with_log_server_response("/foo/bar/logs")
+            .with_log_server_responses("/foo/bar/logs")
             .build()
             .unwrap();
 
@@ -458,6 +686,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_valid_builder_has_no_errors() {
+        let builder = ConfigBuilder::new()
+            .with_session_id("54321")
+            .with_puzzle_dir("/tmp/puzzle/dir")
+            .with_passphrase("this is my password");
+
+        assert!(builder.validate().is_empty());
+        assert!(builder.build_or_print_errors().is_ok());
+    }
+
+    #[test]
+    fn validate_returns_all_problems() {
+        let builder = ConfigBuilder::new().with_puzzle_dir("/tmp/puzzle/dir");
+        let errors = builder.validate();
+
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(errors[0], ConfigError::SessionIdRequired));
+        assert!(matches!(errors[1], ConfigError::PassphraseRequired));
+        assert!(builder.build_or_print_errors().is_err());
+    }
+
+    #[test]
+    fn validate_rejects_empty_values() {
+        let errors = ConfigBuilder::new()
+            .with_session_id("")
+            .with_passphrase("")
+            .validate();
+
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(errors[0], ConfigError::SessionIdRequired));
+        assert!(matches!(errors[1], ConfigError::PassphraseRequired));
+    }
+
     #[test]
     fn client_can_overwrite_options() {
         let mut options = ConfigBuilder::new().with_passphrase("12345");
@@ -539,4 +801,209 @@ mod tests {
             Some(PathBuf::from_str("path/to/puzzle/dir").unwrap())
         );
     }
+
+    #[test]
+    fn profile_keys_override_client_keys() {
+        let config_text = r#"
+        [client]
+        session_id = "base_session"
+        passphrase = "base_password"
+        puzzle_dir = "base/puzzle/dir"
+
+        [profiles.dev]
+        session_id = "dev_session"
+        puzzle_dir = "dev/puzzle/dir"
+
+        [profiles.prod]
+        session_id = "prod_session"
+        "#;
+
+        let config = ConfigBuilder::new()
+            .use_toml(config_text)
+            .unwrap()
+            .with_profile("dev")
+            .build()
+            .unwrap();
+
+        assert_eq!(config.profile, Some("dev".to_string()));
+        assert_eq!(config.session_id, Some("dev_session".to_string()));
+        assert_eq!(config.puzzle_dir, PathBuf::from("dev/puzzle/dir"));
+        assert_eq!(&config.passphrase, "base_password");
+    }
+
+    #[test]
+    fn profile_keys_override_keys_set_by_builder_funcs() {
+        let config_text = r#"
+        [profiles.dev]
+        session_id = "dev_session"
+        "#;
+
+        let config = ConfigBuilder::new()
+            .use_toml(config_text)
+            .unwrap()
+            .with_session_id("other_session")
+            .with_profile("dev")
+            .build()
+            .unwrap();
+
+        assert_eq!(config.session_id, Some("dev_session".to_string()));
+    }
+
+    #[test]
+    fn unknown_profile_is_an_error() {
+        let config_text = r#"
+        [client]
+        session_id = "base_session"
+
+        [profiles.dev]
+        session_id = "dev_session"
+        "#;
+
+        let builder = ConfigBuilder::new()
+            .use_toml(config_text)
+            .unwrap()
+            .with_profile("prod");
+
+        assert!(matches!(
+            builder.validate().as_slice(),
+            [ConfigError::UnknownProfile(name)] if name == "prod"
+        ));
+        assert!(matches!(
+            builder.build(),
+            Err(ConfigError::UnknownProfile(name)) if name == "prod"
+        ));
+    }
+
+    #[test]
+    fn proxy_url_is_read_from_toml_and_builder() {
+        let config_text = r#"
+        [client]
+        proxy_url = "http://proxy.example.com:8080"
+        "#;
+
+        let options = ConfigBuilder::new().use_toml(config_text).unwrap();
+        assert_eq!(
+            options.proxy_url,
+            Some("http://proxy.example.com:8080".to_string())
+        );
+
+        let config = options
+            .with_proxy("http://other.example.com:3128")
+            .with_session_id("my_session")
+            .build()
+            .unwrap();
+        assert_eq!(
+            config.proxy_url,
+            Some("http://other.example.com:3128".to_string())
+        );
+    }
+
+    #[test]
+    fn user_agent_is_read_from_toml_and_builder() {
+        let config_text = r#"
+        [client]
+        user_agent = "toml-tool/1.0"
+        "#;
+
+        let options = ConfigBuilder::new().use_toml(config_text).unwrap();
+        assert_eq!(options.user_agent, Some("toml-tool/1.0".to_string()));
+
+        let config = options
+            .with_user_agent("builder-tool/2.0")
+            .with_session_id("my_session")
+            .build()
+            .unwrap();
+        assert_eq!(config.user_agent, Some("builder-tool/2.0".to_string()));
+
+        let config = ConfigBuilder::new()
+            .with_session_id("my_session")
+            .build()
+            .unwrap();
+        assert_eq!(config.user_agent, None);
+    }
+
+    #[test]
+    fn request_interval_defaults_to_one_second() {
+        let config = ConfigBuilder::new()
+            .with_session_id("54321")
+            .build()
+            .unwrap();
+        assert_eq!(config.request_interval, Duration::from_secs(1));
+
+        let config = ConfigBuilder::new()
+            .with_session_id("54321")
+            .with_request_interval(Duration::from_millis(250))
+            .build()
+            .unwrap();
+        assert_eq!(config.request_interval, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn merge_prefers_values_from_other() {
+        let base = ConfigBuilder::new()
+            .with_session_id("base_session")
+            .with_passphrase("base_password");
+        let other = ConfigBuilder::new().with_session_id("other_session");
+
+        let merged = base.merge(other);
+
+        assert_eq!(merged.session_id, Some("other_session".to_string()));
+        assert_eq!(merged.passphrase, Some("base_password".to_string()));
+    }
+
+    #[test]
+    fn merge_does_not_overwrite_with_unset_values() {
+        let base = ConfigBuilder::new()
+            .with_session_id("base_session")
+            .with_puzzle_dir("base/puzzle/dir")
+            .with_log_server_responses("base/logs");
+
+        let merged = base.merge(ConfigBuilder::new());
+
+        assert_eq!(merged.session_id, Some("base_session".to_string()));
+        assert_eq!(merged.puzzle_dir, Some(PathBuf::from("base/puzzle/dir")));
+        assert_eq!(
+            merged.log_server_responses,
+            Some(PathBuf::from("base/logs"))
+        );
+    }
+
+    #[test]
+    fn merge_combines_profiles() {
+        let base = ConfigBuilder::new()
+            .use_toml(
+                r#"
+                [profiles.dev]
+                session_id = "base_dev_session"
+                passphrase = "base_dev_password"
+                "#,
+            )
+            .unwrap();
+        let other = ConfigBuilder::new()
+            .use_toml(
+                r#"
+                [profiles.dev]
+                session_id = "other_dev_session"
+
+                [profiles.prod]
+                session_id = "other_prod_session"
+                "#,
+            )
+            .unwrap();
+
+        let merged = base.merge(other);
+
+        assert_eq!(
+            merged.profiles["dev"].session_id,
+            Some("other_dev_session".to_string())
+        );
+        assert_eq!(
+            merged.profiles["dev"].passphrase,
+            Some("base_dev_password".to_string())
+        );
+        assert_eq!(
+            merged.profiles["prod"].session_id,
+            Some("other_prod_session".to_string())
+        );
+    }
 }
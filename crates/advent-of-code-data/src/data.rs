@@ -45,19 +45,31 @@ pub enum CheckResult {
     Correct,
     /// The answer is incorrect.
     Wrong,
-    /// The answer is too low and incorrect.
-    TooLow,
-    /// The answer is too high and incorrect.
-    TooHigh,
+    /// The answer is too low and incorrect. `hint` holds the bound given by
+    /// the Advent of Code server when its response included one.
+    TooLow { hint: Option<String> },
+    /// The answer is too high and incorrect. `hint` holds the bound given by
+    /// the Advent of Code server when its response included one.
+    TooHigh { hint: Option<String> },
 }
 
 /// Stores correct and incorrect answers for a puzzle, along with hints such as
 /// "too large" and "too small".
-#[derive(Debug, PartialEq)]
+///
+/// Answers are stored in the cache with the line oriented text format used by
+/// `serialize` and `deserialize`. When the `serde` feature is enabled answers
+/// can also be (de)serialized with serde, using a JSON friendly layout such as
+/// `{"correct": "42", "wrong": ["-9", "1"], "low": -50, "high": 25}`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Answers {
+    #[cfg_attr(feature = "serde", serde(rename = "correct", default))]
     correct_answer: Option<Answer>,
+    #[cfg_attr(feature = "serde", serde(rename = "wrong", default))]
     wrong_answers: Vec<Answer>,
+    #[cfg_attr(feature = "serde", serde(rename = "low", default))]
     low_bounds: Option<i128>,
+    #[cfg_attr(feature = "serde", serde(rename = "high", default))]
     high_bounds: Option<i128>,
 }
 
@@ -88,6 +100,22 @@ impl Answers {
         &self.high_bounds
     }
 
+    /// Returns true if the correct answer for this puzzle part is known.
+    pub fn is_solved(&self) -> bool {
+        self.correct_answer.is_some()
+    }
+
+    /// Returns the number of known wrong answers.
+    pub fn wrong_count(&self) -> usize {
+        self.wrong_answers.len()
+    }
+
+    /// Returns the number of answers that were submitted, counting each wrong
+    /// answer and the correct answer if it is known.
+    pub fn attempt_count(&self) -> usize {
+        self.wrong_count() + usize::from(self.is_solved())
+    }
+
     /// Checks if this answer is correct or incorrect according to the information
     /// stored in this `Answers` database.
     ///
@@ -101,9 +129,11 @@ impl Answers {
         // Check the answer against the optional low and high value boundaries.
         match (answer.to_i128(), &self.low_bounds, &self.high_bounds) {
             (Some(answer), Some(low), _) if answer <= *low => {
-                return Some(CheckResult::TooLow);
+                return Some(CheckResult::TooLow { hint: None });
+            }
+            (Some(answer), _, Some(high)) if answer >= *high => {
+                return Some(CheckResult::TooHigh { hint: None })
             }
-            (Some(answer), _, Some(high)) if answer >= *high => return Some(CheckResult::TooHigh),
             _ => {}
         };
 
@@ -150,7 +180,7 @@ impl Answers {
     /// used as the new high boundary.
     ///
     /// Any numeric answer passed to `Answers::check` will be returned as
-    /// `CheckResult::TooLow` if it equals or is smaller than the low boundary.
+    /// `CheckResult::TooLow { hint: None }` if it equals or is smaller than the low boundary.
     pub fn set_low_bounds(&mut self, answer: Answer) -> i128 {
         // TODO: Verify that low bounds is not a correct answer.
         // TODO: Verify that low bounds is not larger or equal to high bounds.
@@ -176,7 +206,7 @@ impl Answers {
     /// used as the new high boundary.
     ///
     /// Any numeric answer passed to `Answers::check` will be returned as
-    /// `CheckResult::TooHigh` if it equals or is larger than the high boundary.
+    /// `CheckResult::TooHigh { hint: None }` if it equals or is larger than the high boundary.
     pub fn set_high_bounds(&mut self, answer: Answer) -> i128 {
         // TODO: Verify that high bounds is not a correct answer.
         // TODO: Verify that high bounds is not smaller or equal to low bounds.
@@ -198,6 +228,36 @@ impl Answers {
         }
     }
 
+    /// Combines the information in this answers database with `other` without
+    /// losing any of it.
+    ///
+    /// Wrong answers from both databases are kept with duplicates removed, the
+    /// correct answer is taken from whichever database knows it, and the
+    /// tightest low and high bounds are kept. An error is returned if both
+    /// databases have a correct answer and the answers are different.
+    pub fn merge(mut self, other: Answers) -> Result<Answers, AnswersMergeError> {
+        self.correct_answer = match (self.correct_answer, other.correct_answer) {
+            (Some(a), Some(b)) if a != b => {
+                return Err(AnswersMergeError::ConflictingCorrectAnswers(a, b));
+            }
+            (a, b) => a.or(b),
+        };
+
+        for answer in other.wrong_answers {
+            if !self.wrong_answers.contains(&answer) {
+                self.wrong_answers.push(answer);
+            }
+        }
+
+        self.low_bounds = self.low_bounds.max(other.low_bounds);
+        self.high_bounds = match (self.high_bounds, other.high_bounds) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+
+        Ok(self)
+    }
+
     pub fn serialize_to_string(&self) -> String {
         // TODO: Convert unwraps into Errors.
         let mut buf = BufWriter::new(Vec::new());
@@ -240,7 +300,6 @@ impl Answers {
     }
 
     pub fn deserialize_from_str(text: &str) -> Result<Self, AnswerDeserializationError> {
-        // TODO: Write tests.
         let mut buf = BufReader::new(text.as_bytes());
         Self::deserialize(&mut buf)
     }
@@ -248,44 +307,32 @@ impl Answers {
     pub fn deserialize<R: Read>(
         reader: &mut BufReader<R>,
     ) -> Result<Self, AnswerDeserializationError> {
-        // TODO: Convert unwrap/expect into Errors
-        // TODO: Write tests.
         let mut answers = Answers::new();
 
         // Each line in the input string is an entry in the answers database.
         // The first character indicates the type of answer, and the characters
         // following the space hold the answer value.
-        for line in reader.lines() {
-            let line = line.unwrap();
+        for (line_index, line) in reader.lines().enumerate() {
+            let line = line?;
             let (ty, value) = line
                 .split_once(' ')
-                .ok_or_else(|| AnswerDeserializationError::UnknownLineFormat(line.clone()))?;
+                .ok_or(AnswerDeserializationError::MissingSpace(line_index + 1))?;
 
-            match ty.chars().next().unwrap() {
+            match ty.chars().next().unwrap_or(' ') {
                 CORRECT_ANSWER_CHAR => {
-                    answers.set_correct_answer(
-                        Answer::from_str(value).expect("Answer::from_str does not return Err"),
-                    );
+                    answers.set_correct_answer(parse_answer_value(value)?);
                 }
                 WRONG_ANSWER_CHAR => {
-                    answers.add_wrong_answer(
-                        Answer::from_str(value).expect("Answer::from_str does not return Err"),
-                    );
+                    answers.add_wrong_answer(parse_answer_value(value)?);
                 }
                 LOW_ANSWER_CHAR => {
-                    let low = value.parse::<i128>().map_err(|_| {
-                        AnswerDeserializationError::LowBoundRequiresInt(value.to_string())
-                    })?;
-                    answers.set_low_bounds(Answer::Int(low));
+                    answers.set_low_bounds(Answer::Int(parse_bounds_value(value)?));
                 }
                 HIGH_ANSWER_CHAR => {
-                    let high = value.parse::<i128>().map_err(|_| {
-                        AnswerDeserializationError::HighBoundRequiresInt(value.to_string())
-                    })?;
-                    answers.set_high_bounds(Answer::Int(high));
+                    answers.set_high_bounds(Answer::Int(parse_bounds_value(value)?));
                 }
                 c => {
-                    return Err(AnswerDeserializationError::UnknownAnswerType(c));
+                    return Err(AnswerDeserializationError::UnknownType(c));
                 }
             }
         }
@@ -294,18 +341,31 @@ impl Answers {
     }
 }
 
+/// Parses the value of a correct or wrong answer entry in a serialized answers database.
+fn parse_answer_value(value: &str) -> Result<Answer, AnswerDeserializationError> {
+    if value.is_empty() {
+        return Err(AnswerDeserializationError::ParseAnswer(value.to_string()));
+    }
+
+    Answer::from_str(value).map_err(|_| AnswerDeserializationError::ParseAnswer(value.to_string()))
+}
+
+/// Parses the value of a low or high bounds entry in a serialized answers database.
+fn parse_bounds_value(value: &str) -> Result<i128, AnswerDeserializationError> {
+    value
+        .parse::<i128>()
+        .map_err(|_| AnswerDeserializationError::ParseInt(value.to_string()))
+}
+
 impl Default for Answers {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Represents an error when reading a serialized answers database.
 #[derive(Debug, Error)]
 pub enum AnswerDeserializationError {
-    #[error(
-        "expected type char followed by a space followed by the answer value, but got `{}`", .0
-    )]
-    UnknownLineFormat(String),
     #[error(
         "unknown answer type char `{}` when deserializing (expected `{}`, `{}`, `{}`, or `{}`)",
         .0,
@@ -314,14 +374,27 @@ pub enum AnswerDeserializationError {
         LOW_ANSWER_CHAR,
         HIGH_ANSWER_CHAR
     )]
-    UnknownAnswerType(char),
-    #[error("the low bound answer `{}` must be parsable as an i128 integer", .0)]
-    LowBoundRequiresInt(String),
-    #[error("the high bound answer `{}` must be parsable as an i128 integer", .0)]
-    HighBoundRequiresInt(String),
+    UnknownType(char),
+    #[error(
+        "expected type char followed by a space followed by the answer value on line {}", .0
+    )]
+    MissingSpace(usize),
+    #[error("the bounds answer `{}` must be parsable as an i128 integer", .0)]
+    ParseInt(String),
+    #[error("the answer `{}` is not a valid answer value", .0)]
+    ParseAnswer(String),
+    #[error("{}", .0)]
+    Io(#[from] std::io::Error),
 }
 
-#[derive(Serialize, Deserialize)]
+/// Represents an error when merging two answers databases.
+#[derive(Debug, Error, PartialEq)]
+pub enum AnswersMergeError {
+    #[error("cannot merge answers with different correct answers `{}` and `{}`", .0, .1)]
+    ConflictingCorrectAnswers(Answer, Answer),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Session {
     pub session_id: String,
     pub submit_wait_until: Option<chrono::DateTime<chrono::Utc>>,
@@ -341,6 +414,93 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn merge_disjoint_wrong_answers() {
+        let mut a = Answers::new();
+        a.add_wrong_answer(Answer::Int(1));
+        a.add_wrong_answer(Answer::from_str("abc").unwrap());
+
+        let mut b = Answers::new();
+        b.add_wrong_answer(Answer::Int(2));
+        b.add_wrong_answer(Answer::Int(1));
+
+        let merged = a.merge(b).unwrap();
+
+        assert_eq!(
+            merged.wrong_answers_ref(),
+            &vec![
+                Answer::Int(1),
+                Answer::String("abc".to_string()),
+                Answer::Int(2)
+            ]
+        );
+        assert_eq!(merged.correct_answer_ref(), &None);
+    }
+
+    #[test]
+    fn merge_keeps_correct_answer_from_either_side() {
+        let mut solved = Answers::new();
+        solved.set_correct_answer(Answer::Int(42));
+
+        let mut unsolved = Answers::new();
+        unsolved.add_wrong_answer(Answer::Int(7));
+
+        let merged = unsolved.clone().merge(solved.clone()).unwrap();
+        assert_eq!(merged.correct_answer_ref(), &Some(Answer::Int(42)));
+        assert_eq!(merged.wrong_answers_ref(), &vec![Answer::Int(7)]);
+
+        let merged = solved.clone().merge(unsolved).unwrap();
+        assert_eq!(merged.correct_answer_ref(), &Some(Answer::Int(42)));
+        assert_eq!(merged.wrong_answers_ref(), &vec![Answer::Int(7)]);
+
+        // Matching correct answers are not a conflict.
+        let merged = solved.clone().merge(solved).unwrap();
+        assert_eq!(merged.correct_answer_ref(), &Some(Answer::Int(42)));
+    }
+
+    #[test]
+    fn merge_conflicting_correct_answers_is_error() {
+        let mut a = Answers::new();
+        a.set_correct_answer(Answer::Int(42));
+
+        let mut b = Answers::new();
+        b.set_correct_answer(Answer::Int(43));
+
+        assert_eq!(
+            a.merge(b),
+            Err(AnswersMergeError::ConflictingCorrectAnswers(
+                Answer::Int(42),
+                Answer::Int(43)
+            ))
+        );
+    }
+
+    #[test]
+    fn merge_keeps_tightest_bounds() {
+        let mut a = Answers::new();
+        a.set_low_bounds(Answer::Int(10));
+        a.set_high_bounds(Answer::Int(100));
+
+        let mut b = Answers::new();
+        b.set_low_bounds(Answer::Int(20));
+        b.set_high_bounds(Answer::Int(200));
+
+        let merged = a.merge(b).unwrap();
+        assert_eq!(merged.low_bounds_ref(), &Some(20));
+        assert_eq!(merged.high_bounds_ref(), &Some(100));
+
+        let mut c = Answers::new();
+        c.set_high_bounds(Answer::Int(50));
+
+        let merged = merged.merge(c).unwrap();
+        assert_eq!(merged.low_bounds_ref(), &Some(20));
+        assert_eq!(merged.high_bounds_ref(), &Some(50));
+
+        let merged = Answers::new().merge(merged).unwrap();
+        assert_eq!(merged.low_bounds_ref(), &Some(20));
+        assert_eq!(merged.high_bounds_ref(), &Some(50));
+    }
+
     #[test]
     fn add_wrong_answers() {
         let mut answers = Answers::new();
@@ -359,6 +519,44 @@ mod tests {
         )
     }
 
+    #[test]
+    fn unsolved_answers_counts() {
+        let mut answers = Answers::new();
+
+        assert!(!answers.is_solved());
+        assert_eq!(answers.wrong_count(), 0);
+        assert_eq!(answers.attempt_count(), 0);
+
+        answers.add_wrong_answer(Answer::Int(10));
+
+        assert!(!answers.is_solved());
+        assert_eq!(answers.wrong_count(), 1);
+        assert_eq!(answers.attempt_count(), 1);
+    }
+
+    #[test]
+    fn solved_answers_counts() {
+        let mut answers = Answers::new();
+        answers.set_correct_answer(Answer::Int(42));
+
+        assert!(answers.is_solved());
+        assert_eq!(answers.wrong_count(), 0);
+        assert_eq!(answers.attempt_count(), 1);
+    }
+
+    #[test]
+    fn solved_answers_with_wrong_attempts_counts() {
+        let mut answers = Answers::new();
+        answers.add_wrong_answer(Answer::Int(1));
+        answers.add_wrong_answer(Answer::Int(2));
+        answers.add_wrong_answer(Answer::from_str("three").unwrap());
+        answers.set_correct_answer(Answer::Int(42));
+
+        assert!(answers.is_solved());
+        assert_eq!(answers.wrong_count(), 3);
+        assert_eq!(answers.attempt_count(), 4);
+    }
+
     #[test]
     fn correct_answer_when_checking() {
         let mut answers = Answers::new();
@@ -428,12 +626,21 @@ mod tests {
 
         answers.set_low_bounds(Answer::Int(90));
 
-        assert_eq!(answers.check(&Answer::Int(85)), Some(CheckResult::TooLow));
-        assert_eq!(answers.check(&Answer::Int(90)), Some(CheckResult::TooLow));
+        assert_eq!(
+            answers.check(&Answer::Int(85)),
+            Some(CheckResult::TooLow { hint: None })
+        );
+        assert_eq!(
+            answers.check(&Answer::Int(90)),
+            Some(CheckResult::TooLow { hint: None })
+        );
         assert!(answers.check(&Answer::Int(100)).is_none());
 
         answers.add_wrong_answer(Answer::Int(90));
-        assert_eq!(answers.check(&Answer::Int(90)), Some(CheckResult::TooLow));
+        assert_eq!(
+            answers.check(&Answer::Int(90)),
+            Some(CheckResult::TooLow { hint: None })
+        );
     }
 
     #[test]
@@ -443,12 +650,21 @@ mod tests {
 
         answers.set_high_bounds(Answer::Int(90));
 
-        assert_eq!(answers.check(&Answer::Int(100)), Some(CheckResult::TooHigh));
-        assert_eq!(answers.check(&Answer::Int(90)), Some(CheckResult::TooHigh));
+        assert_eq!(
+            answers.check(&Answer::Int(100)),
+            Some(CheckResult::TooHigh { hint: None })
+        );
+        assert_eq!(
+            answers.check(&Answer::Int(90)),
+            Some(CheckResult::TooHigh { hint: None })
+        );
         assert!(answers.check(&Answer::Int(85)).is_none());
 
         answers.add_wrong_answer(Answer::Int(90));
-        assert_eq!(answers.check(&Answer::Int(90)), Some(CheckResult::TooHigh));
+        assert_eq!(
+            answers.check(&Answer::Int(90)),
+            Some(CheckResult::TooHigh { hint: None })
+        );
     }
 
     #[test]
@@ -458,12 +674,24 @@ mod tests {
         answers.set_low_bounds(Answer::Int(96));
         answers.set_high_bounds(Answer::Int(103));
 
-        assert_eq!(answers.check(&Answer::Int(107)), Some(CheckResult::TooHigh));
-        assert_eq!(answers.check(&Answer::Int(103)), Some(CheckResult::TooHigh));
+        assert_eq!(
+            answers.check(&Answer::Int(107)),
+            Some(CheckResult::TooHigh { hint: None })
+        );
+        assert_eq!(
+            answers.check(&Answer::Int(103)),
+            Some(CheckResult::TooHigh { hint: None })
+        );
         assert_eq!(answers.check(&Answer::Int(100)), None);
         assert_eq!(answers.check(&Answer::Int(98)), None);
-        assert_eq!(answers.check(&Answer::Int(96)), Some(CheckResult::TooLow));
-        assert_eq!(answers.check(&Answer::Int(-5)), Some(CheckResult::TooLow));
+        assert_eq!(
+            answers.check(&Answer::Int(96)),
+            Some(CheckResult::TooLow { hint: None })
+        );
+        assert_eq!(
+            answers.check(&Answer::Int(-5)),
+            Some(CheckResult::TooLow { hint: None })
+        );
     }
 
     #[test]
@@ -478,18 +706,24 @@ mod tests {
 
         assert_eq!(
             answers.check(&Answer::from_str("55").unwrap()),
-            Some(CheckResult::TooHigh)
+            Some(CheckResult::TooHigh { hint: None })
+        );
+        assert_eq!(
+            answers.check(&Answer::Int(55)),
+            Some(CheckResult::TooHigh { hint: None })
         );
-        assert_eq!(answers.check(&Answer::Int(55)), Some(CheckResult::TooHigh));
 
         assert_eq!(answers.check(&Answer::Int(10)), None);
         assert_eq!(answers.check(&Answer::from_str("10").unwrap()), None);
 
         assert_eq!(
             answers.check(&Answer::from_str("-74").unwrap()),
-            Some(CheckResult::TooLow)
+            Some(CheckResult::TooLow { hint: None })
+        );
+        assert_eq!(
+            answers.check(&Answer::Int(-74)),
+            Some(CheckResult::TooLow { hint: None })
         );
-        assert_eq!(answers.check(&Answer::Int(-74)), Some(CheckResult::TooLow));
     }
 
     #[test]
@@ -520,14 +754,20 @@ mod tests {
         );
         assert_eq!(
             answers.check(&Answer::from_str("100").unwrap()),
-            Some(CheckResult::TooHigh)
+            Some(CheckResult::TooHigh { hint: None })
+        );
+        assert_eq!(
+            answers.check(&Answer::Int(100)),
+            Some(CheckResult::TooHigh { hint: None })
         );
-        assert_eq!(answers.check(&Answer::Int(100)), Some(CheckResult::TooHigh));
         assert_eq!(
             answers.check(&Answer::from_str("-100").unwrap()),
-            Some(CheckResult::TooLow)
+            Some(CheckResult::TooLow { hint: None })
+        );
+        assert_eq!(
+            answers.check(&Answer::Int(-100)),
+            Some(CheckResult::TooLow { hint: None })
         );
-        assert_eq!(answers.check(&Answer::Int(-100)), Some(CheckResult::TooLow));
     }
 
     #[test]
@@ -685,4 +925,79 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn deserialize_answers_unknown_type_err() {
+        assert!(matches!(
+            Answers::deserialize_from_str("= 12\n? 5\n"),
+            Err(AnswerDeserializationError::UnknownType('?'))
+        ));
+        assert!(matches!(
+            Answers::deserialize_from_str(" 5\n"),
+            Err(AnswerDeserializationError::UnknownType(' '))
+        ));
+    }
+
+    #[test]
+    fn deserialize_answers_missing_space_err() {
+        assert!(matches!(
+            Answers::deserialize_from_str("= 12\nX 1\nX5\n"),
+            Err(AnswerDeserializationError::MissingSpace(3))
+        ));
+    }
+
+    #[test]
+    fn deserialize_answers_parse_int_err() {
+        assert!(matches!(
+            Answers::deserialize_from_str("[ low\n"),
+            Err(AnswerDeserializationError::ParseInt(v)) if v == "low"
+        ));
+        assert!(matches!(
+            Answers::deserialize_from_str("] 1.5\n"),
+            Err(AnswerDeserializationError::ParseInt(v)) if v == "1.5"
+        ));
+    }
+
+    #[test]
+    fn deserialize_answers_parse_answer_err() {
+        assert!(matches!(
+            Answers::deserialize_from_str("= \n"),
+            Err(AnswerDeserializationError::ParseAnswer(v)) if v.is_empty()
+        ));
+        assert!(matches!(
+            Answers::deserialize_from_str("X 1\nX \n"),
+            Err(AnswerDeserializationError::ParseAnswer(v)) if v.is_empty()
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn answers_json_round_trip() {
+        let answers = Answers {
+            correct_answer: Some(Answer::Int(42)),
+            wrong_answers: vec![Answer::Int(-9), Answer::from("xyz")],
+            low_bounds: Some(-50),
+            high_bounds: Some(25),
+        };
+
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(
+            json,
+            r#"{"correct":"42","wrong":["-9","xyz"],"low":-50,"high":25}"#
+        );
+        assert_eq!(serde_json::from_str::<Answers>(&json).unwrap(), answers);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn answers_json_matches_text_format() {
+        let text = "= hello world\n[ -50\nX -9\nX 1\n";
+        let from_text = Answers::deserialize_from_str(text).unwrap();
+        let from_json: Answers =
+            serde_json::from_str(r#"{"correct": "hello world", "wrong": ["-9", "1"], "low": -50}"#)
+                .unwrap();
+
+        assert_eq!(from_json, from_text);
+        assert_eq!(from_json.serialize_to_string(), text);
+    }
 }
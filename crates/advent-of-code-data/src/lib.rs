@@ -9,6 +9,8 @@ pub mod cache;
 pub mod client;
 pub mod config;
 pub mod data;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 mod utils;
 
@@ -23,15 +25,50 @@ impl std::fmt::Display for Day {
     }
 }
 
+impl Day {
+    /// The first day of puzzles in an Advent of Code year.
+    pub const FIRST: Day = Day(1);
+    /// The last possible day of puzzles in an Advent of Code year.
+    pub const LAST: Day = Day(25);
+
+    /// Check if this day is in the range of possible Advent of Code days
+    /// [1, 25].
+    pub fn is_valid(self) -> bool {
+        (Self::FIRST..=Self::LAST).contains(&self)
+    }
+
+    /// Returns the day for `value`, or `None` if `value` is not in the range
+    /// of possible Advent of Code days [1, 25].
+    pub fn checked(value: usize) -> Option<Self> {
+        Some(Day(value)).filter(|d| d.is_valid())
+    }
+
+    /// Iterate the days from `start` up to and including `end_inclusive`. This
+    /// is a stable alternative to `start..=end_inclusive`, which needs the
+    /// unstable `Step` trait.
+    ///
+    /// ```
+    /// use advent_of_code_data::Day;
+    ///
+    /// let days: Vec<Day> = Day::range(Day(1), Day(3)).collect();
+    /// assert_eq!(days, vec![Day(1), Day(2), Day(3)]);
+    /// ```
+    pub fn range(start: Day, end_inclusive: Day) -> impl Iterator<Item = Day> {
+        (start.0..=end_inclusive.0).map(Day)
+    }
+}
+
 impl From<i32> for Day {
     fn from(value: i32) -> Self {
         assert!(value >= 0);
+        debug_assert!(Day(value as usize).is_valid(), "invalid day {value}");
         Day(value as usize)
     }
 }
 
 impl From<u32> for Day {
     fn from(value: u32) -> Self {
+        debug_assert!(Day(value as usize).is_valid(), "invalid day {value}");
         Day(value as usize)
     }
 }
@@ -47,9 +84,40 @@ impl std::fmt::Display for Year {
     }
 }
 
+impl Year {
+    /// The year of the first Advent of Code event.
+    pub const FIRST: Year = Year(2015);
+
+    /// Check if this year is the year of the first Advent of Code event or
+    /// later.
+    pub fn is_valid(self) -> bool {
+        self >= Self::FIRST
+    }
+
+    /// Returns the year for `value`, or `None` if `value` is before the first
+    /// Advent of Code event in 2015.
+    pub fn checked(value: usize) -> Option<Self> {
+        Some(Year(value)).filter(|y| y.is_valid())
+    }
+
+    /// Iterate every year starting with `start`. The iterator never ends, so
+    /// callers should limit it with `take` or `take_while`.
+    ///
+    /// ```
+    /// use advent_of_code_data::Year;
+    ///
+    /// let years: Vec<Year> = Year::range_from(Year(2023)).take(2).collect();
+    /// assert_eq!(years, vec![Year(2023), Year(2024)]);
+    /// ```
+    pub fn range_from(start: Year) -> impl Iterator<Item = Year> {
+        (start.0..).map(Year)
+    }
+}
+
 impl From<i32> for Year {
     fn from(value: i32) -> Self {
         assert!(value >= 0);
+        debug_assert!(Year(value as usize).is_valid(), "invalid year {value}");
         Year(value as usize)
     }
 }
@@ -62,14 +130,59 @@ impl From<Year> for i32 {
 
 /// Advent of Code puzzles are split into two parts - `One` and `Two`. Both
 /// parts will take the same input but typically produce different answers.
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Part {
     One,
     Two,
 }
 
+impl Part {
+    /// Returns a zero based index for this part, which is `0` for `One` and `1`
+    /// for `Two`.
+    pub fn index(self) -> usize {
+        match self {
+            Part::One => 0,
+            Part::Two => 1,
+        }
+    }
+
+    /// Returns the puzzle part number, which is `1` for `One` and `2` for `Two`.
+    pub fn as_number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    /// Returns the other part of the puzzle.
+    pub fn other(self) -> Part {
+        match self {
+            Part::One => Part::Two,
+            Part::Two => Part::One,
+        }
+    }
+
+    /// Iterate both parts in order, starting with `One`.
+    pub fn all() -> impl Iterator<Item = Part> {
+        [Part::One, Part::Two].into_iter()
+    }
+}
+
+/// Displays the part as a word (`One` or `Two`), or as a number (`1` or `2`)
+/// when the alternate flag is used.
+///
+/// ```
+/// use advent_of_code_data::Part;
+///
+/// assert_eq!(format!("{}", Part::Two), "Two");
+/// assert_eq!(format!("{:#}", Part::Two), "2");
+/// ```
 impl std::fmt::Display for Part {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{}", self.as_number());
+        }
+
         write!(
             f,
             "{}",
@@ -152,6 +265,23 @@ impl std::fmt::Display for Answer {
     }
 }
 
+/// Answers are serialized as strings, and deserialized with `Answer::from_str`
+/// which means numeric strings become `Answer::Int`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Answer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Answer {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Ok(Answer::from_str(&text).expect("Answer::from_str does not return Err"))
+    }
+}
+
 impl From<String> for Answer {
     fn from(value: String) -> Self {
         Self::String(value)
@@ -256,12 +386,120 @@ mod tests {
         assert_eq!(&format!("{day}"), "22");
     }
 
+    #[test]
+    fn day_bounds() {
+        assert!(!Day(0).is_valid());
+        assert!(Day(1).is_valid());
+        assert!(Day(25).is_valid());
+        assert!(!Day(26).is_valid());
+
+        assert_eq!(Day::checked(0), None);
+        assert_eq!(Day::checked(1), Some(Day(1)));
+        assert_eq!(Day::checked(25), Some(Day(25)));
+        assert_eq!(Day::checked(26), None);
+    }
+
+    #[test]
+    fn year_bounds() {
+        assert!(!Year(2014).is_valid());
+        assert!(Year(2015).is_valid());
+        assert!(Year(2030).is_valid());
+
+        assert_eq!(Year::checked(0), None);
+        assert_eq!(Year::checked(2014), None);
+        assert_eq!(Year::checked(2015), Some(Year(2015)));
+        assert_eq!(Year::checked(2024), Some(Year(2024)));
+    }
+
+    #[test]
+    fn day_range_includes_end() {
+        let days: Vec<Day> = Day::range(Day(1), Day(5)).collect();
+
+        assert_eq!(days.len(), 5);
+        assert_eq!(days.first(), Some(&Day(1)));
+        assert_eq!(days.last(), Some(&Day(5)));
+
+        assert_eq!(Day::range(Day::FIRST, Day::LAST).count(), 25);
+        assert_eq!(Day::range(Day(7), Day(7)).collect::<Vec<_>>(), vec![Day(7)]);
+        assert_eq!(Day::range(Day(7), Day(6)).count(), 0);
+    }
+
+    #[test]
+    fn year_range_from_start() {
+        assert_eq!(
+            Year::range_from(Year::FIRST)
+                .take_while(|y| *y <= Year(2018))
+                .collect::<Vec<_>>(),
+            vec![Year(2015), Year(2016), Year(2017), Year(2018)]
+        );
+    }
+
+    #[test]
+    fn convert_valid_day_and_year() {
+        assert_eq!(Day::from(1), Day(1));
+        assert_eq!(Day::from(25_u32), Day(25));
+        assert_eq!(Year::from(2015), Year(2015));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn convert_invalid_day_asserts() {
+        let _ = Day::from(26_u32);
+    }
+
     #[test]
     fn print_part() {
         assert_eq!(&format!("{}", Part::One), "One");
         assert_eq!(&format!("{}", Part::Two), "Two");
     }
 
+    #[test]
+    fn print_part_as_number() {
+        assert_eq!(&format!("{:#}", Part::One), "1");
+        assert_eq!(&format!("{:#}", Part::Two), "2");
+    }
+
+    #[test]
+    fn part_as_number() {
+        assert_eq!(Part::One.as_number(), 1);
+        assert_eq!(Part::Two.as_number(), 2);
+    }
+
+    #[test]
+    fn part_index() {
+        assert_eq!(Part::One.index(), 0);
+        assert_eq!(Part::Two.index(), 1);
+    }
+
+    #[test]
+    fn other_part() {
+        assert_eq!(Part::One.other(), Part::Two);
+        assert_eq!(Part::Two.other(), Part::One);
+
+        for part in Part::all() {
+            assert_eq!(part.other().other(), part);
+        }
+    }
+
+    #[test]
+    fn all_parts() {
+        assert_eq!(Part::all().collect::<Vec<_>>(), vec![Part::One, Part::Two]);
+        assert!(Part::all().enumerate().all(|(i, p)| p.index() == i));
+    }
+
+    #[test]
+    fn parts_are_ordered_map_keys() {
+        let answers: std::collections::BTreeMap<Part, i32> =
+            [(Part::Two, 2), (Part::One, 1)].into_iter().collect();
+
+        assert_eq!(
+            answers.keys().collect::<Vec<_>>(),
+            vec![&Part::One, &Part::Two]
+        );
+        assert!(Part::One < Part::Two);
+    }
+
     #[test]
     fn print_answer() {
         assert_eq!(
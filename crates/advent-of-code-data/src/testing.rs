@@ -0,0 +1,260 @@
+//! In-memory implementations of the cache traits for use in tests.
+//!
+//! These types never touch the file system, so tests that use them do not need
+//! a temporary directory. Enable the `testing` feature to use them outside of
+//! this crate.
+use std::{cell::RefCell, collections::HashMap};
+
+use crate::{
+    cache::{CacheError, PuzzleCache, SessionCache},
+    data::{Answers, Session},
+    Day, Part, Year,
+};
+
+/// A `PuzzleCache` that stores inputs and answers in memory.
+#[derive(Debug, Default)]
+pub struct FakePuzzleCache {
+    inputs: RefCell<HashMap<(Day, Year), String>>,
+    answers: RefCell<HashMap<(Part, Day, Year), Answers>>,
+}
+
+impl FakePuzzleCache {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a builder for a cache that is populated with inputs and answers
+    /// before it is used.
+    pub fn builder() -> FakePuzzleCacheBuilder {
+        Default::default()
+    }
+
+    /// Add input for the given day and year to the cache.
+    pub fn pre_load_input<S: Into<String>>(&self, day: Day, year: Year, text: S) {
+        self.inputs.borrow_mut().insert((day, year), text.into());
+    }
+
+    /// Add answers for the given part, day and year to the cache.
+    pub fn pre_load_answers(&self, part: Part, day: Day, year: Year, answers: Answers) {
+        self.answers.borrow_mut().insert((part, day, year), answers);
+    }
+}
+
+impl PuzzleCache for FakePuzzleCache {
+    fn load_input(&self, day: Day, year: Year) -> Result<Option<String>, CacheError> {
+        Ok(self.inputs.borrow().get(&(day, year)).cloned())
+    }
+
+    fn load_answers(
+        &self,
+        part: Part,
+        day: Day,
+        year: Year,
+    ) -> Result<Option<Answers>, CacheError> {
+        Ok(self.answers.borrow().get(&(part, day, year)).cloned())
+    }
+
+    fn save_input(&self, input: &str, day: Day, year: Year) -> Result<(), CacheError> {
+        self.pre_load_input(day, year, input);
+        Ok(())
+    }
+
+    fn save_answers(
+        &self,
+        answers: &Answers,
+        part: Part,
+        day: Day,
+        year: Year,
+    ) -> Result<(), CacheError> {
+        self.pre_load_answers(part, day, year, answers.clone());
+        Ok(())
+    }
+}
+
+/// Creates a `FakePuzzleCache` that is populated with inputs and answers.
+#[derive(Debug, Default)]
+pub struct FakePuzzleCacheBuilder {
+    cache: FakePuzzleCache,
+}
+
+impl FakePuzzleCacheBuilder {
+    /// Add input for the given day and year to the cache.
+    pub fn with_input<S: Into<String>>(self, day: Day, year: Year, text: S) -> Self {
+        self.cache.pre_load_input(day, year, text);
+        self
+    }
+
+    /// Add answers for the given part, day and year to the cache.
+    pub fn with_answers(self, part: Part, day: Day, year: Year, answers: Answers) -> Self {
+        self.cache.pre_load_answers(part, day, year, answers);
+        self
+    }
+
+    pub fn build(self) -> FakePuzzleCache {
+        self.cache
+    }
+}
+
+/// A `SessionCache` that stores sessions in memory.
+#[derive(Debug, Default)]
+pub struct FakeSessionCache {
+    sessions: RefCell<HashMap<String, Session>>,
+}
+
+impl FakeSessionCache {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a session to the cache, replacing any existing session with the same
+    /// session id.
+    pub fn pre_load_session(&self, session: Session) {
+        self.sessions
+            .borrow_mut()
+            .insert(session.session_id.clone(), session);
+    }
+}
+
+impl SessionCache for FakeSessionCache {
+    fn try_load(&self, session_id: &str) -> Result<Option<Session>, CacheError> {
+        Ok(self.sessions.borrow().get(session_id).cloned())
+    }
+
+    fn save(&self, session: &Session) -> Result<(), CacheError> {
+        self.pre_load_session(session.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{data::Puzzle, Answer};
+
+    use super::*;
+
+    #[test]
+    fn puzzle_cache_is_empty() {
+        let cache = FakePuzzleCache::new();
+
+        assert_eq!(cache.load_input(Day(1), Year(2020)).unwrap(), None);
+        assert_eq!(
+            cache.load_answers(Part::One, Day(1), Year(2020)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn puzzle_cache_loads_pre_loaded_data() {
+        let cache = FakePuzzleCache::new();
+        let mut answers = Answers::new();
+        answers.set_correct_answer(Answer::Int(42));
+
+        cache.pre_load_input(Day(3), Year(2021), "hello world");
+        cache.pre_load_answers(Part::Two, Day(3), Year(2021), answers.clone());
+
+        assert_eq!(
+            cache.load_input(Day(3), Year(2021)).unwrap(),
+            Some("hello world".to_string())
+        );
+        assert_eq!(cache.load_input(Day(4), Year(2021)).unwrap(), None);
+
+        assert_eq!(
+            cache.load_answers(Part::Two, Day(3), Year(2021)).unwrap(),
+            Some(answers)
+        );
+        assert_eq!(
+            cache.load_answers(Part::One, Day(3), Year(2021)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn puzzle_cache_builder_loads_data() {
+        let mut answers = Answers::new();
+        answers.add_wrong_answer(Answer::Int(5));
+
+        let cache = FakePuzzleCache::builder()
+            .with_input(Day(1), Year(2015), "first")
+            .with_input(Day(2), Year(2015), "second")
+            .with_answers(Part::One, Day(2), Year(2015), answers.clone())
+            .build();
+
+        assert_eq!(
+            cache.load_input(Day(1), Year(2015)).unwrap(),
+            Some("first".to_string())
+        );
+        assert_eq!(
+            cache.load_input(Day(2), Year(2015)).unwrap(),
+            Some("second".to_string())
+        );
+        assert_eq!(
+            cache.load_answers(Part::One, Day(2), Year(2015)).unwrap(),
+            Some(answers)
+        );
+        assert_eq!(
+            cache.load_answers(Part::One, Day(1), Year(2015)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn puzzle_cache_saves_puzzles() {
+        let cache = FakePuzzleCache::new();
+        let mut part_one_answers = Answers::new();
+        part_one_answers.add_wrong_answer(Answer::Int(7));
+
+        cache
+            .save(Puzzle {
+                day: Day(9),
+                year: Year(2022),
+                input: "input text".to_string(),
+                part_one_answers: part_one_answers.clone(),
+                part_two_answers: Answers::new(),
+            })
+            .unwrap();
+
+        assert_eq!(
+            cache.load_input(Day(9), Year(2022)).unwrap(),
+            Some("input text".to_string())
+        );
+        assert_eq!(
+            cache.load_answers(Part::One, Day(9), Year(2022)).unwrap(),
+            Some(part_one_answers)
+        );
+        assert_eq!(
+            cache.load_answers(Part::Two, Day(9), Year(2022)).unwrap(),
+            Some(Answers::new())
+        );
+
+        // Saving again overwrites the previous values.
+        cache.save_input("new text", Day(9), Year(2022)).unwrap();
+        assert_eq!(
+            cache.load_input(Day(9), Year(2022)).unwrap(),
+            Some("new text".to_string())
+        );
+    }
+
+    #[test]
+    fn session_cache_loads_and_saves_sessions() {
+        let cache = FakeSessionCache::new();
+        assert!(cache.try_load("abc").unwrap().is_none());
+
+        // `load` creates a new session when there is nothing cached.
+        let session = cache.load("abc").unwrap();
+        assert_eq!(session.session_id, "abc");
+        assert!(session.submit_wait_until.is_none());
+
+        let mut session = Session::new("abc");
+        session.submit_wait_until = Some(chrono::Utc::now());
+        cache.pre_load_session(session.clone());
+
+        assert_eq!(
+            cache.try_load("abc").unwrap().unwrap().submit_wait_until,
+            session.submit_wait_until
+        );
+        assert!(cache.try_load("xyz").unwrap().is_none());
+
+        cache.save(&Session::new("xyz")).unwrap();
+        assert!(cache.try_load("xyz").unwrap().is_some());
+    }
+}
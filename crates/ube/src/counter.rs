@@ -1,29 +1,55 @@
 use std::{collections::HashMap, hash::Hash};
 
+/// Counts how many times each distinct element has been added, similar to Python's
+/// `collections.Counter`.
+///
+/// Elements with the same count are ordered by when they were first counted.
 pub struct Counter<T> {
-    counters: HashMap<T, usize>,
+    counters: HashMap<T, Entry>,
+    next_order: usize,
+}
+
+struct Entry {
+    count: usize,
+    /// Used to order elements that have the same count.
+    order: usize,
 }
 
 impl<T> Counter<T> {
     pub fn new() -> Self {
         Self {
             counters: Default::default(),
+            next_order: 0,
         }
     }
-}
 
-impl<T> Counter<T>
-where
-    T: Clone,
-{
-    pub fn most_common(&self) -> Vec<(T, usize)> {
-        let mut elements = self
-            .counters
-            .iter()
-            .map(|(k, v)| (k.clone(), *v))
-            .collect::<Vec<_>>();
-        elements.sort_by_key(|a| std::cmp::Reverse(a.1));
+    /// Get the sum of the counts of every element.
+    pub fn total(&self) -> usize {
+        self.counters.values().map(|e| e.count).sum()
+    }
+
+    /// Get the `n` elements with the highest counts, ordered from the highest count to the lowest.
+    pub fn most_common(&self, n: usize) -> Vec<(&T, &usize)> {
+        let mut elements = self.counters.iter().collect::<Vec<_>>();
+        elements.sort_by_key(|(_, e)| (std::cmp::Reverse(e.count), e.order));
+
         elements
+            .into_iter()
+            .take(n)
+            .map(|(k, e)| (k, &e.count))
+            .collect()
+    }
+
+    /// Get the `n` elements with the lowest counts, ordered from the lowest count to the highest.
+    pub fn least_common(&self, n: usize) -> Vec<(&T, &usize)> {
+        let mut elements = self.counters.iter().collect::<Vec<_>>();
+        elements.sort_by_key(|(_, e)| (e.count, e.order));
+
+        elements
+            .into_iter()
+            .take(n)
+            .map(|(k, e)| (k, &e.count))
+            .collect()
     }
 }
 
@@ -32,14 +58,26 @@ where
     T: Hash + Eq,
 {
     pub fn add(&mut self, v: T) -> usize {
-        let count = self.counters.entry(v).or_default();
-        *count += 1;
-
-        *count
+        self.add_count(v, 1)
     }
 
     pub fn count(&self, v: &T) -> usize {
-        *self.counters.get(v).unwrap_or(&0)
+        self.counters.get(v).map_or(0, |e| e.count)
+    }
+
+    /// Increase the count of `v` by `count`, returning the new count.
+    fn add_count(&mut self, v: T, count: usize) -> usize {
+        let entry = self.counters.entry(v).or_insert_with(|| {
+            self.next_order += 1;
+
+            Entry {
+                count: 0,
+                order: self.next_order,
+            }
+        });
+
+        entry.count += count;
+        entry.count
     }
 }
 
@@ -49,6 +87,80 @@ impl<T> Default for Counter<T> {
     }
 }
 
+impl<T> FromIterator<T> for Counter<T>
+where
+    T: Hash + Eq,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut c = Self::new();
+        c.extend(iter);
+        c
+    }
+}
+
+/// Adds each element to the counter, incrementing any existing counts.
+impl<T> Extend<T> for Counter<T>
+where
+    T: Hash + Eq,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for v in iter {
+            self.add(v);
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Counter<T>
+where
+    T: Hash + Eq,
+{
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+/// Multiset union, where the count of each element is the sum of its counts in both counters.
+impl<T> std::ops::Add for Counter<T>
+where
+    T: Hash + Eq,
+{
+    type Output = Counter<T>;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        let mut rhs = rhs.counters.into_iter().collect::<Vec<_>>();
+        rhs.sort_by_key(|(_, e)| e.order);
+
+        for (v, e) in rhs {
+            self.add_count(v, e.count);
+        }
+
+        self
+    }
+}
+
+/// Multiset difference, where the count of each element is reduced by its count in `rhs`. Counts
+/// never go below zero, and elements whose count drops to zero are removed.
+impl<T> std::ops::Sub for Counter<T>
+where
+    T: Hash + Eq,
+{
+    type Output = Counter<T>;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        for (v, e) in rhs.counters {
+            if let Some(entry) = self.counters.get_mut(&v) {
+                entry.count = entry.count.saturating_sub(e.count);
+
+                if entry.count == 0 {
+                    self.counters.remove(&v);
+                }
+            }
+        }
+
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,4 +179,126 @@ mod tests {
         assert_eq!(c.count(&'a'), 2);
         assert_eq!(c.count(&'b'), 0);
     }
+
+    #[test]
+    fn empty_counter() {
+        let c: Counter<char> = Default::default();
+
+        assert_eq!(c.total(), 0);
+        assert!(c.most_common(3).is_empty());
+        assert!(c.least_common(3).is_empty());
+    }
+
+    #[test]
+    fn total_sums_all_counts() {
+        let c: Counter<char> = "mississippi".chars().collect();
+
+        assert_eq!(c.total(), 11);
+        assert_eq!(c.count(&'s'), 4);
+        assert_eq!(c.count(&'m'), 1);
+    }
+
+    #[test]
+    fn most_common_orders_by_count() {
+        let c: Counter<char> = "abbcccdddd".chars().collect();
+
+        assert_eq!(c.most_common(2), vec![(&'d', &4), (&'c', &3)]);
+        assert_eq!(
+            c.most_common(10),
+            vec![(&'d', &4), (&'c', &3), (&'b', &2), (&'a', &1)]
+        );
+        assert!(c.most_common(0).is_empty());
+    }
+
+    #[test]
+    fn most_common_ties_use_first_counted_order() {
+        let c: Counter<char> = "mississippi".chars().collect();
+
+        // 'i' and 's' both appear four times, and 'i' was counted first.
+        assert_eq!(
+            c.most_common(4),
+            vec![(&'i', &4), (&'s', &4), (&'p', &2), (&'m', &1)]
+        );
+    }
+
+    #[test]
+    fn least_common_orders_by_count() {
+        let c: Counter<char> = "ccbbbaad".chars().collect();
+
+        assert_eq!(c.least_common(1), vec![(&'d', &1)]);
+        assert_eq!(
+            c.least_common(4),
+            vec![(&'d', &1), (&'c', &2), (&'a', &2), (&'b', &3)]
+        );
+    }
+
+    #[test]
+    fn collect_counts_duplicates() {
+        let c: Counter<i32> = [3, 1, 3, 2, 3, 1].iter().copied().collect();
+
+        assert_eq!(c.count(&1), 2);
+        assert_eq!(c.count(&2), 1);
+        assert_eq!(c.count(&3), 3);
+        assert_eq!(c.count(&4), 0);
+        assert_eq!(c.total(), 6);
+    }
+
+    #[test]
+    fn extend_adds_to_existing_counts() {
+        let mut c: Counter<char> = "aab".chars().collect();
+        c.extend("abc".chars());
+
+        assert_eq!(c.count(&'a'), 3);
+        assert_eq!(c.count(&'b'), 2);
+        assert_eq!(c.count(&'c'), 1);
+        assert_eq!(c.most_common(3), vec![(&'a', &3), (&'b', &2), (&'c', &1)]);
+    }
+
+    #[test]
+    fn from_vec() {
+        let c = Counter::from(vec!["x", "y", "x"]);
+
+        assert_eq!(c.count(&"x"), 2);
+        assert_eq!(c.count(&"y"), 1);
+        assert_eq!(c.total(), 3);
+    }
+
+    #[test]
+    fn add_sums_counts() {
+        let a: Counter<char> = "aab".chars().collect();
+        let b: Counter<char> = "bcc".chars().collect();
+
+        let c = a + b;
+
+        assert_eq!(c.count(&'a'), 2);
+        assert_eq!(c.count(&'b'), 2);
+        assert_eq!(c.count(&'c'), 2);
+        assert_eq!(c.total(), 6);
+        assert_eq!(c.most_common(3), vec![(&'a', &2), (&'b', &2), (&'c', &2)]);
+    }
+
+    #[test]
+    fn add_empty_counter() {
+        let a: Counter<char> = "ab".chars().collect();
+        let c = a + Counter::new();
+
+        assert_eq!(c.most_common(2), vec![(&'a', &1), (&'b', &1)]);
+    }
+
+    #[test]
+    fn sub_floors_at_zero() {
+        let a: Counter<char> = "aaabbc".chars().collect();
+        let b: Counter<char> = "abbbbd".chars().collect();
+
+        let c = a - b;
+
+        assert_eq!(c.count(&'a'), 2);
+        assert_eq!(c.count(&'b'), 0);
+        assert_eq!(c.count(&'c'), 1);
+        assert_eq!(c.count(&'d'), 0);
+        assert_eq!(c.total(), 3);
+
+        // Elements with a count of zero are removed.
+        assert_eq!(c.least_common(10), vec![(&'c', &1), (&'a', &2)]);
+    }
 }
@@ -1,11 +1,35 @@
-use std::collections::{HashMap, VecDeque};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+};
 
 use slotmap::{SecondaryMap, SlotMap};
+use thiserror::Error;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Error, PartialEq)]
+pub enum ShortestPathError {
+    #[error("the edge from node {} to node {} has a negative weight {}", .from, .to, .weight)]
+    NegativeWeight { from: usize, to: usize, weight: i64 },
+}
+
+/// A directed edge to another node in the graph. Edges without a weight have a weight of one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Edge {
+    pub to: NodeKey,
+    pub weight: Option<i64>,
+}
+
+impl Edge {
+    /// Get the cost of following this edge.
+    pub fn cost(&self) -> i64 {
+        self.weight.unwrap_or(1)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Node {
     id: usize,
-    edges: Vec<NodeKey>,
+    edges: Vec<Edge>,
 }
 
 impl Node {
@@ -16,14 +40,29 @@ impl Node {
         }
     }
 
-    pub fn edges(&self) -> EdgeIter {
+    pub fn edges(&self) -> EdgeIter<'_> {
         EdgeIter::new(&self.edges)
     }
+
+    /// Get the edges leaving this node along with their weights.
+    pub fn weighted_edges(&self) -> impl Iterator<Item = &Edge> {
+        self.edges.iter()
+    }
+
+    /// Get the cost of the cheapest edge from this node to `to`, or `None` if there is no such
+    /// edge.
+    pub fn edge_cost(&self, to: NodeKey) -> Option<i64> {
+        self.edges
+            .iter()
+            .filter(|e| e.to == to)
+            .map(Edge::cost)
+            .min()
+    }
 }
 
 slotmap::new_key_type! { pub struct NodeKey; }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Graph {
     nodes: SlotMap<NodeKey, Node>,
     node_to_name: SecondaryMap<NodeKey, String>, // TODO: make this optional, for debugging.
@@ -50,6 +89,182 @@ impl Graph {
             None
         }
     }
+
+    /// Remove the node `nk` and every edge from other nodes to it. Returns the removed node, or
+    /// `None` if the node is not in the graph.
+    pub fn remove_node(&mut self, nk: NodeKey) -> Option<Node> {
+        let node = self.nodes.remove(nk)?;
+        self.node_to_name.remove(nk);
+
+        for other in self.nodes.values_mut() {
+            other.edges.retain(|e| e.to != nk);
+        }
+
+        Some(node)
+    }
+
+    /// Find the lowest cost path from `start` to `end` using Dijkstra's algorithm. Edges without a
+    /// weight have a cost of one.
+    ///
+    /// Returns the total cost of the path and the nodes along it (including `start` and `end`), or
+    /// `None` if `end` cannot be reached from `start`. An error is returned if any edge in the
+    /// graph has a negative weight.
+    pub fn shortest_path(
+        &self,
+        start: NodeKey,
+        end: NodeKey,
+    ) -> Result<Option<(i64, Vec<NodeKey>)>, ShortestPathError> {
+        for node in self.nodes.values() {
+            if let Some(edge) = node.edges.iter().find(|e| e.cost() < 0) {
+                return Err(ShortestPathError::NegativeWeight {
+                    from: node.id,
+                    to: self.nodes[edge.to].id,
+                    weight: edge.cost(),
+                });
+            }
+        }
+
+        let mut costs: SecondaryMap<NodeKey, i64> = Default::default();
+        let mut previous: SecondaryMap<NodeKey, NodeKey> = Default::default();
+        let mut to_visit = BinaryHeap::new();
+
+        costs.insert(start, 0);
+        to_visit.push(Reverse((0, start)));
+
+        while let Some(Reverse((cost, nk))) = to_visit.pop() {
+            if nk == end {
+                return Ok(Some((cost, reconstruct_path(&previous, end))));
+            }
+
+            // Skip stale queue entries for nodes that were reached by a cheaper path.
+            if cost > costs[nk] {
+                continue;
+            }
+
+            for edge in &self.nodes[nk].edges {
+                let next_cost = cost + edge.cost();
+
+                if costs.get(edge.to).is_none_or(|c| next_cost < *c) {
+                    costs.insert(edge.to, next_cost);
+                    previous.insert(edge.to, nk);
+                    to_visit.push(Reverse((next_cost, edge.to)));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Find the lowest cost path from `start` to `goal` using the A* search algorithm. Edges
+    /// without a weight have a cost of one, and edges must not have negative weights.
+    ///
+    /// `heuristic` estimates the cost from a node to `goal`, and must never overestimate the real
+    /// cost for the returned path to be the cheapest one. Nodes are explored in order of their cost
+    /// from `start` plus their estimated cost to `goal`, so a good estimate explores far fewer
+    /// nodes than `shortest_path`.
+    ///
+    /// Returns the total cost of the path and the nodes along it (including `start` and `goal`),
+    /// or `None` if `goal` cannot be reached from `start`.
+    pub fn astar<F>(
+        &self,
+        start: NodeKey,
+        goal: NodeKey,
+        heuristic: F,
+    ) -> Option<(i64, Vec<NodeKey>)>
+    where
+        F: Fn(NodeKey) -> i64,
+    {
+        let mut costs: SecondaryMap<NodeKey, i64> = Default::default();
+        let mut previous: SecondaryMap<NodeKey, NodeKey> = Default::default();
+        let mut to_visit = BinaryHeap::new();
+
+        costs.insert(start, 0);
+        to_visit.push(Reverse((heuristic(start), 0, start)));
+
+        while let Some(Reverse((_, cost, nk))) = to_visit.pop() {
+            if nk == goal {
+                return Some((cost, reconstruct_path(&previous, goal)));
+            }
+
+            // Skip stale queue entries for nodes that were reached by a cheaper path.
+            if cost > costs[nk] {
+                continue;
+            }
+
+            for edge in &self.nodes[nk].edges {
+                let next_cost = cost + edge.cost();
+
+                if costs.get(edge.to).is_none_or(|c| next_cost < *c) {
+                    costs.insert(edge.to, next_cost);
+                    previous.insert(edge.to, nk);
+                    to_visit.push(Reverse((
+                        next_cost + heuristic(edge.to),
+                        next_cost,
+                        edge.to,
+                    )));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl Graph {
+    /// Get the name of every node, using `$id` for nodes that were not given a name.
+    fn node_names(&self) -> SecondaryMap<NodeKey, String> {
+        let mut names = self.node_to_name.clone();
+
+        for k in self.nodes.keys() {
+            if !names.contains_key(k) {
+                names.insert(k, format!("${}", self.nodes[k].id));
+            }
+        }
+
+        names
+    }
+
+    /// Serialize the graph as a Graphviz DOT `digraph`. Nodes and edges are sorted by name so the
+    /// output is deterministic, and edges with a weight are labeled with it.
+    ///
+    /// The output can be rendered with `dot -Tpng`.
+    pub fn to_dot(&self) -> String {
+        fn quote(name: &str) -> String {
+            format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+
+        let names = self.node_names();
+        let mut sorted_nodes = names.iter().collect::<Vec<_>>();
+        sorted_nodes.sort_by_key(|n| n.1);
+
+        let mut dot = String::from("digraph {\n");
+
+        for (_, name) in &sorted_nodes {
+            dot.push_str(&format!("    {};\n", quote(name)));
+        }
+
+        for (nk, name) in &sorted_nodes {
+            let mut edges = self.nodes[*nk].edges.iter().collect::<Vec<_>>();
+            edges.sort_by_key(|e| &names[e.to]);
+
+            for edge in edges {
+                dot.push_str(&format!(
+                    "    {} -> {}",
+                    quote(name),
+                    quote(&names[edge.to])
+                ));
+
+                if let Some(weight) = edge.weight {
+                    dot.push_str(&format!(" [label=\"{weight}\"]"));
+                }
+
+                dot.push_str(";\n");
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
 }
 
 impl Default for Graph {
@@ -75,13 +290,7 @@ impl PartialEq for Graph {
 impl std::fmt::Display for Graph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // Ensure all of the nodes have a name for printing.
-        let mut names = self.node_to_name.clone();
-
-        for k in self.nodes.keys() {
-            if !names.contains_key(k) {
-                names[k] = format!("${}", self.nodes[k].id);
-            }
-        }
+        let names = self.node_names();
 
         // Sort node in alphabetical order for predictable output.
         let mut sorted_nodes = names.iter().collect::<Vec<_>>();
@@ -96,11 +305,11 @@ impl std::fmt::Display for Graph {
 
             write!(f, "\t\"{node_name}\": [")?;
 
-            for (edge_index, edge_nk) in edges.iter().enumerate() {
+            for (edge_index, edge) in edges.iter().enumerate() {
                 write!(
                     f,
                     "\"{}{}\"",
-                    names.get(*edge_nk).unwrap(),
+                    names.get(edge.to).unwrap(),
                     if edge_index < edges.len() - 1 {
                         ","
                     } else {
@@ -117,12 +326,12 @@ impl std::fmt::Display for Graph {
 }
 
 pub struct EdgeIter<'a> {
-    edges: &'a Vec<NodeKey>,
+    edges: &'a [Edge],
     next_i: usize,
 }
 
 impl<'a> EdgeIter<'a> {
-    pub fn new(edges: &'a Vec<NodeKey>) -> Self {
+    pub fn new(edges: &'a [Edge]) -> Self {
         Self { edges, next_i: 0 }
     }
 }
@@ -132,7 +341,7 @@ impl Iterator for EdgeIter<'_> {
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.next_i < self.edges.len() {
-            let nk = self.edges[self.next_i];
+            let nk = self.edges[self.next_i].to;
             self.next_i += 1;
 
             Some(nk)
@@ -213,16 +422,31 @@ impl GraphBuilder {
 
         for to_node_name in n_builder.unidir_edges {
             let to_nk = self.register_node(Some(to_node_name));
-            self.add_edge(nk, to_nk);
+            self.add_edge(nk, to_nk, None);
         }
 
         for to_node_name in n_builder.bidir_edges {
             let to_nk = self.register_node(Some(to_node_name));
-            self.add_edge(nk, to_nk);
-            self.add_edge(to_nk, nk);
+            self.add_edge(nk, to_nk, None);
+            self.add_edge(to_nk, nk, None);
         }
     }
 
+    /// Add a directed edge with a cost of `weight` between the nodes named `from` and `to`. Nodes
+    /// are created if they do not exist yet. Adding another edge between the same nodes creates a
+    /// parallel edge rather than replacing the existing one.
+    pub fn add_weighted_edge<S: Into<String>>(&mut self, from: S, to: S, weight: i64) {
+        let from_nk = self.register_node(Some(from.into()));
+        let to_nk = self.register_node(Some(to.into()));
+
+        self.add_edge(from_nk, to_nk, Some(weight));
+    }
+
+    pub fn with_weighted_edge<S: Into<String>>(mut self, from: S, to: S, weight: i64) -> Self {
+        self.add_weighted_edge(from, to, weight);
+        self
+    }
+
     pub fn with_node<F>(mut self, builder_fn: F) -> Self
     where
         F: FnOnce(NodeBuilder) -> NodeBuilder,
@@ -231,9 +455,13 @@ impl GraphBuilder {
         self
     }
 
-    fn add_edge(&mut self, a: NodeKey, b: NodeKey) {
-        if !self.nodes[a].edges.contains(&b) {
-            self.nodes[a].edges.push(b);
+    /// Add an edge from `a` to `b`. Weighted edges are always added so that parallel edges can be
+    /// built, while an unweighted edge is skipped if `a` already has an edge to `b`.
+    fn add_edge(&mut self, a: NodeKey, b: NodeKey, weight: Option<i64>) {
+        let edges = &mut self.nodes[a].edges;
+
+        if weight.is_some() || !edges.iter().any(|e| e.to == b) {
+            edges.push(Edge { to: b, weight });
         }
     }
 
@@ -311,9 +539,9 @@ fn is_acyclic_dfs(
 ) -> bool {
     statuses[nk] = VisitStatus::Active;
 
-    for to_k in &g.nodes[nk].edges {
-        if statuses[*to_k] == VisitStatus::Active
-            || (statuses[*to_k] == VisitStatus::New && !is_acyclic_dfs(*to_k, g, statuses))
+    for to_k in g.nodes[nk].edges() {
+        if statuses[to_k] == VisitStatus::Active
+            || (statuses[to_k] == VisitStatus::New && !is_acyclic_dfs(to_k, g, statuses))
         {
             return false;
         }
@@ -323,6 +551,140 @@ fn is_acyclic_dfs(
     true
 }
 
+/// Error returned when an operation that requires a directed acyclic graph finds a cycle.
+#[derive(Debug, Error, PartialEq)]
+#[error("the graph contains a cycle of {} nodes", .cycle.len())]
+pub struct CycleError {
+    /// The nodes that form the first cycle that was found, in edge order. The last node has an
+    /// edge back to the first node.
+    pub cycle: Vec<NodeKey>,
+}
+
+/// Orders the nodes of `g` so that every node comes before the nodes its edges point to, or returns
+/// the first cycle found if `g` is not acyclic.
+pub fn topological_sort(g: &Graph) -> Result<Vec<NodeKey>, CycleError> {
+    let mut statuses: SecondaryMap<NodeKey, VisitStatus> = Default::default();
+    let mut path: Vec<NodeKey> = Vec::new();
+    let mut order: Vec<NodeKey> = Vec::with_capacity(g.nodes.len());
+
+    for nk in g.nodes.keys() {
+        statuses.insert(nk, VisitStatus::New);
+    }
+
+    for nk in g.nodes.keys() {
+        if statuses[nk] == VisitStatus::New {
+            topological_sort_dfs(nk, g, &mut statuses, &mut path, &mut order)?;
+        }
+    }
+
+    // Nodes are finished after all of the nodes they point to, so the post-order is reversed.
+    order.reverse();
+    Ok(order)
+}
+
+fn topological_sort_dfs(
+    nk: NodeKey,
+    g: &Graph,
+    statuses: &mut SecondaryMap<NodeKey, VisitStatus>,
+    path: &mut Vec<NodeKey>,
+    order: &mut Vec<NodeKey>,
+) -> Result<(), CycleError> {
+    statuses[nk] = VisitStatus::Active;
+    path.push(nk);
+
+    for to_k in g.nodes[nk].edges() {
+        match statuses[to_k] {
+            VisitStatus::New => topological_sort_dfs(to_k, g, statuses, path, order)?,
+            VisitStatus::Active => {
+                // `to_k` is still on the current path so the path from it back to `nk` is a cycle.
+                let cycle_start = path.iter().position(|p| *p == to_k).unwrap();
+                return Err(CycleError {
+                    cycle: path[cycle_start..].to_vec(),
+                });
+            }
+            VisitStatus::Finished => {}
+        }
+    }
+
+    path.pop();
+    statuses[nk] = VisitStatus::Finished;
+    order.push(nk);
+
+    Ok(())
+}
+
+/// Per node bookkeeping for Tarjan's strongly connected components algorithm.
+struct TarjanState {
+    next_index: usize,
+    indices: SecondaryMap<NodeKey, usize>,
+    low_links: SecondaryMap<NodeKey, usize>,
+    stack: Vec<NodeKey>,
+    on_stack: SecondaryMap<NodeKey, bool>,
+    components: Vec<Vec<NodeKey>>,
+}
+
+/// Splits `g` into strongly connected components using Tarjan's algorithm. Every node in a
+/// component can reach every other node in the same component.
+///
+/// Components are returned in reverse topological order, meaning no component has an edge to a
+/// component that comes after it. Nodes that are not part of a cycle are returned as a component
+/// with a single node.
+pub fn strongly_connected_components(g: &Graph) -> Vec<Vec<NodeKey>> {
+    let mut state = TarjanState {
+        next_index: 0,
+        indices: Default::default(),
+        low_links: Default::default(),
+        stack: Vec::new(),
+        on_stack: Default::default(),
+        components: Vec::new(),
+    };
+
+    for nk in g.nodes.keys() {
+        if !state.indices.contains_key(nk) {
+            strong_connect(nk, g, &mut state);
+        }
+    }
+
+    state.components
+}
+
+fn strong_connect(nk: NodeKey, g: &Graph, state: &mut TarjanState) {
+    state.indices.insert(nk, state.next_index);
+    state.low_links.insert(nk, state.next_index);
+    state.next_index += 1;
+
+    state.stack.push(nk);
+    state.on_stack.insert(nk, true);
+
+    for to_k in g.nodes[nk].edges() {
+        if !state.indices.contains_key(to_k) {
+            strong_connect(to_k, g, state);
+            state.low_links[nk] = state.low_links[nk].min(state.low_links[to_k]);
+        } else if state.on_stack[to_k] {
+            state.low_links[nk] = state.low_links[nk].min(state.indices[to_k]);
+        }
+    }
+
+    // `nk` is the root of a component when no node reachable from it links back to an earlier
+    // node. The component is every node above `nk` on the stack.
+    if state.low_links[nk] == state.indices[nk] {
+        let mut component = Vec::new();
+
+        loop {
+            let member = state.stack.pop().unwrap();
+            state.on_stack[member] = false;
+            component.push(member);
+
+            if member == nk {
+                break;
+            }
+        }
+
+        component.reverse();
+        state.components.push(component);
+    }
+}
+
 pub struct VisitorNodeQueue<'a> {
     queue: &'a mut VecDeque<NodeKey>,
 }
@@ -366,3 +728,885 @@ where
         }
     }
 }
+
+/// Walk `previous` backwards from `end` to build the path from the start node (the only node
+/// without a previous node) to `end`.
+fn reconstruct_path(previous: &SecondaryMap<NodeKey, NodeKey>, end: NodeKey) -> Vec<NodeKey> {
+    let mut path = vec![end];
+
+    while let Some(prev_nk) = previous.get(*path.last().unwrap()) {
+        path.push(*prev_nk);
+    }
+
+    path.reverse();
+    path
+}
+
+/// Finds the path from `start` to `end` that follows the fewest edges, ignoring edge weights.
+///
+/// Returns the nodes along the path including `start` and `end`, or `None` if `end` cannot be
+/// reached from `start`.
+pub fn bfs_path(g: &Graph, start: NodeKey, end: NodeKey) -> Option<Vec<NodeKey>> {
+    let mut previous: SecondaryMap<NodeKey, NodeKey> = Default::default();
+    let mut to_visit = VecDeque::new();
+
+    to_visit.push_back(start);
+
+    while let Some(nk) = to_visit.pop_front() {
+        if nk == end {
+            return Some(reconstruct_path(&previous, end));
+        }
+
+        for to_k in g.nodes[nk].edges() {
+            if to_k != start && !previous.contains_key(to_k) {
+                previous.insert(to_k, nk);
+                to_visit.push_back(to_k);
+            }
+        }
+    }
+
+    None
+}
+
+/// Error returned when the minimum cut of a graph cannot be found.
+#[derive(Debug, Error, PartialEq)]
+pub enum MinCutError {
+    #[error("the edges from node {} to node {} do not have the same total weight as the edges in the other direction", .from, .to)]
+    DirectedEdge { from: usize, to: usize },
+    #[error("the edge from node {} to node {} has a negative weight {}", .from, .to, .weight)]
+    NegativeWeight { from: usize, to: usize, weight: i64 },
+    #[error("a graph needs at least two nodes to be cut")]
+    TooFewNodes,
+}
+
+/// Finds the minimum cut of the undirected graph `g` using the Stoer-Wagner algorithm. The minimum
+/// cut is the set of edges with the smallest total weight that splits the graph in two when
+/// removed. Edges without a weight have a cost of one.
+///
+/// Returns the total weight of the cut edges and the nodes on each side of the cut. An error is
+/// returned if `g` has fewer than two nodes, has a negative edge weight, or is not undirected
+/// (meaning the edges from one node to another must have the same total weight as the edges going
+/// the other way).
+pub fn min_cut(g: &Graph) -> Result<(usize, Vec<NodeKey>, Vec<NodeKey>), MinCutError> {
+    if g.nodes.len() < 2 {
+        return Err(MinCutError::TooFewNodes);
+    }
+
+    for node in g.nodes.values() {
+        for edge in &node.edges {
+            if edge.cost() < 0 {
+                return Err(MinCutError::NegativeWeight {
+                    from: node.id,
+                    to: g.nodes[edge.to].id,
+                    weight: edge.cost(),
+                });
+            }
+        }
+    }
+
+    // Give each node an index so merged nodes can be tracked with plain vectors. Each index holds
+    // the original nodes that were merged into it, and the total weight of edges to other indices.
+    // Parallel edges between the same pair of nodes have their weights combined.
+    let keys: Vec<NodeKey> = g.nodes.keys().collect();
+    let mut indices: SecondaryMap<NodeKey, usize> = Default::default();
+
+    for (i, nk) in keys.iter().enumerate() {
+        indices.insert(*nk, i);
+    }
+
+    let mut groups: Vec<Vec<NodeKey>> = keys.iter().map(|nk| vec![*nk]).collect();
+    let mut weights: Vec<HashMap<usize, i64>> = vec![HashMap::new(); keys.len()];
+
+    for (i, nk) in keys.iter().enumerate() {
+        for edge in &g.nodes[*nk].edges {
+            let j = indices[edge.to];
+
+            if i != j {
+                *weights[i].entry(j).or_default() += edge.cost();
+            }
+        }
+    }
+
+    // The graph is undirected when the combined weight from each node to another node is the same
+    // as the combined weight going back.
+    for (i, to_weights) in weights.iter().enumerate() {
+        for (j, w) in to_weights {
+            if weights[*j].get(&i) != Some(w) {
+                return Err(MinCutError::DirectedEdge {
+                    from: g.nodes[keys[i]].id,
+                    to: g.nodes[keys[*j]].id,
+                });
+            }
+        }
+    }
+
+    let mut active: Vec<usize> = (0..keys.len()).collect();
+    let mut best: Option<(i64, Vec<NodeKey>)> = None;
+
+    while active.len() > 1 {
+        // Find the most tightly connected ordering of the active nodes, starting from any node.
+        // The last two nodes added are merged after this phase, and the weight connecting the last
+        // node to the rest of the graph is a candidate for the minimum cut.
+        let mut connection: Vec<i64> = vec![0; keys.len()];
+        let mut added = vec![false; keys.len()];
+        let mut to_visit: BinaryHeap<(i64, usize)> = active.iter().map(|i| (0, *i)).collect();
+        let mut previous = active[0];
+        let mut last = active[0];
+        let mut cut_of_phase = 0;
+
+        while let Some((weight, i)) = to_visit.pop() {
+            // Skip stale queue entries for nodes that have already been added.
+            if added[i] || weight < connection[i] {
+                continue;
+            }
+
+            added[i] = true;
+            previous = last;
+            last = i;
+            cut_of_phase = weight;
+
+            for (j, w) in &weights[i] {
+                if !added[*j] {
+                    connection[*j] += w;
+                    to_visit.push((connection[*j], *j));
+                }
+            }
+        }
+
+        if best.as_ref().is_none_or(|(w, _)| cut_of_phase < *w) {
+            best = Some((cut_of_phase, groups[last].clone()));
+        }
+
+        // Merge the last node into the node added before it.
+        let last_group = std::mem::take(&mut groups[last]);
+        groups[previous].extend(last_group);
+
+        for (j, w) in std::mem::take(&mut weights[last]) {
+            weights[j].remove(&last);
+
+            if j != previous {
+                *weights[previous].entry(j).or_default() += w;
+                *weights[j].entry(previous).or_default() += w;
+            }
+        }
+
+        active.retain(|i| *i != last);
+    }
+
+    let (weight, cut) = best.expect("graphs with two or more nodes have at least one phase");
+    let rest = keys.into_iter().filter(|nk| !cut.contains(nk)).collect();
+
+    Ok((weight as usize, cut, rest))
+}
+
+/// Finds the maximum flow from `source` to `sink` using the Edmonds-Karp algorithm, where the weight
+/// of each directed edge is its capacity. Edges without a weight have a capacity of one, and edges
+/// with a negative weight have no capacity.
+///
+/// Returns the total amount that can flow from `source` to `sink`, which is zero when `source` and
+/// `sink` are the same node.
+pub fn max_flow(g: &Graph, source: NodeKey, sink: NodeKey) -> i64 {
+    if source == sink {
+        return 0;
+    }
+
+    // The remaining capacity of each edge, plus a reverse edge that allows flow to be undone. Edges
+    // between the same pair of nodes have their capacities combined.
+    let mut residual: SecondaryMap<NodeKey, HashMap<NodeKey, i64>> = Default::default();
+
+    for nk in g.nodes.keys() {
+        residual.insert(nk, HashMap::new());
+    }
+
+    for (nk, node) in &g.nodes {
+        for edge in &node.edges {
+            *residual[nk].entry(edge.to).or_default() += edge.cost().max(0);
+            residual[edge.to].entry(nk).or_default();
+        }
+    }
+
+    let mut total_flow = 0;
+
+    loop {
+        // Find the augmenting path with the fewest edges that still has capacity.
+        let mut previous: SecondaryMap<NodeKey, NodeKey> = Default::default();
+        let mut to_visit = VecDeque::from([source]);
+
+        while let Some(nk) = to_visit.pop_front() {
+            if nk == sink {
+                break;
+            }
+
+            for (to_k, capacity) in &residual[nk] {
+                if *capacity > 0 && *to_k != source && !previous.contains_key(*to_k) {
+                    previous.insert(*to_k, nk);
+                    to_visit.push_back(*to_k);
+                }
+            }
+        }
+
+        if !previous.contains_key(sink) {
+            return total_flow;
+        }
+
+        // Push as much flow as the smallest capacity along the path allows.
+        let mut path_flow = i64::MAX;
+        let mut nk = sink;
+
+        while nk != source {
+            let prev_nk = previous[nk];
+            path_flow = path_flow.min(residual[prev_nk][&nk]);
+            nk = prev_nk;
+        }
+
+        let mut nk = sink;
+
+        while nk != source {
+            let prev_nk = previous[nk];
+            *residual[prev_nk].get_mut(&nk).unwrap() -= path_flow;
+            *residual[nk].get_mut(&prev_nk).unwrap() += path_flow;
+            nk = prev_nk;
+        }
+
+        total_flow += path_flow;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build an undirected graph where every `(a, b, weight)` tuple is an edge in both directions.
+    fn undirected_graph(edges: &[(&str, &str, i64)]) -> (Graph, HashMap<String, NodeKey>) {
+        let mut builder = GraphBuilder::new();
+
+        for (a, b, weight) in edges {
+            builder.add_weighted_edge(*a, *b, *weight);
+            builder.add_weighted_edge(*b, *a, *weight);
+        }
+
+        builder.build()
+    }
+
+    /// Check that `path` follows edges in `g` and that the cost of those edges adds up to `cost`.
+    fn assert_valid_path(g: &Graph, cost: i64, path: &[NodeKey]) {
+        let path_cost: i64 = path
+            .windows(2)
+            .map(|w| {
+                g.node(w[0])
+                    .edge_cost(w[1])
+                    .expect("path should only follow edges in the graph")
+            })
+            .sum();
+
+        assert_eq!(path_cost, cost);
+    }
+
+    #[test]
+    fn weighted_edges_default_to_cost_of_one() {
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_edge("b"))
+            .with_weighted_edge("a", "c", 7)
+            .build();
+
+        assert_eq!(g.node(names["a"]).edge_cost(names["b"]), Some(1));
+        assert_eq!(g.node(names["a"]).edge_cost(names["c"]), Some(7));
+        assert_eq!(g.node(names["b"]).edge_cost(names["a"]), None);
+        assert_eq!(g.node(names["a"]).edges().count(), 2);
+    }
+
+    #[test]
+    fn shortest_path_avoids_greedy_choice() {
+        // The cheapest first step from `a` leads to an expensive edge, so the optimal path must
+        // take the more expensive first step.
+        let (g, names) = GraphBuilder::new()
+            .with_weighted_edge("a", "b", 1)
+            .with_weighted_edge("b", "e", 10)
+            .with_weighted_edge("a", "c", 2)
+            .with_weighted_edge("c", "d", 2)
+            .with_weighted_edge("d", "e", 2)
+            .build();
+
+        let (cost, path) = g.shortest_path(names["a"], names["e"]).unwrap().unwrap();
+
+        assert_eq!(cost, 6);
+        assert_eq!(path, vec![names["a"], names["c"], names["d"], names["e"]]);
+        assert_valid_path(&g, cost, &path);
+    }
+
+    #[test]
+    fn shortest_path_with_unweighted_edges() {
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_edge("b").with_edge("c"))
+            .with_node(|n| n.with_name("b").with_edge("d"))
+            .with_node(|n| n.with_name("c").with_edge("x"))
+            .with_node(|n| n.with_name("x").with_edge("d"))
+            .build();
+
+        let (cost, path) = g.shortest_path(names["a"], names["d"]).unwrap().unwrap();
+
+        assert_eq!(cost, 2);
+        assert_eq!(path, vec![names["a"], names["b"], names["d"]]);
+        assert_valid_path(&g, cost, &path);
+    }
+
+    #[test]
+    fn shortest_path_to_start_has_no_cost() {
+        let (g, names) = GraphBuilder::new().with_weighted_edge("a", "b", 3).build();
+
+        assert_eq!(
+            g.shortest_path(names["a"], names["a"]),
+            Ok(Some((0, vec![names["a"]])))
+        );
+    }
+
+    #[test]
+    fn shortest_path_unreachable() {
+        let (g, names) = GraphBuilder::new()
+            .with_weighted_edge("a", "b", 3)
+            .with_weighted_edge("c", "a", 3)
+            .build();
+
+        assert_eq!(g.shortest_path(names["a"], names["c"]), Ok(None));
+    }
+
+    #[test]
+    fn shortest_path_rejects_negative_weights() {
+        let (g, names) = GraphBuilder::new()
+            .with_weighted_edge("a", "b", 3)
+            .with_weighted_edge("b", "c", -1)
+            .build();
+
+        assert!(matches!(
+            g.shortest_path(names["a"], names["c"]),
+            Err(ShortestPathError::NegativeWeight { weight: -1, .. })
+        ));
+    }
+
+    /// Build a `size` by `size` grid of nodes named `x,y`, where each node has an unweighted edge
+    /// to the nodes above, below, left and right of it.
+    fn grid_graph(size: i64) -> (Graph, HashMap<String, NodeKey>) {
+        let mut builder = GraphBuilder::new();
+
+        for y in 0..size {
+            for x in 0..size {
+                let mut node = NodeBuilder::new().with_name(format!("{x},{y}"));
+
+                if x + 1 < size {
+                    node.add_bidir_edge(format!("{},{y}", x + 1));
+                }
+
+                if y + 1 < size {
+                    node.add_bidir_edge(format!("{x},{}", y + 1));
+                }
+
+                builder.add_node(node);
+            }
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn astar_matches_shortest_path_with_fewer_expansions() {
+        let (g, names) = grid_graph(12);
+        let key_names: HashMap<NodeKey, &str> =
+            names.iter().map(|(k, v)| (*v, k.as_str())).collect();
+
+        let (start, goal) = (names["1,2"], names["9,7"]);
+        let manhattan_distance = |nk: NodeKey| {
+            let (x, y) = key_names[&nk].split_once(',').unwrap();
+            (9 - x.parse::<i64>().unwrap()).abs() + (7 - y.parse::<i64>().unwrap()).abs()
+        };
+
+        let estimates = std::cell::Cell::new(0);
+        let (cost, path) = g
+            .astar(start, goal, |nk| {
+                estimates.set(estimates.get() + 1);
+                manhattan_distance(nk)
+            })
+            .unwrap();
+
+        // A heuristic that always returns zero explores nodes in the same order as Dijkstra.
+        let dijkstra_estimates = std::cell::Cell::new(0);
+        let (dijkstra_cost, _) = g
+            .astar(start, goal, |_| {
+                dijkstra_estimates.set(dijkstra_estimates.get() + 1);
+                0
+            })
+            .unwrap();
+
+        assert_eq!(cost, 13);
+        assert_eq!(path.len(), 14);
+        assert_eq!(g.shortest_path(start, goal).unwrap().unwrap().0, cost);
+        assert_eq!(dijkstra_cost, cost);
+        assert_valid_path(&g, cost, &path);
+
+        assert!(estimates.get() < dijkstra_estimates.get());
+    }
+
+    #[test]
+    fn astar_with_weighted_edges() {
+        let (g, names) = GraphBuilder::new()
+            .with_weighted_edge("a", "b", 1)
+            .with_weighted_edge("b", "e", 10)
+            .with_weighted_edge("a", "c", 2)
+            .with_weighted_edge("c", "d", 2)
+            .with_weighted_edge("d", "e", 2)
+            .build();
+
+        assert_eq!(
+            g.astar(names["a"], names["e"], |_| 0),
+            Some((6, vec![names["a"], names["c"], names["d"], names["e"]]))
+        );
+    }
+
+    #[test]
+    fn astar_unreachable() {
+        let (g, names) = GraphBuilder::new()
+            .with_weighted_edge("a", "b", 3)
+            .with_weighted_edge("c", "a", 3)
+            .build();
+
+        assert_eq!(g.astar(names["a"], names["c"], |_| 0), None);
+    }
+
+    #[test]
+    fn topological_sort_of_dag() {
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("shirt").with_edge("tie").with_edge("belt"))
+            .with_node(|n| n.with_name("tie").with_edge("jacket"))
+            .with_node(|n| n.with_name("pants").with_edge("shoes").with_edge("belt"))
+            .with_node(|n| n.with_name("belt").with_edge("jacket"))
+            .with_node(|n| n.with_name("socks").with_edge("shoes"))
+            .with_node(|n| n.with_name("watch"))
+            .build();
+
+        let order = topological_sort(&g).unwrap();
+        assert_eq!(order.len(), names.len());
+
+        let position = |nk: NodeKey| order.iter().position(|o| *o == nk).unwrap();
+
+        for nk in names.values() {
+            for to_k in g.node(*nk).edges() {
+                assert!(position(*nk) < position(to_k));
+            }
+        }
+    }
+
+    #[test]
+    fn topological_sort_of_empty_graph() {
+        assert_eq!(topological_sort(&Graph::new()), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn topological_sort_finds_cycle() {
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_edge("b"))
+            .with_node(|n| n.with_name("b").with_edge("c"))
+            .with_node(|n| n.with_name("c").with_edge("d"))
+            .with_node(|n| n.with_name("d").with_edge("b"))
+            .build();
+
+        let cycle = topological_sort(&g).unwrap_err().cycle;
+        assert_eq!(cycle, vec![names["b"], names["c"], names["d"]]);
+
+        // Every node in the cycle has an edge to the next node, and the last node links back to
+        // the first node.
+        for (i, nk) in cycle.iter().enumerate() {
+            let next = cycle[(i + 1) % cycle.len()];
+            assert!(g.node(*nk).edges().any(|e| e == next));
+        }
+    }
+
+    #[test]
+    fn topological_sort_finds_self_loop() {
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_edge("a"))
+            .build();
+
+        assert_eq!(
+            topological_sort(&g),
+            Err(CycleError {
+                cycle: vec![names["a"]]
+            })
+        );
+    }
+
+    #[test]
+    fn strongly_connected_components_of_varying_sizes() {
+        // Components: {a, b, c}, {d, e}, {f}, {g}
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_edge("b"))
+            .with_node(|n| n.with_name("b").with_edge("c").with_edge("d"))
+            .with_node(|n| n.with_name("c").with_edge("a"))
+            .with_node(|n| n.with_name("d").with_edge("e").with_edge("f"))
+            .with_node(|n| n.with_name("e").with_edge("d"))
+            .with_node(|n| n.with_name("f"))
+            .with_node(|n| n.with_name("g").with_edge("a"))
+            .build();
+
+        let key_names: HashMap<NodeKey, &str> =
+            names.iter().map(|(k, v)| (*v, k.as_str())).collect();
+
+        let components = strongly_connected_components(&g);
+        let component_names: Vec<Vec<&str>> = components
+            .iter()
+            .map(|c| {
+                let mut c = c.iter().map(|nk| key_names[nk]).collect::<Vec<_>>();
+                c.sort();
+                c
+            })
+            .collect();
+
+        // Components are in reverse topological order.
+        assert_eq!(
+            component_names,
+            vec![vec!["f"], vec!["d", "e"], vec!["a", "b", "c"], vec!["g"]]
+        );
+
+        assert_eq!(components.iter().map(Vec::len).sum::<usize>(), names.len());
+    }
+
+    #[test]
+    fn strongly_connected_components_of_dag_are_single_nodes() {
+        let (g, _) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_edge("b").with_edge("c"))
+            .with_node(|n| n.with_name("b").with_edge("c"))
+            .build();
+
+        let components = strongly_connected_components(&g);
+
+        assert_eq!(components.len(), 3);
+        assert!(components.iter().all(|c| c.len() == 1));
+    }
+
+    #[test]
+    fn bfs_path_disconnected_graph() {
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_edge("b"))
+            .with_node(|n| n.with_name("c").with_edge("d"))
+            .build();
+
+        assert_eq!(bfs_path(&g, names["a"], names["d"]), None);
+        assert_eq!(bfs_path(&g, names["b"], names["a"]), None);
+    }
+
+    #[test]
+    fn bfs_path_direct_edge() {
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_edge("b"))
+            .build();
+
+        assert_eq!(
+            bfs_path(&g, names["a"], names["b"]),
+            Some(vec![names["a"], names["b"]])
+        );
+    }
+
+    #[test]
+    fn bfs_path_to_start() {
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_bidir_edge("b"))
+            .build();
+
+        assert_eq!(bfs_path(&g, names["a"], names["a"]), Some(vec![names["a"]]));
+    }
+
+    #[test]
+    fn bfs_path_returns_shortest_route() {
+        // a -> b -> c -> d -> z is longer than a -> e -> f -> z, and a -> g leads to a cycle that
+        // never reaches z.
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| {
+                n.with_name("a")
+                    .with_edge("b")
+                    .with_edge("g")
+                    .with_edge("e")
+            })
+            .with_node(|n| n.with_name("b").with_edge("c"))
+            .with_node(|n| n.with_name("c").with_edge("d"))
+            .with_node(|n| n.with_name("d").with_edge("z"))
+            .with_node(|n| n.with_name("e").with_edge("f"))
+            .with_node(|n| n.with_name("f").with_edge("z"))
+            .with_node(|n| n.with_name("g").with_edge("h"))
+            .with_node(|n| n.with_name("h").with_edge("g").with_edge("a"))
+            .build();
+
+        let path = bfs_path(&g, names["a"], names["z"]).unwrap();
+
+        assert_eq!(path, vec![names["a"], names["e"], names["f"], names["z"]]);
+
+        for w in path.windows(2) {
+            assert!(g.node(w[0]).edges().any(|e| e == w[1]));
+        }
+    }
+
+    #[test]
+    fn to_dot_for_small_graph() {
+        let (g, _) = GraphBuilder::new()
+            .with_node(|n| n.with_name("b").with_edge("a"))
+            .with_weighted_edge("a", "c", 5)
+            .with_node(|n| n.with_name("a").with_edge("b"))
+            .build();
+
+        assert_eq!(
+            g.to_dot(),
+            concat!(
+                "digraph {\n",
+                "    \"a\";\n",
+                "    \"b\";\n",
+                "    \"c\";\n",
+                "    \"a\" -> \"b\";\n",
+                "    \"a\" -> \"c\" [label=\"5\"];\n",
+                "    \"b\" -> \"a\";\n",
+                "}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn to_dot_is_well_formed() {
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("say \"hi\"").with_bidir_edge("x"))
+            .with_weighted_edge("x", "y", -2)
+            .build();
+
+        let dot = g.to_dot();
+        let lines = dot.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.first(), Some(&"digraph {"));
+        assert_eq!(lines.last(), Some(&"}"));
+
+        // Every statement is terminated, and every quote inside of a name is escaped.
+        let statements = &lines[1..lines.len() - 1];
+        assert!(statements.iter().all(|l| l.ends_with(';')));
+        assert!(statements
+            .iter()
+            .all(|l| l.replace("\\\"", "").matches('"').count() % 2 == 0));
+
+        let node_count = statements.iter().filter(|l| !l.contains("->")).count();
+        let edge_count = statements.iter().filter(|l| l.contains("->")).count();
+        assert_eq!(node_count, names.len());
+        assert_eq!(edge_count, 3);
+
+        assert!(dot.contains("    \"say \\\"hi\\\"\" -> \"x\";\n"));
+        assert!(dot.contains("    \"x\" -> \"y\" [label=\"-2\"];\n"));
+    }
+
+    #[test]
+    fn min_cut_of_textbook_graph() {
+        // The example graph from "A Simple Min-Cut Algorithm" by Stoer and Wagner.
+        let (g, names) = undirected_graph(&[
+            ("1", "2", 2),
+            ("1", "5", 3),
+            ("2", "3", 3),
+            ("2", "5", 2),
+            ("2", "6", 2),
+            ("3", "4", 4),
+            ("3", "7", 2),
+            ("4", "7", 2),
+            ("4", "8", 2),
+            ("5", "6", 3),
+            ("6", "7", 1),
+            ("7", "8", 3),
+        ]);
+
+        let (weight, a, b) = min_cut(&g).unwrap();
+        assert_eq!(weight, 4);
+
+        // Both partitions together cover every node exactly once.
+        let mut all_nodes = a.iter().chain(b.iter()).copied().collect::<Vec<_>>();
+        all_nodes.sort();
+        all_nodes.dedup();
+        assert_eq!(all_nodes.len(), names.len());
+        assert_eq!(a.len() + b.len(), names.len());
+
+        let mut cut_side = if a.contains(&names["1"]) { b } else { a };
+        cut_side.sort();
+
+        let mut expected = vec![names["3"], names["4"], names["7"], names["8"]];
+        expected.sort();
+
+        assert_eq!(cut_side, expected);
+    }
+
+    #[test]
+    fn min_cut_of_two_clusters() {
+        // Two triangles joined by a single unweighted edge.
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_bidir_edge("b").with_bidir_edge("c"))
+            .with_node(|n| n.with_name("b").with_bidir_edge("c"))
+            .with_node(|n| n.with_name("c").with_bidir_edge("x"))
+            .with_node(|n| n.with_name("x").with_bidir_edge("y").with_bidir_edge("z"))
+            .with_node(|n| n.with_name("y").with_bidir_edge("z"))
+            .build();
+
+        let (weight, a, b) = min_cut(&g).unwrap();
+
+        assert_eq!(weight, 1);
+        assert_eq!(a.len(), 3);
+        assert_eq!(b.len(), 3);
+        assert_eq!(a.contains(&names["a"]), a.contains(&names["c"]));
+        assert_eq!(a.contains(&names["x"]), a.contains(&names["z"]));
+        assert_ne!(a.contains(&names["a"]), a.contains(&names["x"]));
+    }
+
+    #[test]
+    fn min_cut_sums_parallel_edges() {
+        let (g, names) = undirected_graph(&[("a", "b", 2), ("a", "b", 3), ("b", "c", 6)]);
+
+        let (weight, a, b) = min_cut(&g).unwrap();
+        let cut_side = if a.contains(&names["a"]) { a } else { b };
+
+        assert_eq!(weight, 5);
+        assert_eq!(cut_side, vec![names["a"]]);
+    }
+
+    #[test]
+    fn min_cut_rejects_uneven_parallel_edges() {
+        let (g, _) = GraphBuilder::new()
+            .with_weighted_edge("a", "b", 2)
+            .with_weighted_edge("a", "b", 3)
+            .with_weighted_edge("b", "a", 2)
+            .build();
+
+        assert!(matches!(min_cut(&g), Err(MinCutError::DirectedEdge { .. })));
+    }
+
+    #[test]
+    fn min_cut_rejects_directed_graphs() {
+        let (g, _) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_bidir_edge("b"))
+            .with_node(|n| n.with_name("b").with_edge("c"))
+            .build();
+
+        assert!(matches!(min_cut(&g), Err(MinCutError::DirectedEdge { .. })));
+    }
+
+    #[test]
+    fn min_cut_needs_two_nodes() {
+        let (g, _) = GraphBuilder::new().with_node(|n| n.with_name("a")).build();
+        assert_eq!(min_cut(&g), Err(MinCutError::TooFewNodes));
+    }
+
+    #[test]
+    fn max_flow_of_chain_is_smallest_capacity() {
+        let (g, names) = GraphBuilder::new()
+            .with_weighted_edge("s", "a", 10)
+            .with_weighted_edge("a", "b", 3)
+            .with_weighted_edge("b", "c", 7)
+            .with_weighted_edge("c", "t", 5)
+            .build();
+
+        assert_eq!(max_flow(&g, names["s"], names["t"]), 3);
+        assert_eq!(max_flow(&g, names["t"], names["s"]), 0);
+    }
+
+    #[test]
+    fn max_flow_of_parallel_paths_is_sum_of_paths() {
+        let (g, names) = GraphBuilder::new()
+            .with_weighted_edge("s", "a", 4)
+            .with_weighted_edge("a", "t", 6)
+            .with_weighted_edge("s", "b", 5)
+            .with_weighted_edge("b", "t", 2)
+            .with_weighted_edge("s", "c", 3)
+            .with_weighted_edge("c", "t", 3)
+            .build();
+
+        assert_eq!(max_flow(&g, names["s"], names["t"]), 4 + 2 + 3);
+    }
+
+    #[test]
+    fn weighted_edges_between_same_nodes_are_parallel() {
+        let (g, names) = GraphBuilder::new()
+            .with_weighted_edge("a", "b", 5)
+            .with_weighted_edge("a", "b", 2)
+            .with_node(|n| n.with_name("a").with_edge("b"))
+            .with_node(|n| n.with_name("b").with_edge("c").with_edge("c"))
+            .build();
+
+        assert_eq!(g.node(names["a"]).weighted_edges().count(), 2);
+        assert_eq!(g.node(names["a"]).edge_cost(names["b"]), Some(2));
+        assert_eq!(g.node(names["b"]).weighted_edges().count(), 1);
+    }
+
+    #[test]
+    fn max_flow_combines_parallel_edges() {
+        let (g, names) = GraphBuilder::new()
+            .with_weighted_edge("s", "a", 4)
+            .with_weighted_edge("s", "a", 3)
+            .with_weighted_edge("a", "t", 5)
+            .with_weighted_edge("a", "t", 5)
+            .build();
+
+        assert_eq!(max_flow(&g, names["s"], names["t"]), 7);
+    }
+
+    #[test]
+    fn max_flow_reroutes_earlier_flow() {
+        // The classic example from CLRS, which needs flow to be pushed back along a reverse edge.
+        let (g, names) = GraphBuilder::new()
+            .with_weighted_edge("s", "v1", 16)
+            .with_weighted_edge("s", "v2", 13)
+            .with_weighted_edge("v1", "v3", 12)
+            .with_weighted_edge("v2", "v1", 4)
+            .with_weighted_edge("v2", "v4", 14)
+            .with_weighted_edge("v3", "v2", 9)
+            .with_weighted_edge("v3", "t", 20)
+            .with_weighted_edge("v4", "v3", 7)
+            .with_weighted_edge("v4", "t", 4)
+            .build();
+
+        assert_eq!(max_flow(&g, names["s"], names["t"]), 23);
+    }
+
+    #[test]
+    fn max_flow_with_edges_in_both_directions() {
+        let (g, names) = GraphBuilder::new()
+            .with_weighted_edge("s", "a", 5)
+            .with_weighted_edge("a", "s", 5)
+            .with_weighted_edge("a", "t", 2)
+            .with_node(|n| n.with_name("s").with_bidir_edge("t"))
+            .build();
+
+        assert_eq!(max_flow(&g, names["s"], names["t"]), 3);
+        assert_eq!(max_flow(&g, names["s"], names["s"]), 0);
+    }
+
+    #[test]
+    fn remove_node_removes_edges_to_it() {
+        let (mut g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_edge("b").with_edge("c"))
+            .with_node(|n| n.with_name("b").with_bidir_edge("c"))
+            .build();
+
+        assert!(g.remove_node(names["c"]).is_some());
+
+        assert_eq!(g.find_node(names["c"]), None);
+        assert_eq!(
+            g.node(names["a"]).edges().collect::<Vec<_>>(),
+            vec![names["b"]]
+        );
+        assert_eq!(g.node(names["b"]).edges().count(), 0);
+        assert!(!g.to_dot().contains("\"c\""));
+
+        // Removing the node again does nothing.
+        assert!(g.remove_node(names["c"]).is_none());
+    }
+
+    #[test]
+    fn cloned_graph_is_independent() {
+        let (g, names) = GraphBuilder::new()
+            .with_node(|n| n.with_name("a").with_edge("b"))
+            .build();
+
+        let mut copy = g.clone();
+        assert_eq!(copy, g);
+
+        copy.remove_node(names["b"]);
+        assert!(copy.find_node(names["b"]).is_none());
+        assert!(g.find_node(names["b"]).is_some());
+        assert_eq!(g.node(names["a"]).edges().count(), 1);
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::RangeInclusive;
+use std::ops::{Range, RangeInclusive};
 
 use thiserror::Error;
 
@@ -82,6 +82,201 @@ pub fn merge_intervals(mut intervals: Vec<RangeInclusive<usize>>) -> Vec<RangeIn
     merged_intervals
 }
 
+/// A half-open interval of integers that includes its `start` value but not its `end` value. An
+/// interval where `start` is not smaller than `end` is empty and contains no values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    /// Create a new interval from `start` up to but not including `end`. The interval is empty
+    /// when `start` is not smaller than `end`.
+    pub fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+
+    /// Get the number of integers in this interval, which is zero for an empty interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ube::intervals::Interval;
+    ///
+    /// assert_eq!(Interval::new(3, 5).length(), 2);
+    /// assert_eq!(Interval::new(5, 5).length(), 0);
+    /// assert_eq!(Interval::new(5, 3).length(), 0);
+    /// ```
+    pub fn length(&self) -> i128 {
+        (self.end as i128 - self.start as i128).max(0)
+    }
+
+    /// Check if this interval contains no values, which happens when `start` is not smaller than
+    /// `end`.
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Check if `value` is inside this interval.
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Get the values that are in both this interval and `other`, or `None` if the intervals are
+    /// disjoint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ube::intervals::Interval;
+    ///
+    /// let a = Interval::new(1, 5);
+    /// assert_eq!(a.intersect(&Interval::new(3, 8)), Some(Interval::new(3, 5)));
+    /// assert_eq!(a.intersect(&Interval::new(5, 8)), None);
+    /// ```
+    pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+
+        (start < end).then_some(Interval { start, end })
+    }
+
+    /// Merge this interval with `other` into a single interval, or `None` if there is a gap
+    /// between the two intervals. Adjacent intervals like `1..4` and `4..7` are merged, and
+    /// merging with an empty interval returns the other interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ube::intervals::Interval;
+    ///
+    /// let a = Interval::new(1, 4);
+    /// assert_eq!(a.union(&Interval::new(4, 7)), Some(Interval::new(1, 7)));
+    /// assert_eq!(a.union(&Interval::new(5, 7)), None);
+    /// ```
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        if self.is_empty() {
+            return Some(*other);
+        } else if other.is_empty() {
+            return Some(*self);
+        }
+
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        if second.start <= first.end {
+            Some(Interval {
+                start: first.start,
+                end: first.end.max(second.end),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(value: Range<i64>) -> Self {
+        Self::new(value.start, value.end)
+    }
+}
+
+/// A collection of intervals which may overlap.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Intervals(Vec<Interval>);
+
+impl Intervals {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn push(&mut self, interval: Interval) {
+        self.0.push(interval);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Get the number of intervals in this collection.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval> {
+        self.0.iter()
+    }
+
+    /// Collapse the intervals into the minimal set of sorted, non-overlapping intervals that cover
+    /// the same values. Overlapping and adjacent intervals are merged together, and empty
+    /// intervals are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ube::intervals::{Interval, Intervals};
+    ///
+    /// let intervals: Intervals = [1..4, 10..13, 2..7, 7..9].into_iter().map(Interval::from).collect();
+    /// let merged = intervals.merge_overlapping();
+    ///
+    /// assert_eq!(merged.iter().copied().collect::<Vec<_>>(), vec![Interval::new(1, 9), Interval::new(10, 13)]);
+    /// assert_eq!(merged.total_length(), 11);
+    /// ```
+    pub fn merge_overlapping(&self) -> Intervals {
+        let mut intervals = self
+            .0
+            .iter()
+            .filter(|i| !i.is_empty())
+            .copied()
+            .collect::<Vec<_>>();
+        intervals.sort_by_key(|i| i.start);
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+
+        for current in intervals {
+            match merged.last().and_then(|last| last.union(&current)) {
+                Some(combined) => *merged.last_mut().unwrap() = combined,
+                None => merged.push(current),
+            }
+        }
+
+        Intervals(merged)
+    }
+
+    /// Get the number of distinct integers covered by these intervals. Values covered by more than
+    /// one interval are only counted once, and empty intervals do not add to the total.
+    pub fn total_length(&self) -> i128 {
+        self.merge_overlapping().iter().map(Interval::length).sum()
+    }
+}
+
+impl FromIterator<Interval> for Intervals {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a Intervals {
+    type Item = &'a Interval;
+    type IntoIter = std::slice::Iter<'a, Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,4 +360,127 @@ mod tests {
         let result = merge_intervals(vec![5..=7, 1..=3, 2..=6]);
         assert_eq!(result, vec![1..=7]);
     }
+
+    fn iv(start: i64, end: i64) -> Interval {
+        Interval::new(start, end)
+    }
+
+    #[test]
+    fn interval_length_and_contains() {
+        assert_eq!(iv(3, 4).length(), 1);
+        assert_eq!(iv(-2, 3).length(), 5);
+        assert_eq!(iv(i64::MIN, i64::MAX).length(), u64::MAX as i128);
+
+        assert!(iv(-2, 3).contains(-2));
+        assert!(iv(-2, 3).contains(2));
+        assert!(!iv(-2, 3).contains(3));
+    }
+
+    #[test]
+    fn interval_start_not_before_end_is_empty() {
+        assert!(iv(5, 5).is_empty());
+        assert!(iv(5, 4).is_empty());
+        assert!(iv(i64::MAX, i64::MIN).is_empty());
+        assert!(!iv(5, 6).is_empty());
+
+        assert_eq!(iv(5, 5).length(), 0);
+        assert_eq!(iv(5, 4).length(), 0);
+        assert_eq!(iv(i64::MAX, i64::MIN).length(), 0);
+        assert!(!iv(5, 5).contains(5));
+        assert!(!iv(5, 4).contains(4));
+        assert!(!iv(5, 4).contains(5));
+    }
+
+    #[test]
+    fn union_with_empty_interval() {
+        assert_eq!(iv(5, 5).union(&iv(10, 13)), Some(iv(10, 13)));
+        assert_eq!(iv(10, 13).union(&iv(5, 4)), Some(iv(10, 13)));
+    }
+
+    #[test]
+    fn intersect_overlapping_intervals() {
+        assert_eq!(iv(1, 6).intersect(&iv(3, 9)), Some(iv(3, 6)));
+        assert_eq!(iv(3, 9).intersect(&iv(1, 6)), Some(iv(3, 6)));
+        assert_eq!(iv(1, 11).intersect(&iv(4, 7)), Some(iv(4, 7)));
+        assert_eq!(iv(1, 6).intersect(&iv(5, 10)), Some(iv(5, 6)));
+    }
+
+    #[test]
+    fn intersect_adjacent_and_disjoint_intervals() {
+        assert_eq!(iv(1, 4).intersect(&iv(4, 7)), None);
+        assert_eq!(iv(4, 7).intersect(&iv(1, 4)), None);
+        assert_eq!(iv(-10, -4).intersect(&iv(5, 11)), None);
+    }
+
+    #[test]
+    fn union_overlapping_intervals() {
+        assert_eq!(iv(1, 6).union(&iv(3, 9)), Some(iv(1, 9)));
+        assert_eq!(iv(3, 9).union(&iv(1, 6)), Some(iv(1, 9)));
+        assert_eq!(iv(1, 11).union(&iv(4, 7)), Some(iv(1, 11)));
+    }
+
+    #[test]
+    fn union_adjacent_intervals() {
+        assert_eq!(iv(1, 4).union(&iv(4, 7)), Some(iv(1, 7)));
+        assert_eq!(iv(4, 7).union(&iv(1, 4)), Some(iv(1, 7)));
+        assert_eq!(
+            iv(i64::MIN, 0).union(&iv(0, i64::MAX)),
+            Some(iv(i64::MIN, i64::MAX))
+        );
+    }
+
+    #[test]
+    fn union_disjoint_intervals() {
+        assert_eq!(iv(1, 4).union(&iv(5, 7)), None);
+        assert_eq!(iv(5, 7).union(&iv(1, 4)), None);
+    }
+
+    #[test]
+    fn merge_overlapping_empty_collection() {
+        let intervals = Intervals::new();
+
+        assert!(intervals.merge_overlapping().is_empty());
+        assert_eq!(intervals.total_length(), 0);
+    }
+
+    #[test]
+    fn merge_overlapping_and_adjacent_intervals() {
+        let intervals: Intervals = [iv(5, 8), iv(-3, 2), iv(0, 3), iv(8, 10), iv(20, 26)]
+            .into_iter()
+            .collect();
+        let merged = intervals.merge_overlapping();
+
+        assert_eq!(
+            merged.iter().copied().collect::<Vec<_>>(),
+            vec![iv(-3, 3), iv(5, 10), iv(20, 26)]
+        );
+        assert_eq!(merged.total_length(), 6 + 5 + 6);
+    }
+
+    #[test]
+    fn total_length_skips_empty_intervals() {
+        let intervals: Intervals = [iv(1, 4), iv(10, 2), iv(2, 6), iv(7, 7), iv(20, 22)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(intervals.total_length(), 5 + 2);
+        assert_eq!(
+            intervals
+                .merge_overlapping()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![iv(1, 6), iv(20, 22)]
+        );
+    }
+
+    #[test]
+    fn total_length_counts_overlaps_once() {
+        let intervals: Intervals = [iv(1, 11), iv(5, 16), iv(5, 16), iv(30, 31)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(intervals.len(), 4);
+        assert_eq!(intervals.total_length(), 16);
+    }
 }
@@ -1,9 +1,22 @@
+mod bounding_box2;
 mod direction;
+mod geometry;
 mod grid;
+mod pathfinding;
 mod point2;
 mod point3;
+mod sparse_grid;
 
-pub use direction::{Direction4, Direction8};
-pub use grid::{CellRef, Cells, Col, Cols, Grid, IteratorItemCountError, Points, Row, Rows};
-pub use point2::Point2;
+pub use bounding_box2::BoundingBox2;
+pub use direction::{Direction4, Direction8, TurnError};
+pub use geometry::{
+    line_points, picks_interior_points, point_in_polygon, polygon_area, polygon_perimeter_length,
+};
+pub use grid::{
+    label_grid_regions, CellRef, Cells, Col, Cols, Connectivity, Grid, GridView,
+    IteratorItemCountError, Points, Row, Rows,
+};
+pub use pathfinding::{astar, bfs_path};
+pub use point2::{diagonal_neighbors, Point2, PointOverflowError};
 pub use point3::Point3;
+pub use sparse_grid::SparseGrid;
@@ -0,0 +1,209 @@
+use super::{point2::iter_rows_inclusive, Point2};
+
+/// An axis aligned box that covers every integer point from its `min` corner
+/// to its `max` corner, inclusive.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct BoundingBox2 {
+    min: Point2,
+    max: Point2,
+}
+
+impl BoundingBox2 {
+    /// Initialize a new bounding box with corners `min` and `max`. The corners
+    /// can be given in any order, and the box will cover the region between
+    /// them.
+    pub fn new(min: Point2, max: Point2) -> Self {
+        Self {
+            min: Point2::min(min, max),
+            max: Point2::max(min, max),
+        }
+    }
+
+    /// Return the smallest bounding box that contains every point in `iter`, or
+    /// `None` if `iter` is empty.
+    pub fn from_points(iter: impl IntoIterator<Item = Point2>) -> Option<Self> {
+        let mut points = iter.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(Self::new(first, first), |b, p| b.expand_to_include(p)))
+    }
+
+    /// Get the corner with the smallest `x` and `y` values.
+    pub fn min(&self) -> Point2 {
+        self.min
+    }
+
+    /// Get the corner with the largest `x` and `y` values.
+    pub fn max(&self) -> Point2 {
+        self.max
+    }
+
+    /// Get the number of columns covered by this bounding box.
+    pub fn width(&self) -> u64 {
+        self.min.x.abs_diff(self.max.x) as u64 + 1
+    }
+
+    /// Get the number of rows covered by this bounding box.
+    pub fn height(&self) -> u64 {
+        self.min.y.abs_diff(self.max.y) as u64 + 1
+    }
+
+    /// Check if `p` is inside of this bounding box, including its edges.
+    pub fn contains(self, p: Point2) -> bool {
+        self.min.x <= p.x && p.x <= self.max.x && self.min.y <= p.y && p.y <= self.max.y
+    }
+
+    /// Return a copy of this bounding box that is grown to contain `p`.
+    pub fn expand_to_include(self, p: Point2) -> Self {
+        Self {
+            min: Point2::min(self.min, p),
+            max: Point2::max(self.max, p),
+        }
+    }
+
+    /// Return the region covered by both this bounding box and `other`, or
+    /// `None` if they do not overlap. Boxes that share an edge intersect along
+    /// that edge.
+    pub fn intersection(self, other: BoundingBox2) -> Option<BoundingBox2> {
+        let min = Point2::max(self.min, other.min);
+        let max = Point2::min(self.max, other.max);
+
+        if min.x <= max.x && min.y <= max.y {
+            Some(Self { min, max })
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of integer points covered by this bounding box.
+    pub fn area(self) -> u64 {
+        self.width() * self.height()
+    }
+
+    /// Iterate every point in this bounding box one row at a time, starting
+    /// from the `min` corner.
+    pub fn points(self) -> impl Iterator<Item = Point2> {
+        iter_rows_inclusive(self.min, self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bb(min: (isize, isize), max: (isize, isize)) -> BoundingBox2 {
+        BoundingBox2::new(min.into(), max.into())
+    }
+
+    #[test]
+    fn new_orders_corners() {
+        let b = BoundingBox2::new(Point2::new(5, -2), Point2::new(1, 3));
+
+        assert_eq!(b.min(), Point2::new(1, -2));
+        assert_eq!(b.max(), Point2::new(5, 3));
+    }
+
+    #[test]
+    fn from_points_empty() {
+        assert_eq!(BoundingBox2::from_points(Vec::new()), None);
+    }
+
+    #[test]
+    fn from_points_single_point() {
+        assert_eq!(
+            BoundingBox2::from_points([Point2::new(4, 7)]),
+            Some(bb((4, 7), (4, 7)))
+        );
+    }
+
+    #[test]
+    fn from_points_many_points() {
+        let points = [
+            Point2::new(3, 1),
+            Point2::new(-2, 4),
+            Point2::new(0, -6),
+            Point2::new(1, 1),
+        ];
+
+        assert_eq!(
+            BoundingBox2::from_points(points),
+            Some(bb((-2, -6), (3, 4)))
+        );
+    }
+
+    #[test]
+    fn contains_points() {
+        let b = bb((0, 0), (3, 2));
+
+        assert!(b.contains(Point2::new(0, 0)));
+        assert!(b.contains(Point2::new(3, 2)));
+        assert!(b.contains(Point2::new(1, 1)));
+        assert!(b.contains(Point2::new(3, 0)));
+        assert!(!b.contains(Point2::new(4, 1)));
+        assert!(!b.contains(Point2::new(1, -1)));
+        assert!(!b.contains(Point2::new(-1, 3)));
+    }
+
+    #[test]
+    fn expand_to_include_points() {
+        let b = bb((0, 0), (2, 2));
+
+        assert_eq!(b.expand_to_include(Point2::new(1, 1)), b);
+        assert_eq!(b.expand_to_include(Point2::new(5, 1)), bb((0, 0), (5, 2)));
+        assert_eq!(
+            b.expand_to_include(Point2::new(-3, -4)),
+            bb((-3, -4), (2, 2))
+        );
+    }
+
+    #[test]
+    fn intersection_of_overlapping_boxes() {
+        let a = bb((0, 0), (4, 4));
+        let b = bb((2, -1), (6, 3));
+
+        assert_eq!(a.intersection(b), Some(bb((2, 0), (4, 3))));
+        assert_eq!(b.intersection(a), Some(bb((2, 0), (4, 3))));
+        assert_eq!(a.intersection(bb((1, 1), (2, 2))), Some(bb((1, 1), (2, 2))));
+    }
+
+    #[test]
+    fn intersection_of_edge_touching_boxes() {
+        let a = bb((0, 0), (4, 4));
+
+        assert_eq!(a.intersection(bb((4, 1), (8, 2))), Some(bb((4, 1), (4, 2))));
+        assert_eq!(a.intersection(bb((4, 4), (5, 5))), Some(bb((4, 4), (4, 4))));
+    }
+
+    #[test]
+    fn intersection_of_disjoint_boxes() {
+        let a = bb((0, 0), (4, 4));
+
+        assert_eq!(a.intersection(bb((5, 0), (8, 4))), None);
+        assert_eq!(a.intersection(bb((0, -3), (4, -1))), None);
+    }
+
+    #[test]
+    fn area_counts_points() {
+        assert_eq!(bb((0, 0), (0, 0)).area(), 1);
+        assert_eq!(bb((0, 0), (3, 2)).area(), 12);
+        assert_eq!(bb((-2, -2), (2, 2)).area(), 25);
+    }
+
+    #[test]
+    fn points_in_box() {
+        let points = bb((1, 5), (2, 7)).points().collect::<Vec<_>>();
+
+        assert_eq!(
+            points,
+            vec![
+                Point2::new(1, 5),
+                Point2::new(2, 5),
+                Point2::new(1, 6),
+                Point2::new(2, 6),
+                Point2::new(1, 7),
+                Point2::new(2, 7),
+            ]
+        );
+        assert_eq!(points.len() as u64, bb((1, 5), (2, 7)).area());
+    }
+}
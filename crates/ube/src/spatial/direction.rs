@@ -14,7 +14,9 @@ const SOUTH_NAME: &str = "South";
 const SOUTHEAST_NAME: &str = "Southeast";
 
 /// Represents an east, north, west or south direction.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+///
+/// Directions are ordered counter clockwise starting from East.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Direction4 {
     East,
     North,
@@ -41,7 +43,79 @@ impl Direction4 {
         }
     }
 
-    /// Return an iterator over directions clockwise starting from East.
+    /// Rotate counter clockwise, or "left" from the perspective of a North
+    /// facing direction.
+    ///
+    /// ```
+    /// use ube::spatial::Direction4;
+    ///
+    /// assert_eq!(Direction4::North.rotated_90_ccw(), Direction4::West);
+    /// assert_eq!(Direction4::South.rotated_90_ccw(), Direction4::East);
+    /// ```
+    pub fn rotated_90_ccw(&self) -> Self {
+        match self {
+            Direction4::East => Direction4::North,
+            Direction4::North => Direction4::West,
+            Direction4::West => Direction4::South,
+            Direction4::South => Direction4::East,
+        }
+    }
+
+    /// Turn left, which is the same as `rotated_90_ccw`.
+    pub fn turn_left(&self) -> Self {
+        self.rotated_90_ccw()
+    }
+
+    /// Turn right, which is the same as `rotated_90_cw`.
+    pub fn turn_right(&self) -> Self {
+        self.rotated_90_cw()
+    }
+
+    /// Turn left or right depending on `turn`. `'L'`, `'l'` and `'<'` turn
+    /// left, and `'R'`, `'r'` and `'>'` turn right.
+    ///
+    /// ```
+    /// use ube::spatial::Direction4;
+    ///
+    /// let dir = "RRL"
+    ///     .chars()
+    ///     .try_fold(Direction4::North, |dir, c| dir.turn(c))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(dir, Direction4::East);
+    /// ```
+    pub fn turn(&self, turn: char) -> Result<Self, TurnError> {
+        match turn {
+            'L' | 'l' | '<' => Ok(self.turn_left()),
+            'R' | 'r' | '>' => Ok(self.turn_right()),
+            _ => Err(TurnError(turn)),
+        }
+    }
+
+    /// Turn left or right `steps` times depending on `turn`. See `turn` for
+    /// the characters that are accepted.
+    ///
+    /// ```
+    /// use ube::spatial::Direction4;
+    ///
+    /// assert_eq!(Direction4::North.turn_steps('R', 2), Ok(Direction4::South));
+    /// assert_eq!(Direction4::North.turn_steps('L', 3), Ok(Direction4::East));
+    /// ```
+    pub fn turn_steps(&self, turn: char, steps: u32) -> Result<Self, TurnError> {
+        // Check that `turn` is valid even when no turns are needed.
+        let mut dir = *self;
+        self.turn(turn)?;
+
+        // Turning four times in the same direction returns to the start.
+        for _ in 0..(steps % 4) {
+            dir = dir.turn(turn)?;
+        }
+
+        Ok(dir)
+    }
+
+    /// Return an iterator over directions clockwise starting from North,
+    /// which is the order of repeatedly turning right after starting North.
     ///
     /// ```
     /// use ube::spatial::Direction4;
@@ -53,16 +127,16 @@ impl Direction4 {
     /// assert_eq!(
     ///   Direction4::all().collect::<Vec<Direction4>>(),
     ///   [
-    ///     Direction4::East,
     ///     Direction4::North,
-    ///     Direction4::West,
+    ///     Direction4::East,
     ///     Direction4::South,
+    ///     Direction4::West,
     ///   ]
     /// );
     /// ```
     pub fn all() -> Direction4Itr {
         Direction4Itr {
-            next: Some(Direction4::East),
+            next: Some(Direction4::North),
         }
     }
 }
@@ -82,6 +156,10 @@ impl std::fmt::Display for Direction4 {
 #[error("the direction name `{}` was not recogonized", .0)]
 pub struct NoSuchDirectionNameError(String);
 
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("the character `{}` is not a left or right turn", .0)]
+pub struct TurnError(char);
+
 impl FromStr for Direction4 {
     type Err = NoSuchDirectionNameError;
 
@@ -123,10 +201,10 @@ impl Iterator for Direction4Itr {
         let next = self.next;
 
         self.next = self.next.and_then(|d| match d {
-            Direction4::East => Some(Direction4::North),
-            Direction4::North => Some(Direction4::West),
-            Direction4::West => Some(Direction4::South),
-            Direction4::South => None,
+            Direction4::North => Some(Direction4::East),
+            Direction4::East => Some(Direction4::South),
+            Direction4::South => Some(Direction4::West),
+            Direction4::West => None,
         });
 
         next
@@ -135,7 +213,9 @@ impl Iterator for Direction4Itr {
 
 /// Represents east, north, west, south directions and the four diagonals
 /// between.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+///
+/// Directions are ordered counter clockwise starting from East.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Direction8 {
     East,
     Northeast,
@@ -284,19 +364,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn turn_direction4() {
+        for turn in ['L', 'l', '<'] {
+            assert_eq!(Direction4::North.turn(turn), Ok(Direction4::West));
+            assert_eq!(Direction4::West.turn(turn), Ok(Direction4::South));
+            assert_eq!(Direction4::South.turn(turn), Ok(Direction4::East));
+            assert_eq!(Direction4::East.turn(turn), Ok(Direction4::North));
+        }
+
+        for turn in ['R', 'r', '>'] {
+            assert_eq!(Direction4::North.turn(turn), Ok(Direction4::East));
+            assert_eq!(Direction4::East.turn(turn), Ok(Direction4::South));
+            assert_eq!(Direction4::South.turn(turn), Ok(Direction4::West));
+            assert_eq!(Direction4::West.turn(turn), Ok(Direction4::North));
+        }
+
+        assert_eq!(Direction4::North.turn('x'), Err(TurnError('x')));
+        assert_eq!(Direction4::North.turn('F'), Err(TurnError('F')));
+    }
+
+    #[test]
+    fn turn_direction4_steps() {
+        for dir in Direction4::all() {
+            assert_eq!(dir.turn_steps('L', 4), Ok(dir));
+            assert_eq!(dir.turn_steps('R', 4), Ok(dir));
+            assert_eq!(dir.turn_steps('L', 0), Ok(dir));
+            assert_eq!(dir.turn_steps('L', 1), Ok(dir.turn_left()));
+            assert_eq!(dir.turn_steps('R', 5), Ok(dir.turn_right()));
+            assert_eq!(dir.turn_steps('L', 2), dir.turn_steps('R', 2));
+        }
+
+        assert_eq!(Direction4::North.turn_steps('?', 0), Err(TurnError('?')));
+        assert_eq!(Direction4::North.turn_steps('?', 3), Err(TurnError('?')));
+    }
+
     #[test]
     fn iterate_direction4() {
         assert_eq!(
             Direction4::all().collect::<Vec<Direction4>>(),
             [
-                Direction4::East,
                 Direction4::North,
-                Direction4::West,
-                Direction4::South
+                Direction4::East,
+                Direction4::South,
+                Direction4::West
             ]
         );
     }
 
+    #[test]
+    fn iterate_direction4_is_turning_right_from_north() {
+        let mut dir = Direction4::North;
+
+        for expected in Direction4::all() {
+            assert_eq!(dir, expected);
+            dir = dir.turn_right();
+        }
+
+        assert_eq!(dir, Direction4::North);
+    }
+
     #[test]
     fn format_direction4() {
         assert_eq!(&format!("{}", Direction4::East), "East");
@@ -425,4 +552,62 @@ mod tests {
             Err(NoSuchDirectionNameError("E".to_string()))
         );
     }
+
+    #[test]
+    fn direction4_sort_order() {
+        let mut dirs = vec![
+            Direction4::South,
+            Direction4::North,
+            Direction4::East,
+            Direction4::West,
+        ];
+        dirs.sort();
+
+        assert_eq!(
+            dirs,
+            vec![
+                Direction4::East,
+                Direction4::North,
+                Direction4::West,
+                Direction4::South
+            ]
+        );
+
+        assert!(Direction4::East < Direction4::West);
+        assert!(Direction4::North < Direction4::South);
+        assert!(Direction4::South > Direction4::North);
+    }
+
+    #[test]
+    fn direction8_sort_order() {
+        let dirs: std::collections::BTreeSet<Direction8> = [
+            Direction8::Southeast,
+            Direction8::West,
+            Direction8::North,
+            Direction8::East,
+            Direction8::Southwest,
+            Direction8::Northwest,
+            Direction8::South,
+            Direction8::Northeast,
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            dirs.into_iter().collect::<Vec<_>>(),
+            vec![
+                Direction8::East,
+                Direction8::Northeast,
+                Direction8::North,
+                Direction8::Northwest,
+                Direction8::West,
+                Direction8::Southwest,
+                Direction8::South,
+                Direction8::Southeast,
+            ]
+        );
+
+        assert!(Direction8::Northeast < Direction8::Southwest);
+        assert!(Direction8::Southeast > Direction8::Northwest);
+    }
 }
@@ -0,0 +1,362 @@
+use super::Point2;
+
+/// Iterates the points on the line from `from` to `to` using Bresenham's line
+/// algorithm. Both `from` and `to` are included, and consecutive points are
+/// always adjacent (including diagonally) which means the line has exactly
+/// `max(|dx|, |dy|) + 1` points.
+///
+/// ```
+/// use ube::spatial::{line_points, Point2};
+///
+/// let points: Vec<Point2> = line_points(Point2::new(0, 0), Point2::new(4, 2)).collect();
+///
+/// assert_eq!(
+///     points,
+///     vec![
+///         Point2::new(0, 0),
+///         Point2::new(1, 1),
+///         Point2::new(2, 1),
+///         Point2::new(3, 2),
+///         Point2::new(4, 2),
+///     ]
+/// );
+/// ```
+pub fn line_points(from: Point2, to: Point2) -> impl Iterator<Item = Point2> {
+    let dx = (to.x - from.x).abs();
+    let dy = -(to.y - from.y).abs();
+    let step = Point2::new((to.x - from.x).signum(), (to.y - from.y).signum());
+
+    let mut next = Some(from);
+    let mut error = dx + dy;
+
+    std::iter::from_fn(move || {
+        let mut p = next?;
+        let current = p;
+
+        if p == to {
+            next = None;
+        } else {
+            let e2 = 2 * error;
+
+            if e2 >= dy {
+                error += dy;
+                p.x += step.x;
+            }
+
+            if e2 <= dx {
+                error += dx;
+                p.y += step.y;
+            }
+
+            next = Some(p);
+        }
+
+        Some(current)
+    })
+}
+
+/// Calculates the area of the polygon formed by `vertices` using the shoelace
+/// formula. The polygon is closed by connecting the last vertex back to the
+/// first one.
+///
+/// The returned area is signed: it is positive when the vertices are wound
+/// counter-clockwise (with the Y axis pointing up), and negative when they are
+/// wound clockwise. Areas that are not whole numbers are truncated toward zero.
+///
+/// ```
+/// use ube::spatial::{polygon_area, Point2};
+///
+/// let square = [(0, 0), (4, 0), (4, 4), (0, 4)].map(Point2::from);
+/// assert_eq!(polygon_area(&square), 16);
+/// ```
+pub fn polygon_area(vertices: &[Point2]) -> i64 {
+    let twice_area: i64 = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| (a.x * b.y - b.x * a.y) as i64)
+        .sum();
+
+    twice_area / 2
+}
+
+/// Uses Pick's theorem (`A = I + B/2 - 1`) to count the integer points strictly
+/// inside of a polygon whose vertices are all integer points, given the area
+/// of the polygon and the number of integer points on its boundary.
+///
+/// The sign of `area` is ignored so the result of `polygon_area` can be passed
+/// in directly regardless of winding order.
+///
+/// ```
+/// use ube::spatial::{picks_interior_points, polygon_area, Point2};
+///
+/// let square = [(0, 0), (4, 0), (4, 4), (0, 4)].map(Point2::from);
+/// assert_eq!(picks_interior_points(polygon_area(&square), 16), 9);
+/// ```
+pub fn picks_interior_points(area: i64, boundary_points: usize) -> i64 {
+    area.abs() - boundary_points as i64 / 2 + 1
+}
+
+/// Checks if `point` is inside of the polygon formed by `polygon` using ray
+/// casting, where a horizontal ray from `point` crosses the polygon's edges an
+/// odd number of times when the point is inside. The polygon is closed by
+/// connecting the last vertex back to the first one.
+///
+/// Points that lie exactly on an edge or vertex are considered inside.
+///
+/// ```
+/// use ube::spatial::{point_in_polygon, Point2};
+///
+/// let square = [(0, 0), (4, 0), (4, 4), (0, 4)].map(Point2::from);
+///
+/// assert!(point_in_polygon(Point2::new(2, 2), &square));
+/// assert!(point_in_polygon(Point2::new(4, 1), &square));
+/// assert!(!point_in_polygon(Point2::new(5, 2), &square));
+/// ```
+pub fn point_in_polygon(point: Point2, polygon: &[Point2]) -> bool {
+    let (px, py) = (point.x as i64, point.y as i64);
+    let mut inside = false;
+
+    for (a, b) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+        let (ax, ay) = (a.x as i64, a.y as i64);
+        let (bx, by) = (b.x as i64, b.y as i64);
+
+        // The point is on this edge when it is collinear with the edge and
+        // within the edge's bounding box.
+        let cross = (bx - ax) * (py - ay) - (by - ay) * (px - ax);
+
+        if cross == 0
+            && px >= ax.min(bx)
+            && px <= ax.max(bx)
+            && py >= ay.min(by)
+            && py <= ay.max(by)
+        {
+            return true;
+        }
+
+        // Count the edge as crossed when it straddles the ray and the point is
+        // left of where the edge intersects the ray. Comparing the cross
+        // product's sign avoids dividing to find the intersection.
+        if (ay > py) != (by > py) && (cross > 0) == (by > ay) {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+/// Calculates the length of the perimeter of the polygon formed by `polygon`
+/// by summing the Manhattan distance of each edge. The polygon is closed by
+/// connecting the last vertex back to the first one.
+///
+/// This is the number of boundary points for polygons whose edges are all
+/// horizontal or vertical, which makes it a good fit for `picks_interior_points`.
+///
+/// ```
+/// use ube::spatial::{polygon_perimeter_length, Point2};
+///
+/// let square = [(0, 0), (4, 0), (4, 4), (0, 4)].map(Point2::from);
+/// assert_eq!(polygon_perimeter_length(&square), 16);
+/// ```
+pub fn polygon_perimeter_length(polygon: &[Point2]) -> i64 {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| a.manhattan_distance(*b) as i64)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(from: (isize, isize), to: (isize, isize)) -> Vec<Point2> {
+        line_points(from.into(), to.into()).collect()
+    }
+
+    #[test]
+    fn horizontal_line() {
+        assert_eq!(
+            line((1, 3), (4, 3)),
+            vec![
+                Point2::new(1, 3),
+                Point2::new(2, 3),
+                Point2::new(3, 3),
+                Point2::new(4, 3)
+            ]
+        );
+        assert_eq!(
+            line((4, 3), (2, 3)),
+            vec![Point2::new(4, 3), Point2::new(3, 3), Point2::new(2, 3)]
+        );
+    }
+
+    #[test]
+    fn vertical_line() {
+        assert_eq!(
+            line((2, -1), (2, 1)),
+            vec![Point2::new(2, -1), Point2::new(2, 0), Point2::new(2, 1)]
+        );
+        assert_eq!(
+            line((2, 1), (2, -1)),
+            vec![Point2::new(2, 1), Point2::new(2, 0), Point2::new(2, -1)]
+        );
+    }
+
+    #[test]
+    fn diagonal_line() {
+        assert_eq!(
+            line((0, 0), (3, 3)),
+            vec![
+                Point2::new(0, 0),
+                Point2::new(1, 1),
+                Point2::new(2, 2),
+                Point2::new(3, 3)
+            ]
+        );
+        assert_eq!(
+            line((0, 0), (-2, 2)),
+            vec![Point2::new(0, 0), Point2::new(-1, 1), Point2::new(-2, 2)]
+        );
+    }
+
+    #[test]
+    fn single_point_line() {
+        assert_eq!(line((5, 7), (5, 7)), vec![Point2::new(5, 7)]);
+    }
+
+    #[test]
+    fn all_octants_include_endpoints_and_have_expected_length() {
+        let from = Point2::new(1, -2);
+
+        for to in [
+            (6, 0),
+            (3, 5),
+            (-1, 5),
+            (-6, 0),
+            (-6, -4),
+            (-1, -9),
+            (3, -9),
+            (6, -4),
+        ] {
+            let to = Point2::from(to);
+            let points: Vec<Point2> = line_points(from, to).collect();
+            let d = (to - from).abs();
+
+            assert_eq!(points.len(), d.x.max(d.y) as usize + 1, "{from} to {to}");
+            assert_eq!(points.first(), Some(&from));
+            assert_eq!(points.last(), Some(&to));
+            assert!(points.windows(2).all(|w| {
+                let step = (w[1] - w[0]).abs();
+                step.x <= 1 && step.y <= 1 && step != Point2::zero()
+            }));
+        }
+    }
+
+    fn polygon(vertices: &[(isize, isize)]) -> Vec<Point2> {
+        vertices.iter().copied().map(Point2::from).collect()
+    }
+
+    #[test]
+    fn unit_square_area() {
+        let square = polygon(&[(0, 0), (1, 0), (1, 1), (0, 1)]);
+
+        assert_eq!(polygon_area(&square), 1);
+        assert_eq!(picks_interior_points(polygon_area(&square), 4), 0);
+    }
+
+    #[test]
+    fn l_shaped_polygon_area() {
+        let l_shape = polygon(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]);
+
+        assert_eq!(polygon_area(&l_shape), 12);
+        assert_eq!(picks_interior_points(polygon_area(&l_shape), 16), 5);
+    }
+
+    #[test]
+    fn area_sign_flips_with_winding_order() {
+        let mut triangle = polygon(&[(0, 0), (6, 0), (0, 3)]);
+        assert_eq!(polygon_area(&triangle), 9);
+
+        triangle.reverse();
+        assert_eq!(polygon_area(&triangle), -9);
+        assert_eq!(picks_interior_points(polygon_area(&triangle), 12), 4);
+    }
+
+    #[test]
+    fn degenerate_polygon_area() {
+        assert_eq!(polygon_area(&[]), 0);
+        assert_eq!(polygon_area(&polygon(&[(3, 4)])), 0);
+        assert_eq!(polygon_area(&polygon(&[(0, 0), (5, 5)])), 0);
+    }
+
+    #[test]
+    fn point_in_square() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+
+        assert!(point_in_polygon(Point2::new(1, 1), &square));
+        assert!(point_in_polygon(Point2::new(3, 2), &square));
+        assert!(!point_in_polygon(Point2::new(-1, 2), &square));
+        assert!(!point_in_polygon(Point2::new(5, 2), &square));
+        assert!(!point_in_polygon(Point2::new(2, 5), &square));
+        assert!(!point_in_polygon(Point2::new(6, 6), &square));
+    }
+
+    #[test]
+    fn point_in_l_shaped_polygon() {
+        let l_shape = polygon(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]);
+
+        assert!(point_in_polygon(Point2::new(1, 1), &l_shape));
+        assert!(point_in_polygon(Point2::new(3, 1), &l_shape));
+        assert!(point_in_polygon(Point2::new(1, 3), &l_shape));
+        assert!(!point_in_polygon(Point2::new(3, 3), &l_shape));
+
+        // Clockwise and counter-clockwise winding give the same result.
+        let reversed: Vec<Point2> = l_shape.iter().rev().copied().collect();
+        assert!(point_in_polygon(Point2::new(3, 1), &reversed));
+        assert!(!point_in_polygon(Point2::new(3, 3), &reversed));
+    }
+
+    #[test]
+    fn point_on_polygon_boundary_is_inside() {
+        let l_shape = polygon(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]);
+
+        assert!(point_in_polygon(Point2::new(0, 0), &l_shape));
+        assert!(point_in_polygon(Point2::new(2, 0), &l_shape));
+        assert!(point_in_polygon(Point2::new(3, 2), &l_shape));
+        assert!(point_in_polygon(Point2::new(2, 3), &l_shape));
+        assert!(point_in_polygon(Point2::new(2, 2), &l_shape));
+    }
+
+    #[test]
+    fn point_in_triangle_with_sloped_edges() {
+        let triangle = polygon(&[(0, 0), (6, 0), (0, 3)]);
+
+        assert!(point_in_polygon(Point2::new(1, 1), &triangle));
+        assert!(point_in_polygon(Point2::new(2, 2), &triangle));
+        assert!(!point_in_polygon(Point2::new(4, 2), &triangle));
+    }
+
+    #[test]
+    fn point_in_degenerate_polygon() {
+        assert!(!point_in_polygon(Point2::new(0, 0), &[]));
+        assert!(point_in_polygon(Point2::new(3, 4), &polygon(&[(3, 4)])));
+        assert!(!point_in_polygon(Point2::new(3, 5), &polygon(&[(3, 4)])));
+    }
+
+    #[test]
+    fn polygon_perimeter() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        let l_shape = polygon(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]);
+
+        assert_eq!(polygon_perimeter_length(&square), 16);
+        assert_eq!(polygon_perimeter_length(&l_shape), 16);
+        assert_eq!(
+            picks_interior_points(
+                polygon_area(&l_shape),
+                polygon_perimeter_length(&l_shape) as usize
+            ),
+            5
+        );
+        assert_eq!(polygon_perimeter_length(&[]), 0);
+    }
+}
@@ -1,12 +1,16 @@
 use std::{
     borrow::{Borrow, BorrowMut},
     iter::FusedIterator,
+    path::Path,
     str::FromStr,
 };
 
 use thiserror::Error;
 
-use crate::spatial::Point2;
+use crate::{
+    spatial::{diagonal_neighbors, BoundingBox2, Direction4, Direction8, Point2},
+    union_find::UnionFind,
+};
 
 /// # Overview
 /// A two dimensional grid with each cell storing a single value.
@@ -45,7 +49,7 @@ use crate::spatial::Point2;
 ///     let grid: Grid<u32> = Grid::parse_str(input, |c| c.to_digit(10).unwrap()).unwrap();
 ///
 ///     assert_eq!(grid.get(0, 0), &3);    
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Grid<T> {
     /// Array of values stored in the grid.
     cells: Vec<T>,
@@ -102,10 +106,12 @@ where
 
 impl<T> Grid<T> {
     /// Parses an input string as a grid by storing the result of `map_func` on
-    /// each character in the input string.
+    /// each character in the input string. `Grid<char>` can be parsed directly
+    /// with `str::parse`, and `Grid::from_str_with` is the preferred way to
+    /// parse a puzzle map into a grid of displayable tiles.
     ///
     /// Rows are terminated with a newline character and are not passed to the
-    /// map function. Rows are expected to be the same length.
+    /// map function. Rows are expected to have the same number of characters.
     ///
     /// ```
     /// use ube::spatial::{Grid, Point2};
@@ -126,7 +132,7 @@ impl<T> Grid<T> {
             let mut y_count = 0;
 
             for line in s.lines() {
-                x_count = x_count.or(Some(line.len()));
+                x_count = x_count.or_else(|| Some(line.chars().count()));
                 y_count += 1;
             }
 
@@ -138,6 +144,25 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Parses a puzzle map into a grid of tiles by converting each character in
+    /// `s` with `f`. This is the canonical way to parse a grid of custom tiles,
+    /// and follows the same rules as `Grid::parse_str`.
+    ///
+    /// ```
+    /// use ube::spatial::{Grid, Point2};
+    ///
+    /// let grid = Grid::from_str_with("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+    ///
+    /// assert_eq!(grid[Point2::new(1, 1)], 4);
+    /// assert_eq!(grid.to_string(), "12\n34\n");
+    /// ```
+    pub fn from_str_with(s: &str, f: impl Fn(char) -> T) -> Result<Self, IteratorItemCountError>
+    where
+        T: std::fmt::Display,
+    {
+        Self::parse_str(s, f)
+    }
+
     /// Return a new grid of `y_count` rows and `x_count` cols where each cell
     /// value is taken from the iterator `vals` in row major order.
     ///
@@ -255,6 +280,22 @@ impl<T> Grid<T> {
         )]
     }
 
+    /// Get a reference to the value stored at an `(x, y)` position given as
+    /// `usize` values, or `None` if either value is larger than `isize::MAX`
+    /// or the position is outside of the grid.
+    pub fn try_index(&self, (x, y): (usize, usize)) -> Option<&T> {
+        let p = Point2::new(isize::try_from(x).ok()?, isize::try_from(y).ok()?);
+        self.is_pos_in_bounds(p).then(|| &self[p])
+    }
+
+    /// Get a mutable reference to the value stored at an `(x, y)` position
+    /// given as `usize` values, or `None` if either value is larger than
+    /// `isize::MAX` or the position is outside of the grid.
+    pub fn try_index_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        let p = Point2::new(isize::try_from(x).ok()?, isize::try_from(y).ok()?);
+        self.is_pos_in_bounds(p).then(|| &mut self[p])
+    }
+
     /// Set the value stored at the given `x` column and `y` row.
     pub fn set(&mut self, x: isize, y: isize, value: T) {
         debug_assert!(x < self.x_count as isize - self.x_origin_offset);
@@ -269,12 +310,52 @@ impl<T> Grid<T> {
         )] = value
     }
 
+    /// Get a reference to the value at the given `x` column and `y` row, where
+    /// coordinates outside of the grid wrap around to the opposite side as if
+    /// the grid was infinitely tiled.
+    ///
+    /// ```
+    /// use ube::spatial::Grid;
+    ///
+    /// let grid = Grid::with_values(3, 2, 0..6).unwrap();
+    ///
+    /// assert_eq!(grid.wrap_get(-1, 0), grid.get(2, 0));
+    /// assert_eq!(grid.wrap_get(4, -3), grid.get(1, 1));
+    /// ```
+    pub fn wrap_get(&'_ self, x: isize, y: isize) -> &'_ T {
+        let (x, y) = self.wrap_xy(x, y);
+        self.get(x, y)
+    }
+
+    /// Set the value stored at the given `x` column and `y` row, where
+    /// coordinates outside of the grid wrap around to the opposite side as if
+    /// the grid was infinitely tiled.
+    pub fn wrap_set(&mut self, x: isize, y: isize, value: T) {
+        let (x, y) = self.wrap_xy(x, y);
+        self.set(x, y, value)
+    }
+
+    /// Wraps the given `x` column and `y` row so they fall within the grid.
+    fn wrap_xy(&self, x: isize, y: isize) -> (isize, isize) {
+        assert!(
+            self.x_count > 0 && self.y_count > 0,
+            "cannot wrap coordinates in an empty grid"
+        );
+
+        let top_left = self.top_left();
+
+        (
+            top_left.x + (x - top_left.x).rem_euclid(self.x_count as isize),
+            top_left.y + (y - top_left.y).rem_euclid(self.y_count as isize),
+        )
+    }
+
     /// Returns an iterator to the cells stored in the grid.
     ///
     /// This iterator will iterate row starting from at the first (top most)
     /// row, and iterate through every column left to right before proceeding to
     /// the next row.
-    pub fn iter(&self) -> Cells<T> {
+    pub fn iter(&self) -> Cells<'_, T> {
         Cells {
             points: self.points(),
             grid: self,
@@ -285,37 +366,95 @@ impl<T> Grid<T> {
     /// one row at a time left to right, starting at the top left and ending at
     /// the bottom right.
     pub fn points(&self) -> Points {
-        let topleft_x = -self.x_origin_offset;
-        let topleft_y = -self.y_origin_offset;
-
-        Points {
-            next_x: topleft_x,
-            next_y: topleft_y,
-            start_x: topleft_x,
-            end_x: topleft_x + (self.x_count as isize),
-            end_y: topleft_y + (self.y_count as isize),
-        }
+        Points::new(
+            Point2::new(-self.x_origin_offset, -self.y_origin_offset),
+            self.x_count,
+            self.y_count,
+        )
     }
 
     /// Returns an iterator over the points for the cells in the requested row.
     pub fn row(&self, row_index: isize) -> Option<Points> {
         if row_index >= 0 && (row_index as usize) < self.y_count() {
-            let topleft_x = -self.x_origin_offset;
-            let topleft_y = -self.y_origin_offset;
-            let y = topleft_y + row_index;
-
-            Some(Points {
-                next_x: topleft_x,
-                next_y: y,
-                start_x: topleft_x,
-                end_x: topleft_x + (self.x_count as isize),
-                end_y: y + 1,
-            })
+            Some(Points::new(
+                Point2::new(-self.x_origin_offset, row_index - self.y_origin_offset),
+                self.x_count,
+                1,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the values of the cells in the requested row,
+    /// or `None` if the row is not in the grid. Rows are indexed from zero
+    /// starting at the top row, the same as `row`.
+    ///
+    /// ```
+    /// use ube::spatial::Grid;
+    ///
+    /// let grid: Grid<char> = "abc\ndef".parse().unwrap();
+    ///
+    /// assert_eq!(grid.row_values(1).unwrap().collect::<String>(), "def");
+    /// assert!(grid.row_values(2).is_none());
+    /// ```
+    pub fn row_values(&self, row_index: isize) -> Option<impl Iterator<Item = &T>> {
+        if row_index >= 0 && (row_index as usize) < self.y_count {
+            let start = row_index as usize * self.x_count;
+            Some(self.cells[start..(start + self.x_count)].iter())
         } else {
             None
         }
     }
 
+    /// Returns an iterator over the values of the cells in the requested
+    /// column from top to bottom, or `None` if the column is not in the grid.
+    /// Columns are indexed from zero starting at the leftmost column.
+    ///
+    /// ```
+    /// use ube::spatial::Grid;
+    ///
+    /// let grid: Grid<char> = "abc\ndef".parse().unwrap();
+    ///
+    /// assert_eq!(grid.col_values(1).unwrap().collect::<String>(), "be");
+    /// assert!(grid.col_values(-1).is_none());
+    /// ```
+    pub fn col_values(&self, col_index: isize) -> Option<impl Iterator<Item = &T>> {
+        if col_index >= 0 && (col_index as usize) < self.x_count {
+            Some(
+                self.cells
+                    .iter()
+                    .skip(col_index as usize)
+                    .step_by(self.x_count),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the in bounds cells that are diagonally
+    /// adjacent to `pt`, along with their positions. Cells are visited in the
+    /// same northeast, northwest, southwest and southeast order as
+    /// `diagonal_neighbors`.
+    ///
+    /// ```
+    /// use ube::spatial::{Grid, Point2};
+    ///
+    /// let grid: Grid<char> = "M.S\n.A.\nM.S".parse().unwrap();
+    /// let corners: String = grid
+    ///     .diagonal_neighbors_4(Point2::new(1, 1))
+    ///     .map(|(_, c)| c)
+    ///     .collect();
+    ///
+    /// assert_eq!(corners, "SMMS");
+    /// ```
+    pub fn diagonal_neighbors_4(&self, pt: Point2) -> impl Iterator<Item = (Point2, &T)> {
+        diagonal_neighbors(pt)
+            .into_iter()
+            .filter(|p| self.is_pos_in_bounds(*p))
+            .map(|p| (p, &self[p]))
+    }
+
     /// Returns an iterator over all the rows in the grid.
     pub fn rows(&self) -> Rows {
         let top_left = self.top_left();
@@ -324,6 +463,269 @@ impl<T> Grid<T> {
 
         Rows::new(top_left, dims.x, dims.y)
     }
+
+    /// Format this grid as a string by calling `cell_fn` for each cell and
+    /// concatenating the strings it returns. Each row is followed by a newline,
+    /// matching the output of `Display`.
+    ///
+    /// This is useful when debugging to overlay extra information on a grid.
+    ///
+    /// ```
+    /// use ube::spatial::{Grid, Point2};
+    ///
+    /// let grid: Grid<char> = "..#\n#..".parse().unwrap();
+    /// let visited = [Point2::new(0, 0), Point2::new(1, 0)];
+    ///
+    /// assert_eq!(
+    ///     grid.format_with(|p, c| if visited.contains(&p) { "*".to_string() } else { c.to_string() }),
+    ///     "**#\n#..\n"
+    /// );
+    /// ```
+    pub fn format_with(&self, cell_fn: impl Fn(Point2, &T) -> String) -> String {
+        let mut text = String::new();
+
+        for row in self.rows() {
+            for p in row {
+                text.push_str(&cell_fn(p, &self[p]));
+            }
+
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Print this grid to stdout using `format_with`.
+    pub fn print_with(&self, cell_fn: impl Fn(Point2, &T) -> String) {
+        print!("{}", self.format_with(cell_fn));
+    }
+
+    /// Render this grid as a binary PPM (P6) image with one pixel per cell,
+    /// where `color_fn` maps each cell to an RGB color.
+    ///
+    /// PPM is simple enough to write by hand and most image viewers can open
+    /// it, which makes it handy for inspecting large grids.
+    ///
+    /// ```
+    /// use ube::spatial::Grid;
+    ///
+    /// let grid: Grid<char> = "#.".parse().unwrap();
+    /// let image = grid.to_ppm(|c| if *c == '#' { [255, 255, 255] } else { [0, 0, 0] });
+    ///
+    /// assert_eq!(image, b"P6\n2 1\n255\n\xff\xff\xff\x00\x00\x00");
+    /// ```
+    pub fn to_ppm(&self, color_fn: impl Fn(&T) -> [u8; 3]) -> Vec<u8> {
+        let mut image = format!("P6\n{} {}\n255\n", self.x_count, self.y_count).into_bytes();
+        image.reserve(self.cells.len() * 3);
+
+        for cell in &self.cells {
+            image.extend_from_slice(&color_fn(cell));
+        }
+
+        image
+    }
+
+    /// Write this grid to `path` as a binary PPM image using `to_ppm`.
+    pub fn save_ppm(&self, path: &Path, color_fn: impl Fn(&T) -> [u8; 3]) -> std::io::Result<()> {
+        std::fs::write(path, self.to_ppm(color_fn))
+    }
+
+    /// Render this grid as a binary PGM (P5) image with one pixel per cell,
+    /// where `gray_fn` maps each cell to a grayscale intensity.
+    ///
+    /// ```
+    /// use ube::spatial::Grid;
+    ///
+    /// let grid: Grid<char> = "#.".parse().unwrap();
+    /// let image = grid.to_pgm(|c| if *c == '#' { 255 } else { 0 });
+    ///
+    /// assert_eq!(image, b"P5\n2 1\n255\n\xff\x00");
+    /// ```
+    pub fn to_pgm(&self, gray_fn: impl Fn(&T) -> u8) -> Vec<u8> {
+        let mut image = format!("P5\n{} {}\n255\n", self.x_count, self.y_count).into_bytes();
+        image.extend(self.cells.iter().map(gray_fn));
+        image
+    }
+
+    /// Write this grid to `path` as a binary PGM image using `to_pgm`.
+    pub fn save_pgm(&self, path: &Path, gray_fn: impl Fn(&T) -> u8) -> std::io::Result<()> {
+        std::fs::write(path, self.to_pgm(gray_fn))
+    }
+
+    /// Returns an iterator over every overlapping `win_x` by `win_y` region of
+    /// the grid, along with the position of the region's top left cell. Regions
+    /// are returned in row major order, and no regions are returned when the
+    /// window is larger than the grid.
+    ///
+    /// ```
+    /// use ube::spatial::{Grid, Point2};
+    ///
+    /// let grid: Grid<char> = "abc\ndef".parse().unwrap();
+    /// let windows = grid.windows(2, 2).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(windows.len(), 2);
+    /// assert_eq!(windows[1].0, Point2::new(1, 0));
+    /// assert_eq!(windows[1].1.get(1, 1), &'f');
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `win_x` or `win_y` is zero.
+    pub fn windows(
+        &self,
+        win_x: usize,
+        win_y: usize,
+    ) -> impl Iterator<Item = (Point2, GridView<'_, T>)> {
+        assert!(win_x > 0 && win_y > 0, "windows must be at least 1x1");
+
+        let top_left = self.top_left();
+        let x_windows = (self.x_count + 1).saturating_sub(win_x);
+        let y_windows = (self.y_count + 1).saturating_sub(win_y);
+
+        (0..y_windows as isize)
+            .flat_map(move |y| (0..x_windows as isize).map(move |x| Point2::new(x, y)))
+            .map(move |offset| {
+                let origin = top_left + offset;
+
+                (
+                    origin,
+                    GridView {
+                        grid: self,
+                        origin,
+                        x_count: win_x,
+                        y_count: win_y,
+                    },
+                )
+            })
+    }
+}
+
+/// A read only view of a rectangular region in a `Grid`. Positions are relative
+/// to the top left cell of the region.
+#[derive(Clone, Copy, Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point2,
+    x_count: usize,
+    y_count: usize,
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// Return the number of columns in the view.
+    pub fn x_count(&self) -> usize {
+        self.x_count
+    }
+
+    /// Return the number of rows in the view.
+    pub fn y_count(&self) -> usize {
+        self.y_count
+    }
+
+    /// Return the position in the grid of the view's top left cell.
+    pub fn origin(&self) -> Point2 {
+        self.origin
+    }
+
+    /// Get a reference to the value stored at the given `x` column and `y` row
+    /// relative to the top left cell of the view.
+    ///
+    /// # Panics
+    /// Panics if the position is outside of the view, even when the grid has a
+    /// cell at that position.
+    pub fn get(&self, x: isize, y: isize) -> &'a T {
+        assert!(
+            x >= 0 && x < self.x_count as isize && y >= 0 && y < self.y_count as isize,
+            "position ({x}, {y}) is outside of the {}x{} view",
+            self.x_count,
+            self.y_count
+        );
+
+        &self.grid[self.origin + Point2::new(x, y)]
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Replace every cell with the value returned by `f`, which is called with
+    /// the cell's position, its current value and a snapshot of the grid from
+    /// before any cells were changed.
+    ///
+    /// The snapshot lets `f` look at neighboring cells without seeing values
+    /// written earlier in the same update, which is what cellular automatons
+    /// like Conway's Game of Life need.
+    ///
+    /// ```
+    /// use ube::spatial::{Grid, Point2};
+    ///
+    /// let mut grid: Grid<u32> = Grid::with_values(3, 1, [1, 2, 3]).unwrap();
+    ///
+    /// // Each cell becomes the sum of itself and the cell to its left.
+    /// grid.apply_in_place(|p, v, old| match p.x {
+    ///     0 => *v,
+    ///     _ => v + old[p - Point2::new(1, 0)],
+    /// });
+    ///
+    /// assert_eq!(grid, Grid::with_values(3, 1, [1, 3, 5]).unwrap());
+    /// ```
+    pub fn apply_in_place(&mut self, f: impl Fn(Point2, &T, &Grid<T>) -> T) {
+        let snapshot = self.clone();
+
+        for p in snapshot.points() {
+            self[p] = f(p, &snapshot[p], &snapshot);
+        }
+    }
+
+    /// Copy the cells of `other` into this grid, with the top left cell of
+    /// `other` placed at `offset`. Cells that land outside of this grid are
+    /// skipped.
+    ///
+    /// ```
+    /// use ube::spatial::{Grid, Point2};
+    ///
+    /// let mut screen: Grid<char> = "....\n....".parse().unwrap();
+    /// let sprite: Grid<char> = "ab\ncd".parse().unwrap();
+    ///
+    /// screen.paste_clipped(&sprite, Point2::new(3, 1));
+    /// assert_eq!(screen.to_string(), "....\n...a\n");
+    /// ```
+    pub fn paste_clipped(&mut self, other: &Grid<T>, offset: Point2) {
+        for p in other.points() {
+            let target = p - other.top_left() + offset;
+
+            if self.is_pos_in_bounds(target) {
+                self[target] = other[p].clone();
+            }
+        }
+    }
+
+    /// Return a copy of the smallest region of this grid that contains every
+    /// cell where `is_empty` returns false, which removes any empty rows and
+    /// columns along the border. A 0x0 grid is returned when every cell is
+    /// empty.
+    ///
+    /// The top left cell of the returned grid is at (0, 0).
+    ///
+    /// ```
+    /// use ube::spatial::Grid;
+    ///
+    /// let grid: Grid<char> = ".....\n..#..\n.##..\n.....".parse().unwrap();
+    /// let shrunk = grid.shrink_to_bounding_box(|c| *c == '.');
+    ///
+    /// assert_eq!(shrunk.to_string(), ".#\n##\n");
+    /// ```
+    pub fn shrink_to_bounding_box(&self, is_empty: impl Fn(&T) -> bool) -> Grid<T> {
+        let Some(bounds) =
+            BoundingBox2::from_points(self.points().filter(|p| !is_empty(&self[*p])))
+        else {
+            return Grid::default();
+        };
+
+        Grid {
+            cells: bounds.points().map(|p| self[p].clone()).collect(),
+            x_count: bounds.width() as usize,
+            y_count: bounds.height() as usize,
+            x_origin_offset: 0,
+            y_origin_offset: 0,
+        }
+    }
 }
 
 impl<T: PartialEq> Grid<T> {
@@ -341,6 +743,223 @@ impl<T: PartialEq> Grid<T> {
     }
 }
 
+impl<T> Grid<T> {
+    /// Label each connected region of cells in this grid with a region ID,
+    /// where IDs are assigned in order starting from zero. Neighboring cells
+    /// are connected when they are north, south, east or west of each other and
+    /// `same_region` returns true for their values.
+    ///
+    /// The returned grid has the same dimensions as this grid, and every cell
+    /// holds `Some` region ID.
+    ///
+    /// ```
+    /// use ube::spatial::{Grid, Point2};
+    ///
+    /// let grid: Grid<char> = "AAB\nBAB".parse().unwrap();
+    /// let regions = grid.label_connected_regions(|a, b| a == b);
+    ///
+    /// assert_eq!(regions[Point2::new(0, 0)], Some(0));
+    /// assert_eq!(regions[Point2::new(1, 1)], Some(0));
+    /// assert_eq!(regions[Point2::new(2, 1)], Some(1));
+    /// assert_eq!(regions[Point2::new(0, 1)], Some(2));
+    /// ```
+    pub fn label_connected_regions(
+        &self,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> Grid<Option<usize>> {
+        self.label_regions(&Connectivity::Four.offsets(), same_region)
+    }
+
+    /// Label each connected region of cells in this grid with a region ID like
+    /// `label_connected_regions`, except cells are also connected to the
+    /// diagonal neighbors.
+    pub fn label_connected_regions_with_diagonals(
+        &self,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> Grid<Option<usize>> {
+        self.label_regions(&Connectivity::Eight.offsets(), same_region)
+    }
+
+    /// Return the number of connected regions in this grid, where neighboring
+    /// cells are connected when they are neighbors under `connectivity` and
+    /// `same_region` returns true for their values.
+    ///
+    /// This only tracks which cells have been visited, so it is cheaper than
+    /// labelling the regions when only the count is needed.
+    ///
+    /// ```
+    /// use ube::spatial::{Connectivity, Grid};
+    ///
+    /// let grid: Grid<char> = "#.\n.#".parse().unwrap();
+    ///
+    /// assert_eq!(grid.region_count(Connectivity::Four, |a, b| a == b), 4);
+    /// assert_eq!(grid.region_count(Connectivity::Eight, |a, b| a == b), 2);
+    /// ```
+    pub fn region_count(
+        &self,
+        connectivity: Connectivity,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> usize {
+        let neighbors = connectivity.offsets();
+        let mut visited = Grid {
+            cells: vec![false; self.cells.len()],
+            x_count: self.x_count,
+            y_count: self.y_count,
+            x_origin_offset: self.x_origin_offset,
+            y_origin_offset: self.y_origin_offset,
+        };
+
+        let mut count = 0;
+        let mut stack = Vec::new();
+
+        for start in self.points() {
+            if visited[start] {
+                continue;
+            }
+
+            visited[start] = true;
+            stack.push(start);
+
+            while let Some(p) = stack.pop() {
+                for offset in &neighbors {
+                    let n = p + *offset;
+
+                    if self.is_pos_in_bounds(n) && !visited[n] && same_region(&self[p], &self[n]) {
+                        visited[n] = true;
+                        stack.push(n);
+                    }
+                }
+            }
+
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Flood fill each unlabelled cell with a new region ID, where cells are
+    /// connected to the cells at the `neighbors` offsets.
+    fn label_regions(
+        &self,
+        neighbors: &[Point2],
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> Grid<Option<usize>> {
+        let mut labels = Grid {
+            cells: vec![None; self.cells.len()],
+            x_count: self.x_count,
+            y_count: self.y_count,
+            x_origin_offset: self.x_origin_offset,
+            y_origin_offset: self.y_origin_offset,
+        };
+
+        let mut next_id = 0;
+        let mut queue = std::collections::VecDeque::new();
+
+        for start in self.points() {
+            if labels[start].is_some() {
+                continue;
+            }
+
+            labels[start] = Some(next_id);
+            queue.push_back(start);
+
+            while let Some(p) = queue.pop_front() {
+                for offset in neighbors {
+                    let n = p + *offset;
+
+                    if self.is_pos_in_bounds(n)
+                        && labels[n].is_none()
+                        && same_region(&self[p], &self[n])
+                    {
+                        labels[n] = Some(next_id);
+                        queue.push_back(n);
+                    }
+                }
+            }
+
+            next_id += 1;
+        }
+
+        labels
+    }
+}
+
+/// Which neighboring cells are considered connected to a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Cells are connected to their north, south, east and west neighbors.
+    Four,
+    /// Cells are connected to their north, south, east and west neighbors as
+    /// well as their diagonal neighbors.
+    Eight,
+}
+
+impl Connectivity {
+    /// Get the offsets from a cell to each of its connected neighbors.
+    fn offsets(self) -> Vec<Point2> {
+        match self {
+            Connectivity::Four => Direction4::all().map(Point2::from).collect(),
+            Connectivity::Eight => Direction8::all().map(Point2::from).collect(),
+        }
+    }
+}
+
+/// Label each connected region of equal cells in `grid` with a region ID,
+/// using a union find rather than the flood fill used by
+/// `Grid::label_connected_regions`. Neighboring cells are connected when they
+/// are north, south, east or west of each other and hold equal values.
+///
+/// Region IDs are assigned in order starting from zero, so the labels match
+/// `grid.label_connected_regions(|a, b| a == b)` cell for cell.
+///
+/// ```
+/// use ube::spatial::{label_grid_regions, Grid, Point2};
+///
+/// let grid: Grid<char> = "AAB\nBAB".parse().unwrap();
+/// let regions = label_grid_regions(&grid);
+///
+/// assert_eq!(regions[Point2::new(0, 0)], 0);
+/// assert_eq!(regions[Point2::new(1, 1)], 0);
+/// assert_eq!(regions[Point2::new(2, 1)], 1);
+/// assert_eq!(regions[Point2::new(0, 1)], 2);
+/// ```
+pub fn label_grid_regions<T: Eq>(grid: &Grid<T>) -> Grid<usize> {
+    let mut uf: UnionFind<usize> = (0..grid.cells.len()).collect();
+
+    // Cells are stored in row major order, so it is enough to connect each
+    // cell to its east and south neighbors.
+    for (i, cell) in grid.cells.iter().enumerate() {
+        let east = i + 1;
+        let south = i + grid.x_count;
+
+        if east % grid.x_count != 0 && grid.cells[east] == *cell {
+            uf.union(i, east);
+        }
+
+        if south < grid.cells.len() && grid.cells[south] == *cell {
+            uf.union(i, south);
+        }
+    }
+
+    // Renumber each component's representative in the order the component is
+    // first seen.
+    let mut ids = std::collections::HashMap::new();
+    let cells = (0..grid.cells.len())
+        .map(|i| {
+            let next_id = ids.len();
+            *ids.entry(uf.find(i)).or_insert(next_id)
+        })
+        .collect();
+
+    Grid {
+        cells,
+        x_count: grid.x_count,
+        y_count: grid.y_count,
+        x_origin_offset: grid.x_origin_offset,
+        y_origin_offset: grid.y_origin_offset,
+    }
+}
+
 impl<T> Default for Grid<T> {
     fn default() -> Self {
         Self {
@@ -353,6 +972,16 @@ impl<T> Default for Grid<T> {
     }
 }
 
+/// Hashes the grid's dimensions and every cell value in row major order, which
+/// allows grids to be used as `HashMap` keys when memoizing simulation states.
+impl<T: std::hash::Hash> std::hash::Hash for Grid<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.x_count.hash(state);
+        self.y_count.hash(state);
+        self.cells.hash(state);
+    }
+}
+
 impl<T: std::fmt::Display> std::fmt::Display for Grid<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for y in 0..(self.y_count as isize) {
@@ -412,6 +1041,28 @@ impl<T> std::ops::IndexMut<Point2> for Grid<T> {
     }
 }
 
+/// Index a grid with an `(x, y)` tuple, which is the same as indexing with
+/// `Point2::new(x, y)`.
+///
+/// Only `isize` tuples are supported. Adding an index for `usize` tuples would
+/// stop integer literals from being inferred, so `grid[(2, 3)]` would no longer
+/// compile. Use `Grid::try_index` for `usize` positions instead.
+impl<T> std::ops::Index<(isize, isize)> for Grid<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, (x, y): (isize, isize)) -> &Self::Output {
+        &self[Point2::new(x, y)]
+    }
+}
+
+impl<T> std::ops::IndexMut<(isize, isize)> for Grid<T> {
+    #[inline(always)]
+    fn index_mut(&mut self, (x, y): (isize, isize)) -> &mut Self::Output {
+        &mut self[Point2::new(x, y)]
+    }
+}
+
 /// Represents scenarios where an iterator is used to construct a Grid and
 /// does not match the expected length, width or height.
 ///
@@ -462,47 +1113,67 @@ impl FromStr for Grid<char> {
 /// produce all the points a grid row before moving to the next row. Each row
 /// is iterated left to right, and the rows are iterated top to bottom.
 pub struct Points {
-    next_x: isize,
-    next_y: isize,
     start_x: isize,
-    end_x: isize,
-    end_y: isize,
+    start_y: isize,
+    x_count: usize,
+    /// Row major index of the next point returned from the front.
+    next: usize,
+    /// Row major index one past the next point returned from the back.
+    end: usize,
+}
+
+impl Points {
+    fn new(topleft: Point2, x_count: usize, y_count: usize) -> Self {
+        Self {
+            start_x: topleft.x,
+            start_y: topleft.y,
+            x_count,
+            next: 0,
+            end: x_count * y_count,
+        }
+    }
+
+    fn point_at(&self, index: usize) -> Point2 {
+        Point2::new(
+            self.start_x + (index % self.x_count) as isize,
+            self.start_y + (index / self.x_count) as isize,
+        )
+    }
 }
 
 impl Iterator for Points {
     type Item = Point2;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.next_y >= self.end_y {
+        if self.next >= self.end {
             None
         } else {
-            let x = self.next_x;
-            let y = self.next_y;
-
-            self.next_x += 1;
-
-            if self.next_x >= self.end_x {
-                self.next_x = self.start_x;
-                self.next_y += 1;
-            }
+            let p = self.point_at(self.next);
+            self.next += 1;
 
-            Some(Point2::new(x, y))
+            Some(p)
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if self.next_y >= self.end_y {
-            (0, Some(0))
+        let len = self.end.saturating_sub(self.next);
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Points {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            None
         } else {
-            let len: usize = ((self.end_x - self.next_x)
-                + (self.end_x - self.start_x) * (self.end_y - self.next_y - 1))
-                .try_into()
-                .unwrap();
-            (len, Some(len))
+            self.end -= 1;
+            Some(self.point_at(self.end))
         }
     }
 }
 
+impl ExactSizeIterator for Points {}
+
 impl FusedIterator for Points {}
 
 /// A reference to a specific cell contained in a grid along with the (x, y)
@@ -538,6 +1209,17 @@ impl<'a, T: Clone> Iterator for Cells<'a, T> {
     }
 }
 
+impl<T: Clone> DoubleEndedIterator for Cells<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.points.next_back().map(|p| CellRef {
+            index: p,
+            value: &self.grid[p],
+        })
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for Cells<'_, T> {}
+
 impl<T: Clone> FusedIterator for Cells<'_, T> {}
 
 /// An iterator over the rows in a grid.
@@ -587,6 +1269,24 @@ impl Iterator for Rows {
     }
 }
 
+impl DoubleEndedIterator for Rows {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next_y >= self.end_y {
+            None
+        } else {
+            self.end_y -= 1;
+
+            Some(Row {
+                y: self.end_y,
+                next_x: self.start_x,
+                end_x: self.end_x,
+            })
+        }
+    }
+}
+
+impl ExactSizeIterator for Rows {}
+
 impl FusedIterator for Rows {}
 
 /// An iterator over the points in a grid row.
@@ -628,6 +1328,19 @@ impl Iterator for Row {
     }
 }
 
+impl DoubleEndedIterator for Row {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next_x >= self.end_x {
+            None
+        } else {
+            self.end_x -= 1;
+            Some(Point2::new(self.end_x, self.y))
+        }
+    }
+}
+
+impl ExactSizeIterator for Row {}
+
 impl FusedIterator for Row {}
 
 /// An iterator over the cols in a grid.
@@ -677,6 +1390,24 @@ impl Iterator for Cols {
     }
 }
 
+impl DoubleEndedIterator for Cols {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next_x >= self.end_x {
+            None
+        } else {
+            self.end_x -= 1;
+
+            Some(Col {
+                x: self.end_x,
+                next_y: self.start_y,
+                end_y: self.end_y,
+            })
+        }
+    }
+}
+
+impl ExactSizeIterator for Cols {}
+
 impl FusedIterator for Cols {}
 
 /// An iterator over the points in a grid column.
@@ -718,4 +1449,728 @@ impl Iterator for Col {
     }
 }
 
+impl DoubleEndedIterator for Col {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next_y >= self.end_y {
+            None
+        } else {
+            self.end_y -= 1;
+            Some(Point2::new(self.x, self.end_y))
+        }
+    }
+}
+
+impl ExactSizeIterator for Col {}
+
 impl FusedIterator for Col {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkerboard_cells_are_separate_regions() {
+        let grid: Grid<char> = "#.#.\n.#.#\n#.#.".parse().unwrap();
+        let regions = grid.label_connected_regions(|a, b| a == b);
+
+        assert_eq!(
+            regions.cells,
+            (0..12).map(Some).collect::<Vec<Option<usize>>>()
+        );
+        assert_eq!(grid.region_count(Connectivity::Four, |a, b| a == b), 12);
+    }
+
+    #[test]
+    fn solid_grid_is_one_region() {
+        let grid: Grid<char> = "aaa\naaa".parse().unwrap();
+        let regions = grid.label_connected_regions(|a, b| a == b);
+
+        assert!(regions.cells.iter().all(|id| *id == Some(0)));
+        assert_eq!(grid.region_count(Connectivity::Four, |a, b| a == b), 1);
+    }
+
+    #[test]
+    fn regions_follow_same_region_fn() {
+        let grid: Grid<u32> =
+            Grid::parse_str("1290\n3489\n0011", |c| c.to_digit(10).unwrap()).unwrap();
+
+        // Small digits and large digits form separate regions.
+        let regions = grid.label_connected_regions(|a, b| (*a < 5) == (*b < 5));
+
+        assert_eq!(
+            regions.cells,
+            vec![
+                Some(0),
+                Some(0),
+                Some(1),
+                Some(2),
+                Some(0),
+                Some(0),
+                Some(1),
+                Some(1),
+                Some(0),
+                Some(0),
+                Some(0),
+                Some(0)
+            ]
+        );
+        assert_eq!(
+            grid.region_count(Connectivity::Four, |a, b| (*a < 5) == (*b < 5)),
+            3
+        );
+    }
+
+    #[test]
+    fn diagonal_regions() {
+        let grid: Grid<char> = "#.#\n.#.\n#.#".parse().unwrap();
+
+        assert_eq!(grid.region_count(Connectivity::Four, |a, b| a == b), 9);
+        assert_eq!(
+            grid.label_connected_regions_with_diagonals(|a, b| a == b)
+                .cells,
+            vec![
+                Some(0),
+                Some(1),
+                Some(0),
+                Some(1),
+                Some(0),
+                Some(1),
+                Some(0),
+                Some(1),
+                Some(0)
+            ]
+        );
+    }
+
+    fn assert_union_find_labels_match_flood_fill<T: Eq>(grid: &Grid<T>) {
+        let expected: Vec<usize> = grid
+            .label_connected_regions(|a, b| a == b)
+            .cells
+            .into_iter()
+            .flatten()
+            .collect();
+
+        assert_eq!(label_grid_regions(grid).cells, expected);
+    }
+
+    #[test]
+    fn label_grid_regions_matches_flood_fill() {
+        assert_union_find_labels_match_flood_fill(&Grid::<char>::default());
+        assert_union_find_labels_match_flood_fill(&"#.#.\n.#.#\n#.#.".parse().unwrap());
+        assert_union_find_labels_match_flood_fill(&"aaa\naaa".parse().unwrap());
+        assert_union_find_labels_match_flood_fill(&"abc".parse().unwrap());
+        assert_union_find_labels_match_flood_fill(&"a\nb\nb".parse().unwrap());
+        assert_union_find_labels_match_flood_fill(
+            &"RRRRIICCFF\nRRRRIICCCF\nVVRRRCCFFF\nVVRCCCJFFF\nVVVVCJJCFE\nVVIVCCJJEE\nVVIIICJJEE\nMIIIIIJJEE\nMIIISIJEEE\nMMMISSJEEE"
+                .parse()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn label_grid_regions_joins_u_shaped_region() {
+        // The two arms of the U are only joined by the bottom row.
+        let grid: Grid<char> = "a.a\na.a\naaa".parse().unwrap();
+        let regions = label_grid_regions(&grid);
+
+        assert_eq!(regions.cells, vec![0, 1, 0, 0, 1, 0, 0, 0, 0]);
+    }
+
+    fn digit_color(d: &u32) -> [u8; 3] {
+        match d {
+            1 => [255, 0, 0],
+            2 => [0, 255, 0],
+            3 => [0, 0, 255],
+            _ => [10, 20, 30],
+        }
+    }
+
+    #[test]
+    fn to_ppm_writes_header_and_pixels() {
+        let grid: Grid<u32> = Grid::parse_str("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        let image = grid.to_ppm(digit_color);
+
+        let header = b"P6\n2 2\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        assert_eq!(
+            &image[header.len()..],
+            &[255, 0, 0, 0, 255, 0, 0, 0, 255, 10, 20, 30]
+        );
+    }
+
+    #[test]
+    fn to_ppm_header_uses_width_then_height() {
+        let grid: Grid<u32> = Grid::parse_str("123", |c| c.to_digit(10).unwrap()).unwrap();
+        let image = grid.to_ppm(digit_color);
+
+        assert!(image.starts_with(b"P6\n3 1\n255\n"));
+        assert_eq!(image.len(), b"P6\n3 1\n255\n".len() + 9);
+    }
+
+    #[test]
+    fn save_ppm_writes_image_file() {
+        let grid: Grid<u32> = Grid::parse_str("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        let path = std::env::temp_dir().join(format!("ube-grid-{}.ppm", std::process::id()));
+
+        grid.save_ppm(&path, digit_color).unwrap();
+        let written = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(written, grid.to_ppm(digit_color));
+    }
+
+    #[test]
+    fn to_pgm_writes_header_and_pixels() {
+        let grid: Grid<u32> = Grid::parse_str("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        let image = grid.to_pgm(|d| *d as u8 * 10);
+
+        let header = b"P5\n2 2\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        assert_eq!(&image[header.len()..], &[10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn save_pgm_writes_image_file() {
+        let grid: Grid<u32> = Grid::parse_str("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        let path = std::env::temp_dir().join(format!("ube-grid-{}.pgm", std::process::id()));
+
+        grid.save_pgm(&path, |d| *d as u8).unwrap();
+        let written = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(written, grid.to_pgm(|d| *d as u8));
+    }
+
+    #[test]
+    fn format_with_passes_cell_positions() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+
+        assert_eq!(
+            grid.format_with(|p, c| format!("{c}{}{} ", p.x, p.y)),
+            "a00 b10 c20 \nd01 e11 f21 \n"
+        );
+    }
+
+    #[test]
+    fn format_with_multi_character_cells() {
+        let grid: Grid<u32> = Grid::with_values(2, 3, [1, 22, 333, 4, 55, 6]).unwrap();
+
+        assert_eq!(
+            grid.format_with(|_, v| format!("[{v:>3}]")),
+            "[  1][ 22]\n[333][  4]\n[ 55][  6]\n"
+        );
+        assert_eq!(Grid::<u32>::default().format_with(|_, v| v.to_string()), "");
+    }
+
+    #[test]
+    fn windows_count() {
+        let grid: Grid<u32> = Grid::new(5, 4);
+
+        for (win_x, win_y) in [(1, 1), (2, 3), (5, 4), (3, 1)] {
+            assert_eq!(
+                grid.windows(win_x, win_y).count(),
+                (5 - win_x + 1) * (4 - win_y + 1)
+            );
+        }
+
+        assert_eq!(grid.windows(6, 1).count(), 0);
+        assert_eq!(grid.windows(1, 5).count(), 0);
+    }
+
+    #[test]
+    fn windows_contents() {
+        let grid: Grid<char> = "abcd\nefgh\nijkl".parse().unwrap();
+        let windows = grid
+            .windows(3, 2)
+            .map(|(p, view)| {
+                let text = (0..view.y_count() as isize)
+                    .flat_map(|y| (0..view.x_count() as isize).map(move |x| (x, y)))
+                    .map(|(x, y)| *view.get(x, y))
+                    .collect::<String>();
+
+                (p, text)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            windows,
+            vec![
+                (Point2::new(0, 0), "abcefg".to_string()),
+                (Point2::new(1, 0), "bcdfgh".to_string()),
+                (Point2::new(0, 1), "efgijk".to_string()),
+                (Point2::new(1, 1), "fghjkl".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "position (2, 0) is outside of the 2x2 view")]
+    fn windows_get_outside_view_x_panics() {
+        let grid: Grid<char> = "abcd\nefgh\nijkl".parse().unwrap();
+        let (_, view) = grid.windows(2, 2).next().unwrap();
+
+        view.get(2, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "position (0, -1) is outside of the 2x2 view")]
+    fn windows_get_outside_view_y_panics() {
+        let grid: Grid<char> = "abcd\nefgh\nijkl".parse().unwrap();
+        let (_, view) = grid.windows(2, 2).last().unwrap();
+
+        view.get(0, -1);
+    }
+
+    /// Calculate the next state of a cell in Conway's Game of Life.
+    fn game_of_life_step(p: Point2, alive: &bool, grid: &Grid<bool>) -> bool {
+        let live_neighbors = Direction8::all()
+            .map(|d| p + d)
+            .filter(|n| grid.is_pos_in_bounds(*n) && grid[*n])
+            .count();
+
+        matches!((alive, live_neighbors), (true, 2) | (_, 3))
+    }
+
+    #[test]
+    fn apply_in_place_game_of_life_step() {
+        let mut grid: Grid<bool> =
+            Grid::parse_str(".....\n..#..\n...#.\n.###.\n.....", |c| c == '#').unwrap();
+
+        grid.apply_in_place(game_of_life_step);
+
+        let expected: Grid<bool> =
+            Grid::parse_str(".....\n.....\n.#.#.\n..##.\n..#..", |c| c == '#').unwrap();
+
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn apply_in_place_blinker_oscillates() {
+        let horizontal: Grid<bool> = Grid::parse_str("...\n###\n...", |c| c == '#').unwrap();
+        let vertical: Grid<bool> = Grid::parse_str(".#.\n.#.\n.#.", |c| c == '#').unwrap();
+
+        let mut grid = horizontal.clone();
+
+        grid.apply_in_place(game_of_life_step);
+        assert_eq!(grid, vertical);
+
+        grid.apply_in_place(game_of_life_step);
+        assert_eq!(grid, horizontal);
+    }
+
+    #[test]
+    fn paste_clipped_partially_outside() {
+        let mut grid: Grid<u32> = Grid::new(5, 5);
+        let other: Grid<u32> = Grid::with_values(3, 3, 1..=9).unwrap();
+
+        grid.paste_clipped(&other, Point2::new(-1, -1));
+
+        let mut expected: Grid<u32> = Grid::new(5, 5);
+        expected.set(0, 0, 5);
+        expected.set(1, 0, 6);
+        expected.set(0, 1, 8);
+        expected.set(1, 1, 9);
+
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn paste_clipped_inside_and_fully_outside() {
+        let mut grid: Grid<char> = "....\n....\n....".parse().unwrap();
+        let other: Grid<char> = "ab\ncd".parse().unwrap();
+
+        grid.paste_clipped(&other, Point2::new(1, 1));
+        assert_eq!(grid.to_string(), "....\n.ab.\n.cd.\n");
+
+        grid.paste_clipped(&other, Point2::new(10, -5));
+        assert_eq!(grid.to_string(), "....\n.ab.\n.cd.\n");
+    }
+
+    #[test]
+    fn shrink_removes_empty_border_rows_and_cols() {
+        let grid: Grid<char> = "......\n......\n..#...\n...##.\n......".parse().unwrap();
+        let shrunk = grid.shrink_to_bounding_box(|c| *c == '.');
+
+        assert_eq!(shrunk.x_count(), 3);
+        assert_eq!(shrunk.y_count(), 2);
+        assert_eq!(shrunk.top_left(), Point2::zero());
+        assert_eq!(shrunk.to_string(), "#..\n.##\n");
+    }
+
+    #[test]
+    fn shrink_to_single_corner_cell() {
+        let grid: Grid<u8> = Grid::with_values(3, 3, [0, 0, 0, 0, 0, 0, 0, 0, 7]).unwrap();
+        let shrunk = grid.shrink_to_bounding_box(|v| *v == 0);
+
+        assert_eq!(shrunk, Grid::with_values(1, 1, [7]).unwrap());
+    }
+
+    #[test]
+    fn shrink_keeps_full_grid_without_empty_border() {
+        let grid: Grid<char> = "#.#\n...\n#..".parse().unwrap();
+        assert_eq!(grid.shrink_to_bounding_box(|c| *c == '.'), grid);
+    }
+
+    #[test]
+    fn shrink_all_empty_grid() {
+        let grid: Grid<char> = "...\n...".parse().unwrap();
+        let shrunk = grid.shrink_to_bounding_box(|c| *c == '.');
+
+        assert_eq!(shrunk.x_count(), 0);
+        assert_eq!(shrunk.y_count(), 0);
+        assert_eq!(shrunk.iter().count(), 0);
+    }
+
+    #[test]
+    fn equal_grids_have_equal_hashes() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let a: Grid<char> = "ab\ncd".parse().unwrap();
+        let b: Grid<char> = "ab\ncd".parse().unwrap();
+        let hasher = RandomState::new();
+
+        assert_eq!(a, b);
+        assert_eq!(hasher.hash_one(&a), hasher.hash_one(&b));
+    }
+
+    #[test]
+    fn grid_as_hash_map_key() {
+        let original: Grid<char> = "#.\n.#".parse().unwrap();
+        let mut seen = std::collections::HashMap::new();
+        seen.insert(original.clone(), 7);
+
+        let mut changed = original.clone();
+        changed[(1, 0)] = '#';
+
+        assert_eq!(seen.get(&original), Some(&7));
+        assert_eq!(seen.get(&changed), None);
+
+        // Same cells in a different shape are a different key.
+        let reshaped = Grid::with_values(4, 1, "#..#".chars()).unwrap();
+        assert_eq!(seen.get(&reshaped), None);
+    }
+
+    #[test]
+    fn row_values_match_indexed_row() {
+        let grid: Grid<u32> = Grid::parse_str("123\n456", |c| c.to_digit(10).unwrap()).unwrap();
+
+        for y in 0..2 {
+            let expected: Vec<&u32> = grid.row(y).unwrap().map(|p| &grid[p]).collect();
+            assert_eq!(grid.row_values(y).unwrap().collect::<Vec<_>>(), expected);
+        }
+
+        assert_eq!(
+            grid.row_values(0).unwrap().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(grid.row_values(-1).is_none());
+        assert!(grid.row_values(2).is_none());
+    }
+
+    #[test]
+    fn col_values_match_indexed_col() {
+        let grid: Grid<u32> = Grid::parse_str("123\n456", |c| c.to_digit(10).unwrap()).unwrap();
+
+        for x in 0..3 {
+            let expected: Vec<&u32> = (0..2).map(|y| grid.get(x, y)).collect();
+            assert_eq!(grid.col_values(x).unwrap().collect::<Vec<_>>(), expected);
+        }
+
+        assert_eq!(
+            grid.col_values(2).unwrap().copied().collect::<Vec<_>>(),
+            vec![3, 6]
+        );
+        assert!(grid.col_values(-1).is_none());
+        assert!(grid.col_values(3).is_none());
+        assert!(Grid::<u32>::default().col_values(0).is_none());
+    }
+
+    #[test]
+    fn wrap_get_wraps_negative_coordinates() {
+        let grid = Grid::with_values(5, 3, 0..15).unwrap();
+
+        assert_eq!(grid.wrap_get(-1, 0), grid.get(4, 0));
+        assert_eq!(grid.wrap_get(-5, 0), grid.get(0, 0));
+        assert_eq!(grid.wrap_get(-6, 2), grid.get(4, 2));
+        assert_eq!(grid.wrap_get(0, -1), grid.get(0, 2));
+        assert_eq!(grid.wrap_get(2, -4), grid.get(2, 2));
+        assert_eq!(grid.wrap_get(-1, -1), grid.get(4, 2));
+    }
+
+    #[test]
+    fn wrap_get_wraps_large_coordinates() {
+        let grid = Grid::with_values(5, 3, 0..15).unwrap();
+
+        assert_eq!(grid.wrap_get(5, 0), grid.get(0, 0));
+        assert_eq!(grid.wrap_get(13, 7), grid.get(3, 1));
+        assert_eq!(grid.wrap_get(3, 1), grid.get(3, 1));
+    }
+
+    #[test]
+    fn wrap_set_wraps_coordinates() {
+        let mut grid: Grid<char> = Grid::new(5, 3);
+
+        grid.wrap_set(-1, 0, 'a');
+        grid.wrap_set(7, -1, 'b');
+
+        assert_eq!(grid.get(4, 0), &'a');
+        assert_eq!(grid.get(2, 2), &'b');
+    }
+
+    #[test]
+    fn diagonal_neighbors_in_bounds() {
+        let grid: Grid<char> = "abc\ndef\nghi".parse().unwrap();
+        let neighbors = |x, y| {
+            grid.diagonal_neighbors_4(Point2::new(x, y))
+                .map(|(_, c)| *c)
+                .collect::<String>()
+        };
+
+        // Corner cells.
+        assert_eq!(neighbors(0, 0), "e");
+        assert_eq!(neighbors(2, 2), "e");
+
+        // Edge cells.
+        assert_eq!(neighbors(1, 0), "df");
+        assert_eq!(neighbors(0, 1), "bh");
+
+        // Interior cell.
+        assert_eq!(neighbors(1, 1), "cagi");
+        assert_eq!(
+            grid.diagonal_neighbors_4(Point2::new(1, 1))
+                .map(|(p, _)| p)
+                .collect::<Vec<_>>(),
+            diagonal_neighbors(Point2::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn empty_grid_has_no_regions() {
+        let grid: Grid<char> = Grid::default();
+        assert_eq!(grid.region_count(Connectivity::Four, |a, b| a == b), 0);
+        assert_eq!(grid.region_count(Connectivity::Eight, |a, b| a == b), 0);
+    }
+
+    #[test]
+    fn region_count_solid_grid() {
+        let grid: Grid<char> = "aaaa\naaaa\naaaa".parse().unwrap();
+
+        assert_eq!(grid.region_count(Connectivity::Four, |a, b| a == b), 1);
+        assert_eq!(grid.region_count(Connectivity::Eight, |a, b| a == b), 1);
+    }
+
+    #[test]
+    fn region_count_checkerboard() {
+        let grid: Grid<char> = "#.#.\n.#.#\n#.#.".parse().unwrap();
+
+        // Matching cells only touch diagonally, so each color forms a single
+        // region when diagonal neighbors are connected.
+        assert_eq!(grid.region_count(Connectivity::Four, |a, b| a == b), 12);
+        assert_eq!(grid.region_count(Connectivity::Eight, |a, b| a == b), 2);
+    }
+
+    #[test]
+    fn region_count_three_blobs() {
+        let grid: Grid<char> = "##..\n##.#\n...#\n#...".parse().unwrap();
+
+        // Three blobs of `#` surrounded by one connected region of `.`.
+        assert_eq!(grid.region_count(Connectivity::Four, |a, b| a == b), 4);
+        assert_eq!(grid.region_count(Connectivity::Eight, |a, b| a == b), 4);
+        assert_eq!(
+            grid.region_count(Connectivity::Four, |a, b| a == b),
+            grid.label_connected_regions(|a, b| a == b)
+                .cells
+                .iter()
+                .flatten()
+                .max()
+                .unwrap()
+                + 1
+        );
+    }
+
+    #[test]
+    fn iterators_report_exact_len() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+
+        let mut points = grid.points();
+        assert_eq!(points.len(), 6);
+        points.next();
+        points.next_back();
+        assert_eq!(points.len(), 4);
+        assert_eq!(points.count(), 4);
+
+        assert_eq!(grid.iter().len(), grid.iter().count());
+        assert_eq!(grid.rows().len(), 2);
+        assert_eq!(Cols::new(Point2::zero(), 3, 2).len(), 3);
+        assert_eq!(grid.rows().next().unwrap().len(), 3);
+        assert_eq!(Cols::new(Point2::zero(), 3, 2).next().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn iterators_reverse_in_opposite_order() {
+        fn assert_reversible<I: DoubleEndedIterator + Clone>(itr: I)
+        where
+            I::Item: PartialEq + std::fmt::Debug,
+        {
+            let mut forward: Vec<_> = itr.clone().collect();
+            forward.reverse();
+            assert_eq!(itr.rev().collect::<Vec<_>>(), forward);
+        }
+
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+
+        assert_reversible(grid.rows());
+        assert_reversible(Cols::new(Point2::zero(), 3, 2));
+        assert_reversible(grid.rows().next().unwrap());
+        assert_reversible(Cols::new(Point2::zero(), 3, 2).next().unwrap());
+
+        let cells: String = grid.iter().rev().map(|c| *c.value).collect();
+        assert_eq!(cells, "fedcba");
+        assert_eq!(grid.points().next_back(), Some(Point2::new(2, 1)));
+        assert_eq!(
+            grid.points().rev().collect::<Vec<_>>(),
+            grid.points()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn points_meet_in_the_middle() {
+        let grid: Grid<char> = "ab\ncd".parse().unwrap();
+        let mut points = grid.points();
+
+        assert_eq!(points.next(), Some(Point2::new(0, 0)));
+        assert_eq!(points.next_back(), Some(Point2::new(1, 1)));
+        assert_eq!(points.next_back(), Some(Point2::new(0, 1)));
+        assert_eq!(points.next(), Some(Point2::new(1, 0)));
+        assert_eq!(points.next(), None);
+        assert_eq!(points.next_back(), None);
+    }
+
+    #[test]
+    fn index_with_tuple() {
+        let mut grid: Grid<char> = "abc\ndef\nghi\njkl".parse().unwrap();
+
+        assert_eq!(grid[(2, 3)], grid[Point2::new(2, 3)]);
+        assert_eq!(grid[(0, 1)], 'd');
+
+        grid[(1, 2)] = '*';
+        assert_eq!(grid[Point2::new(1, 2)], '*');
+    }
+
+    #[test]
+    fn try_index_with_usize_tuple() {
+        let mut grid: Grid<char> = "abc\ndef\nghi\njkl".parse().unwrap();
+
+        assert_eq!(grid.try_index((2, 3)), Some(&grid[Point2::new(2, 3)]));
+        assert_eq!(grid.try_index((0, 1)), Some(&'d'));
+        assert_eq!(grid.try_index((3, 0)), None);
+        assert_eq!(grid.try_index((0, 4)), None);
+        assert_eq!(grid.try_index((isize::MAX as usize + 1, 0)), None);
+        assert_eq!(grid.try_index((0, usize::MAX)), None);
+
+        *grid.try_index_mut((1, 2)).unwrap() = '*';
+        assert_eq!(grid[Point2::new(1, 2)], '*');
+        assert!(grid.try_index_mut((usize::MAX, 0)).is_none());
+    }
+
+    #[test]
+    fn parse_str_maps_digits() {
+        let grid: Grid<u8> = Grid::parse_str("012\n345", |c| c as u8 - b'0').unwrap();
+
+        assert_eq!(grid.x_count(), 3);
+        assert_eq!(grid.y_count(), 2);
+        assert_eq!(grid[(0, 0)], 0);
+        assert_eq!(grid[(2, 1)], 5);
+    }
+
+    #[test]
+    fn parse_str_maps_tiles() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        enum Tile {
+            Wall,
+            Floor,
+        }
+
+        impl std::fmt::Display for Tile {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    Tile::Wall => write!(f, "#"),
+                    Tile::Floor => write!(f, "."),
+                }
+            }
+        }
+
+        let input = "#.#\n...\n";
+        let grid = Grid::parse_str(input, |c| match c {
+            '#' => Tile::Wall,
+            _ => Tile::Floor,
+        })
+        .unwrap();
+
+        assert_eq!(grid[(0, 0)], Tile::Wall);
+        assert_eq!(grid[(1, 0)], Tile::Floor);
+        assert_eq!(grid.to_string(), input);
+    }
+
+    #[test]
+    fn from_str_with_parses_digits_into_u8() {
+        let grid: Grid<u8> =
+            Grid::from_str_with("907\n135", |c| c.to_digit(10).unwrap() as u8).unwrap();
+
+        assert_eq!(grid.x_count(), 3);
+        assert_eq!(grid.y_count(), 2);
+        assert_eq!(grid[(0, 0)], 9);
+        assert_eq!(grid[(1, 0)], 0);
+        assert_eq!(grid[(2, 1)], 5);
+    }
+
+    #[test]
+    fn from_str_with_parses_chars_into_enum() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        enum Tile {
+            Wall,
+            Floor,
+            Start,
+        }
+
+        impl std::fmt::Display for Tile {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    Tile::Wall => write!(f, "#"),
+                    Tile::Floor => write!(f, "."),
+                    Tile::Start => write!(f, "S"),
+                }
+            }
+        }
+
+        let input = "#S#\n#.#\n";
+        let grid = Grid::from_str_with(input, |c| match c {
+            '#' => Tile::Wall,
+            'S' => Tile::Start,
+            _ => Tile::Floor,
+        })
+        .unwrap();
+
+        assert_eq!(grid[(1, 0)], Tile::Start);
+        assert_eq!(grid[(1, 1)], Tile::Floor);
+        assert_eq!(grid[(2, 1)], Tile::Wall);
+        assert_eq!(grid.to_string(), input);
+    }
+
+    #[test]
+    fn from_str_with_rejects_uneven_rows() {
+        assert!(Grid::from_str_with("12\n3", |c| c).is_err());
+    }
+
+    #[test]
+    fn parse_str_counts_characters_not_bytes() {
+        let grid = Grid::parse_str("→←\n↑↓", |c| c).unwrap();
+
+        assert_eq!(grid.x_count(), 2);
+        assert_eq!(grid[(1, 1)], '↓');
+    }
+}
@@ -0,0 +1,361 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+};
+
+use super::{Direction4, Grid, Point2};
+
+/// A point waiting to be visited by `astar`, ordered so that `BinaryHeap` (a
+/// max heap) pops the point with the lowest estimated total cost first.
+struct OpenPoint<C> {
+    /// The cost to reach `point` plus the estimated cost from `point` to the
+    /// goal.
+    estimate: C,
+    point: Point2,
+}
+
+impl<C: Ord> Ord for OpenPoint<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| other.point.cmp(&self.point))
+    }
+}
+
+impl<C: Ord> PartialOrd for OpenPoint<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for OpenPoint<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for OpenPoint<C> {}
+
+/// Walk `predecessors` backwards from `goal` to build the path from the start
+/// point (the only point without a predecessor) to `goal`.
+fn reconstruct_path(predecessors: &HashMap<Point2, Point2>, goal: Point2) -> Vec<Point2> {
+    let mut path = vec![goal];
+
+    while let Some(prev) = predecessors.get(path.last().unwrap()) {
+        path.push(*prev);
+    }
+
+    path.reverse();
+    path
+}
+
+/// Find the lowest cost path from `start` to `goal` using A* search, moving
+/// one cell north, south, east or west at a time.
+///
+/// `cost_fn(from, to)` returns the cost of moving from `from` to the adjacent
+/// cell `to`. Every cell in the grid can be entered, so give cells that should
+/// be avoided (eg walls) a cost larger than any path around them.
+/// `heuristic_fn(p, goal)` estimates the remaining cost from `p` to `goal` and
+/// must never overestimate it, otherwise the returned path may not be the
+/// cheapest one.
+///
+/// Returns the total cost of the path and the points along it, including
+/// `start` and `goal`, or `None` if `start` or `goal` is outside the grid.
+///
+/// ```
+/// use ube::spatial::{astar, Grid, Point2};
+///
+/// let grid: Grid<u32> = Grid::parse_str("131\n119\n991", |c| c.to_digit(10).unwrap()).unwrap();
+/// let (cost, path) = astar(
+///     &grid,
+///     Point2::new(0, 0),
+///     Point2::new(2, 2),
+///     |_, to| grid[to],
+///     |_, _| 0,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(cost, 12);
+/// assert_eq!(path.len(), 5);
+/// ```
+pub fn astar<T, C>(
+    grid: &Grid<T>,
+    start: Point2,
+    goal: Point2,
+    cost_fn: impl Fn(Point2, Point2) -> C,
+    heuristic_fn: impl Fn(Point2, Point2) -> C,
+) -> Option<(C, Vec<Point2>)>
+where
+    C: Ord + Copy + Default + std::ops::Add<Output = C>,
+{
+    if !grid.is_pos_in_bounds(start) || !grid.is_pos_in_bounds(goal) {
+        return None;
+    }
+
+    let mut costs: HashMap<Point2, C> = HashMap::from([(start, C::default())]);
+    let mut predecessors: HashMap<Point2, Point2> = HashMap::new();
+    let mut open = BinaryHeap::from([OpenPoint {
+        estimate: heuristic_fn(start, goal),
+        point: start,
+    }]);
+
+    while let Some(OpenPoint { estimate, point }) = open.pop() {
+        let cost = costs[&point];
+
+        if point == goal {
+            return Some((cost, reconstruct_path(&predecessors, goal)));
+        }
+
+        // Skip stale heap entries for points that were later reached with a
+        // lower cost.
+        if estimate > cost + heuristic_fn(point, goal) {
+            continue;
+        }
+
+        for dir in Direction4::all() {
+            let next = point + dir;
+
+            if !grid.is_pos_in_bounds(next) {
+                continue;
+            }
+
+            let next_cost = cost + cost_fn(point, next);
+
+            if costs.get(&next).is_none_or(|c| next_cost < *c) {
+                costs.insert(next, next_cost);
+                predecessors.insert(next, point);
+                open.push(OpenPoint {
+                    estimate: next_cost + heuristic_fn(next, goal),
+                    point: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Find the shortest path from `start` to `goal` using breadth first search,
+/// moving one cell north, south, east or west at a time. Only cells where
+/// `passable(point, value)` returns true can be entered.
+///
+/// Returns the points along the path, including `start` and `goal`, or `None`
+/// if `goal` cannot be reached.
+///
+/// ```
+/// use ube::spatial::{bfs_path, Grid, Point2};
+///
+/// let grid: Grid<char> = ".#.\n...".parse().unwrap();
+/// let path = bfs_path(&grid, Point2::new(0, 0), Point2::new(2, 0), |_, c| *c != '#');
+///
+/// assert_eq!(path.map(|p| p.len()), Some(5));
+/// ```
+pub fn bfs_path<T>(
+    grid: &Grid<T>,
+    start: Point2,
+    goal: Point2,
+    passable: impl Fn(Point2, &T) -> bool,
+) -> Option<Vec<Point2>> {
+    if !grid.is_pos_in_bounds(start) || !grid.is_pos_in_bounds(goal) {
+        return None;
+    }
+
+    let mut visited: HashSet<Point2> = HashSet::from([start]);
+    let mut predecessors: HashMap<Point2, Point2> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(point) = queue.pop_front() {
+        if point == goal {
+            return Some(reconstruct_path(&predecessors, goal));
+        }
+
+        for dir in Direction4::all() {
+            let next = point + dir;
+
+            if grid.is_pos_in_bounds(next) && passable(next, &grid[next]) && visited.insert(next) {
+                predecessors.insert(next, point);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manhattan(a: Point2, b: Point2) -> usize {
+        let d = (a - b).abs();
+        (d.x + d.y) as usize
+    }
+
+    /// Moving into a wall costs more than walking around any of the test grids.
+    fn walls(grid: &Grid<char>) -> impl Fn(Point2, Point2) -> usize + '_ {
+        |_, to| if grid[to] == '#' { 1000 } else { 1 }
+    }
+
+    #[test]
+    fn astar_straight_line() {
+        let grid: Grid<char> = "....\n....".parse().unwrap();
+        let (cost, path) = astar(
+            &grid,
+            Point2::new(0, 0),
+            Point2::new(3, 0),
+            walls(&grid),
+            manhattan,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 3);
+        assert_eq!(
+            path,
+            vec![
+                Point2::new(0, 0),
+                Point2::new(1, 0),
+                Point2::new(2, 0),
+                Point2::new(3, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn astar_avoids_obstacle() {
+        let grid: Grid<char> = ".#.\n.#.\n...".parse().unwrap();
+        let (cost, path) = astar(
+            &grid,
+            Point2::new(0, 0),
+            Point2::new(2, 0),
+            walls(&grid),
+            manhattan,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 6);
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&Point2::new(0, 0)));
+        assert_eq!(path.last(), Some(&Point2::new(2, 0)));
+        assert!(path.iter().all(|p| grid[*p] != '#'));
+        assert!(path.windows(2).all(|w| manhattan(w[0], w[1]) == 1));
+    }
+
+    #[test]
+    fn astar_prefers_cheaper_detour() {
+        let grid: Grid<u32> = Grid::parse_str("191\n111", |c| c.to_digit(10).unwrap()).unwrap();
+        let (cost, path) = astar(
+            &grid,
+            Point2::new(0, 0),
+            Point2::new(2, 0),
+            |_, to| grid[to],
+            |_, _| 0,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 4);
+        assert_eq!(
+            path,
+            vec![
+                Point2::new(0, 0),
+                Point2::new(0, 1),
+                Point2::new(1, 1),
+                Point2::new(2, 1),
+                Point2::new(2, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn astar_unreachable_goal() {
+        let grid: Grid<char> = "....\n....".parse().unwrap();
+
+        assert_eq!(
+            astar(
+                &grid,
+                Point2::new(0, 0),
+                Point2::new(4, 1),
+                walls(&grid),
+                manhattan
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn astar_start_is_goal() {
+        let grid: Grid<char> = "..".parse().unwrap();
+
+        assert_eq!(
+            astar(
+                &grid,
+                Point2::new(1, 0),
+                Point2::new(1, 0),
+                walls(&grid),
+                manhattan
+            ),
+            Some((0, vec![Point2::new(1, 0)]))
+        );
+    }
+
+    fn is_open(_: Point2, c: &char) -> bool {
+        *c != '#'
+    }
+
+    #[test]
+    fn bfs_path_straight_line() {
+        let grid: Grid<char> = "....\n....\n....".parse().unwrap();
+
+        assert_eq!(
+            bfs_path(&grid, Point2::new(0, 1), Point2::new(3, 1), is_open),
+            Some(vec![
+                Point2::new(0, 1),
+                Point2::new(1, 1),
+                Point2::new(2, 1),
+                Point2::new(3, 1)
+            ])
+        );
+    }
+
+    #[test]
+    fn bfs_path_detours_around_walls() {
+        let grid: Grid<char> = ".#...\n.#.#.\n...#.".parse().unwrap();
+
+        assert_eq!(
+            bfs_path(&grid, Point2::new(0, 0), Point2::new(4, 2), is_open),
+            Some(vec![
+                Point2::new(0, 0),
+                Point2::new(0, 1),
+                Point2::new(0, 2),
+                Point2::new(1, 2),
+                Point2::new(2, 2),
+                Point2::new(2, 1),
+                Point2::new(2, 0),
+                Point2::new(3, 0),
+                Point2::new(4, 0),
+                Point2::new(4, 1),
+                Point2::new(4, 2)
+            ])
+        );
+    }
+
+    #[test]
+    fn bfs_path_blocked_grid() {
+        let grid: Grid<char> = ".#.\n##.\n...".parse().unwrap();
+
+        assert_eq!(
+            bfs_path(&grid, Point2::new(0, 0), Point2::new(2, 2), is_open),
+            None
+        );
+    }
+
+    #[test]
+    fn bfs_path_start_is_goal() {
+        let grid: Grid<char> = "..".parse().unwrap();
+
+        assert_eq!(
+            bfs_path(&grid, Point2::new(0, 0), Point2::new(0, 0), is_open),
+            Some(vec![Point2::new(0, 0)])
+        );
+    }
+}
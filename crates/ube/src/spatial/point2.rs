@@ -74,6 +74,94 @@ impl Point2 {
             y: self.y.abs(),
         }
     }
+
+    /// Return the Manhattan (taxicab) distance between this point and `other`.
+    pub fn manhattan_distance(&self, other: Point2) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Iterates all points that are exactly `distance` Manhattan steps away
+    /// from this point, forming a diamond shaped ring around it. The ring
+    /// starts at the topmost point and continues clockwise.
+    ///
+    /// ```
+    /// use ube::spatial::Point2;
+    ///
+    /// let ring: Vec<Point2> = Point2::new(0, 0).points_at_manhattan_distance(1).collect();
+    /// assert_eq!(
+    ///     ring,
+    ///     vec![
+    ///         Point2::new(0, -1),
+    ///         Point2::new(1, 0),
+    ///         Point2::new(0, 1),
+    ///         Point2::new(-1, 0)
+    ///     ]
+    /// );
+    /// ```
+    pub fn points_at_manhattan_distance(self, distance: usize) -> impl Iterator<Item = Point2> {
+        let d = distance as isize;
+        let count = if distance == 0 { 1 } else { 4 * distance };
+
+        (0..count).map(move |k| {
+            if d == 0 {
+                return self;
+            }
+
+            let (side, i) = (k as isize / d, k as isize % d);
+
+            match side {
+                0 => Point2::new(self.x + i, self.y - d + i),
+                1 => Point2::new(self.x + d - i, self.y + i),
+                2 => Point2::new(self.x - i, self.y + d - i),
+                _ => Point2::new(self.x - d + i, self.y - i),
+            }
+        })
+    }
+
+    /// Iterates all points that are at most `distance` Manhattan steps away
+    /// from this point, forming a filled diamond. Points are returned one ring
+    /// at a time starting from this point.
+    pub fn points_within_manhattan_distance(self, distance: usize) -> impl Iterator<Item = Point2> {
+        (0..=distance).flat_map(move |d| self.points_at_manhattan_distance(d))
+    }
+
+    /// Iterates all points in the rectangle with corners `a` and `b`, including
+    /// the points on the edges of the rectangle. Points are returned in row
+    /// major order, and the corners can be given in any order.
+    ///
+    /// ```
+    /// use ube::spatial::Point2;
+    ///
+    /// assert_eq!(
+    ///     Point2::range_inclusive(Point2::new(1, 1), Point2::new(2, 2)).collect::<Vec<_>>(),
+    ///     vec![
+    ///         Point2::new(1, 1),
+    ///         Point2::new(2, 1),
+    ///         Point2::new(1, 2),
+    ///         Point2::new(2, 2),
+    ///     ]
+    /// );
+    /// ```
+    pub fn range_inclusive(a: Point2, b: Point2) -> impl Iterator<Item = Point2> {
+        iter_rows_inclusive(a, b)
+    }
+
+    /// Iterates all points in the rectangle starting at `start` and ending
+    /// before `end`, excluding the last row and column like `start..end`.
+    /// Points are returned in row major order, and nothing is returned when
+    /// `end` is not below and to the right of `start`.
+    ///
+    /// ```
+    /// use ube::spatial::Point2;
+    ///
+    /// assert_eq!(
+    ///     Point2::range(Point2::new(0, 0), Point2::new(2, 1)).collect::<Vec<_>>(),
+    ///     vec![Point2::new(0, 0), Point2::new(1, 0)]
+    /// );
+    /// ```
+    pub fn range(start: Point2, end: Point2) -> impl Iterator<Item = Point2> {
+        (start.y..end.y).flat_map(move |y| (start.x..end.x).map(move |x| Point2::new(x, y)))
+    }
 }
 
 // Convert `(isize, isize)` to `Point2`.
@@ -86,6 +174,51 @@ impl From<(isize, isize)> for Point2 {
     }
 }
 
+// Convert `(i32, i32)` to `Point2`.
+impl From<(i32, i32)> for Point2 {
+    fn from(value: (i32, i32)) -> Self {
+        Point2 {
+            x: value.0 as isize,
+            y: value.1 as isize,
+        }
+    }
+}
+
+// Convert `(u32, u32)` to `Point2`, panicking if a value does not fit in an `isize`.
+impl From<(u32, u32)> for Point2 {
+    fn from(value: (u32, u32)) -> Self {
+        Point2 {
+            x: isize::try_from(value.0).expect("x value should fit in an isize"),
+            y: isize::try_from(value.1).expect("y value should fit in an isize"),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("the value ({}, {}) is too large to be a Point2", .0, .1)]
+pub struct PointOverflowError(u64, u64);
+
+// Convert `(u64, u64)` to `Point2`, or return an error if a value is larger than `isize::MAX`.
+impl TryFrom<(u64, u64)> for Point2 {
+    type Error = PointOverflowError;
+
+    fn try_from(value: (u64, u64)) -> Result<Self, Self::Error> {
+        let err = || PointOverflowError(value.0, value.1);
+
+        Ok(Point2 {
+            x: isize::try_from(value.0).map_err(|_| err())?,
+            y: isize::try_from(value.1).map_err(|_| err())?,
+        })
+    }
+}
+
+// Convert `Point2` to `(isize, isize)`.
+impl From<Point2> for (isize, isize) {
+    fn from(value: Point2) -> Self {
+        (value.x, value.y)
+    }
+}
+
 // `[usize]` operator.
 impl ops::Index<usize> for Point2 {
     type Output = isize;
@@ -326,27 +459,27 @@ impl std::fmt::Display for Point2 {
 }
 
 #[derive(Debug, Error, PartialEq, Eq)]
-#[error("the value `{}` is not a valid Point2", .0)]
+#[error("the value `{}` is not a valid Point2 (expected `(x, y)` or `x, y`)", .0)]
 pub struct ParsePointError(String);
 
+/// Parses a point from either the canonical `(x, y)` format used by `Display`,
+/// or the `x,y` format without parentheses that is common in puzzle inputs.
+/// Whitespace around each component is ignored.
 impl FromStr for Point2 {
     type Err = ParsePointError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x, y) = s
-            .strip_prefix('(')
-            .and_then(|s| s.strip_suffix(')'))
-            .and_then(|s| s.split_once(','))
-            .ok_or(ParsePointError(s.to_string()))?;
-
-        let x = x
-            .trim()
-            .parse::<isize>()
-            .map_err(|_| ParsePointError(s.to_string()))?;
-        let y = y
-            .trim()
-            .parse::<isize>()
-            .map_err(|_| ParsePointError(s.to_string()))?;
+        let err = || ParsePointError(s.to_string());
+        let trimmed = s.trim();
+
+        let components = match trimmed.strip_prefix('(') {
+            Some(rest) => rest.strip_suffix(')').ok_or_else(err)?,
+            None => trimmed,
+        };
+
+        let (x, y) = components.split_once(',').ok_or_else(err)?;
+        let x = x.trim().parse::<isize>().map_err(|_| err())?;
+        let y = y.trim().parse::<isize>().map_err(|_| err())?;
 
         Ok(Point2 { x, y })
     }
@@ -364,6 +497,31 @@ pub fn iter_rows_inclusive(a: Point2, b: Point2) -> impl Iterator<Item = Point2>
     (start_y..end_y).flat_map(move |y| (start_x..end_x).map(move |x| Point2::new(x, y)))
 }
 
+/// Returns the four points diagonally adjacent to `pt` in northeast,
+/// northwest, southwest and southeast order.
+///
+/// ```
+/// use ube::spatial::{diagonal_neighbors, Point2};
+///
+/// assert_eq!(
+///     diagonal_neighbors(Point2::new(0, 0)),
+///     [
+///         Point2::new(1, -1),
+///         Point2::new(-1, -1),
+///         Point2::new(-1, 1),
+///         Point2::new(1, 1)
+///     ]
+/// );
+/// ```
+pub fn diagonal_neighbors(pt: Point2) -> [Point2; 4] {
+    [
+        pt + Direction8::Northeast,
+        pt + Direction8::Northwest,
+        pt + Direction8::Southwest,
+        pt + Direction8::Southeast,
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -375,6 +533,43 @@ mod tests {
         assert_eq!(15, v.y);
     }
 
+    #[test]
+    fn convert_from_int_tuples() {
+        assert_eq!(Point2::from((-3_i32, 17_i32)), Point2::new(-3, 17));
+        assert_eq!(
+            Point2::from((i32::MIN, i32::MAX)),
+            Point2::new(i32::MIN as isize, i32::MAX as isize)
+        );
+        assert_eq!(Point2::from((3_u32, 17_u32)), Point2::new(3, 17));
+        assert_eq!(
+            Point2::from((u32::MAX, 0_u32)),
+            Point2::new(u32::MAX as isize, 0)
+        );
+    }
+
+    #[test]
+    fn try_convert_from_u64_tuple() {
+        assert_eq!(Point2::try_from((5_u64, 8_u64)), Ok(Point2::new(5, 8)));
+        assert_eq!(
+            Point2::try_from((isize::MAX as u64, 0_u64)),
+            Ok(Point2::new(isize::MAX, 0))
+        );
+        assert_eq!(
+            Point2::try_from((isize::MAX as u64 + 1, 0_u64)),
+            Err(PointOverflowError(isize::MAX as u64 + 1, 0))
+        );
+        assert_eq!(
+            Point2::try_from((0_u64, u64::MAX)),
+            Err(PointOverflowError(0, u64::MAX))
+        );
+    }
+
+    #[test]
+    fn convert_to_tuple() {
+        let (x, y): (isize, isize) = Point2::new(-7, 12).into();
+        assert_eq!((x, y), (-7, 12));
+    }
+
     #[test]
     fn component_index() {
         let t = Point2::new(10, 20);
@@ -566,11 +761,101 @@ mod tests {
     fn parse_points() {
         assert_eq!(Ok(Point2::new(2, -49)), "(2,-49)".parse());
         assert_eq!(Ok(Point2::new(2, -49)), "(2, -49)".parse());
+        assert_eq!(Ok(Point2::new(2, -49)), " ( 2 , -49 ) ".parse());
+    }
+
+    #[test]
+    fn parse_points_without_parens() {
+        assert_eq!(Ok(Point2::new(3, -7)), "3,-7".parse());
+        assert_eq!(Ok(Point2::new(3, -7)), "3, -7".parse());
+        assert_eq!(Ok(Point2::new(-12, 0)), " -12 ,0 ".parse());
+    }
+
+    #[test]
+    fn parse_invalid_points() {
+        for s in [
+            "", "3", "(3)", "(3, 4", "3, 4)", "3 4", "3,4,5", "(a, 4)", "3,b", "(,)",
+        ] {
+            assert_eq!(
+                Err(ParsePointError(s.to_string())),
+                s.parse::<Point2>(),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn manhattan_distance() {
+        assert_eq!(Point2::new(1, -2).manhattan_distance(Point2::new(1, -2)), 0);
+        assert_eq!(
+            Point2::new(1, -2).manhattan_distance(Point2::new(-3, 5)),
+            11
+        );
+        assert_eq!(
+            Point2::new(-3, 5).manhattan_distance(Point2::new(1, -2)),
+            11
+        );
+    }
+
+    #[test]
+    fn points_at_manhattan_distance_zero_is_center() {
+        assert_eq!(
+            Point2::new(4, -2)
+                .points_at_manhattan_distance(0)
+                .collect::<Vec<_>>(),
+            vec![Point2::new(4, -2)]
+        );
+    }
+
+    #[test]
+    fn points_at_manhattan_distance_one() {
+        assert_eq!(
+            Point2::new(4, -2)
+                .points_at_manhattan_distance(1)
+                .collect::<Vec<_>>(),
+            vec![
+                Point2::new(4, -3),
+                Point2::new(5, -2),
+                Point2::new(4, -1),
+                Point2::new(3, -2)
+            ]
+        );
+    }
+
+    #[test]
+    fn points_at_manhattan_distance_two() {
+        let center = Point2::new(4, -2);
+        let ring: Vec<Point2> = center.points_at_manhattan_distance(2).collect();
 
         assert_eq!(
-            Err(ParsePointError("2, -49".to_string())),
-            "2, -49".parse::<Point2>()
+            ring,
+            vec![
+                Point2::new(4, -4),
+                Point2::new(5, -3),
+                Point2::new(6, -2),
+                Point2::new(5, -1),
+                Point2::new(4, 0),
+                Point2::new(3, -1),
+                Point2::new(2, -2),
+                Point2::new(3, -3)
+            ]
         );
+        assert!(ring.iter().all(|p| p.manhattan_distance(center) == 2));
+    }
+
+    #[test]
+    fn points_within_manhattan_distance() {
+        let center = Point2::new(-1, 1);
+        let points: Vec<Point2> = center.points_within_manhattan_distance(3).collect();
+
+        assert_eq!(points.len(), 1 + 4 + 8 + 12);
+        assert_eq!(points.first(), Some(&center));
+        assert!(points.iter().all(|p| p.manhattan_distance(center) <= 3));
+
+        let mut unique = points.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), points.len());
     }
 
     #[test]
@@ -657,6 +942,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn range_inclusive_matches_iter_rows_inclusive() {
+        for (a, b) in [
+            (Point2::new(3, 5), Point2::new(5, 6)),
+            (Point2::new(5, 6), Point2::new(3, 5)),
+            (Point2::new(-2, 1), Point2::new(1, -1)),
+            (Point2::new(4, 4), Point2::new(4, 4)),
+        ] {
+            assert_eq!(
+                Point2::range_inclusive(a, b).collect::<Vec<Point2>>(),
+                iter_rows_inclusive(a, b).collect::<Vec<Point2>>(),
+            );
+        }
+    }
+
+    #[test]
+    fn range_excludes_end() {
+        assert_eq!(
+            Point2::range(Point2::new(3, 5), Point2::new(6, 7)).collect::<Vec<Point2>>(),
+            iter_rows_inclusive(Point2::new(3, 5), Point2::new(5, 6)).collect::<Vec<Point2>>(),
+        );
+        assert_eq!(
+            Point2::range(Point2::new(3, 5), Point2::new(3, 7)).count(),
+            0
+        );
+        assert_eq!(
+            Point2::range(Point2::new(3, 5), Point2::new(1, 1)).count(),
+            0
+        );
+    }
+
     #[test]
     fn iter_rows_inclusive_param_order_doesnt_matter() {
         assert_eq!(
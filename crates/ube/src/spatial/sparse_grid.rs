@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use super::{BoundingBox2, Grid, Point2};
+
+/// A two dimensional grid that only stores the cells that have been set, which
+/// allows it to cover coordinate spaces far too large for a dense `Grid`.
+///
+/// Cells that have not been set are treated as holding the grid's default
+/// value.
+///
+/// ```
+/// use ube::spatial::{Point2, SparseGrid};
+///
+/// let mut grid = SparseGrid::new('.');
+/// grid.set(Point2::new(-3_000_000, 12), '#');
+///
+/// assert_eq!(grid.get(Point2::new(-3_000_000, 12)), &'#');
+/// assert_eq!(grid.get(Point2::new(5, 5)), &'.');
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+    default: T,
+}
+
+impl<T> SparseGrid<T> {
+    /// Return a new empty grid where every cell holds `default` until it is
+    /// set.
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+        }
+    }
+
+    /// Get the number of cells that have been explicitly set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Check if no cells have been explicitly set.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Get a reference to the value stored at `p`, or the grid's default value
+    /// if `p` has not been set.
+    pub fn get(&self, p: Point2) -> &T {
+        self.cells.get(&p).unwrap_or(&self.default)
+    }
+
+    /// Set the value stored at `p`.
+    pub fn set(&mut self, p: Point2, value: T) {
+        self.cells.insert(p, value);
+    }
+
+    /// Remove the value stored at `p` so that it holds the default value again.
+    /// Returns the removed value, or `None` if `p` was not set.
+    pub fn remove(&mut self, p: Point2) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    /// Returns an iterator over the cells that have been explicitly set, in no
+    /// particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.cells.iter().map(|(p, v)| (*p, v))
+    }
+
+    /// Return the smallest bounding box that contains every cell that has been
+    /// set, or `None` if no cells have been set.
+    pub fn bounding_box(&self) -> Option<BoundingBox2> {
+        BoundingBox2::from_points(self.cells.keys().copied())
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Copy the region covered by `bounding_box()` into a dense `Grid`. Cells
+    /// that were not set hold the default value.
+    ///
+    /// The dense grid is translated so that the top left corner of the
+    /// bounding box is at (0, 0) in the returned grid. An empty grid is
+    /// returned if no cells have been set.
+    pub fn to_dense_grid(&self) -> Grid<T> {
+        match self.bounding_box() {
+            Some(bounds) => Grid::with_values(
+                bounds.width() as usize,
+                bounds.height() as usize,
+                bounds.points().map(|p| self.get(p).clone()),
+            )
+            .expect("bounding box points fill the grid"),
+            None => Grid::default(),
+        }
+    }
+}
+
+impl<T: Default> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_unset_cell_returns_default() {
+        let mut grid = SparseGrid::new(7);
+        assert_eq!(grid.get(Point2::new(0, 0)), &7);
+
+        grid.set(Point2::new(1_000_000, -1_000_000), 3);
+        assert_eq!(grid.get(Point2::new(1_000_000, -1_000_000)), &3);
+        assert_eq!(grid.get(Point2::new(1_000_000, 1_000_000)), &7);
+    }
+
+    #[test]
+    fn set_and_remove_cells() {
+        let mut grid: SparseGrid<char> = SparseGrid::default();
+        assert!(grid.is_empty());
+
+        grid.set(Point2::new(2, 3), 'a');
+        grid.set(Point2::new(2, 3), 'b');
+        grid.set(Point2::new(-1, 0), 'c');
+        assert_eq!(grid.len(), 2);
+
+        assert_eq!(grid.remove(Point2::new(2, 3)), Some('b'));
+        assert_eq!(grid.remove(Point2::new(2, 3)), None);
+        assert_eq!(grid.get(Point2::new(2, 3)), &'\0');
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            vec![(Point2::new(-1, 0), &'c')]
+        );
+    }
+
+    #[test]
+    fn bounding_box_covers_set_cells() {
+        let mut grid = SparseGrid::new(0);
+        assert_eq!(grid.bounding_box(), None);
+
+        grid.set(Point2::new(5, -2), 1);
+        grid.set(Point2::new(-3, 4), 2);
+        grid.set(Point2::new(0, 0), 3);
+
+        assert_eq!(
+            grid.bounding_box(),
+            Some(BoundingBox2::new(Point2::new(-3, -2), Point2::new(5, 4)))
+        );
+    }
+
+    #[test]
+    fn round_trip_to_dense_grid() {
+        let dense: Grid<char> = "#..\n.#.\n..#\n#.#".parse().unwrap();
+        let mut sparse = SparseGrid::new('.');
+
+        for p in dense.points() {
+            if dense[p] != '.' {
+                sparse.set(p + Point2::new(-10, 20), dense[p]);
+            }
+        }
+
+        assert_eq!(sparse.len(), 5);
+        assert_eq!(sparse.to_dense_grid(), dense);
+    }
+
+    #[test]
+    fn empty_grid_to_dense_grid() {
+        assert_eq!(SparseGrid::new(1).to_dense_grid(), Grid::default());
+    }
+}
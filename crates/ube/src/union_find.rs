@@ -1,7 +1,6 @@
 use std::{collections::HashMap, fmt::Debug, hash::Hash};
 
-use crate::counter::Counter;
-
+/// An opaque handle for a set of elements stored in a disjoint set.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SetId(usize);
 
@@ -11,21 +10,22 @@ impl std::fmt::Display for SetId {
     }
 }
 
-/// An opaque handle for elements stored in a disjoint set.
-impl SetId {
-    pub(crate) fn value(&self) -> usize {
-        self.0
-    }
-}
-
-pub struct Node {
+struct Node {
     parent: usize,
+    rank: usize,
     size: usize,
 }
 
+/// A disjoint set (union find) container that tracks which elements belong to
+/// the same component.
+///
+/// `find` uses path halving and `union` merges by rank, so both operations run
+/// in amortized near constant time.
 pub struct UnionFind<T> {
     index: HashMap<T, usize>,
+    elements: Vec<T>,
     nodes: Vec<Node>,
+    component_count: usize,
 }
 
 impl<T> UnionFind<T> {
@@ -33,7 +33,9 @@ impl<T> UnionFind<T> {
     pub fn new() -> Self {
         Self {
             index: HashMap::new(),
+            elements: Vec::new(),
             nodes: Vec::new(),
+            component_count: 0,
         }
     }
 
@@ -46,110 +48,206 @@ impl<T> UnionFind<T> {
     pub fn len(&self) -> usize {
         self.nodes.len()
     }
+
+    /// Get the number of distinct components (sets) in this union find data
+    /// structure.
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+
+    /// Get the index of the root node for the node at `index` without
+    /// modifying the tree.
+    fn root_of(&self, mut index: usize) -> usize {
+        while self.nodes[index].parent != index {
+            index = self.nodes[index].parent;
+        }
+
+        index
+    }
+
+    /// Get the index of the root node for the node at `index`, pointing every
+    /// other node on the path at its grandparent (path halving) along the way.
+    fn compress_root_of(&mut self, mut index: usize) -> usize {
+        while self.nodes[index].parent != index {
+            let grandparent = self.nodes[self.nodes[index].parent].parent;
+            self.nodes[index].parent = grandparent;
+            index = grandparent;
+        }
+
+        index
+    }
 }
 
 impl<T> UnionFind<T>
 where
-    T: Hash + Eq,
+    T: Hash + Eq + Clone,
 {
     /// Add an element to the union find data structure, with the new element `v` belonging to its
     /// own distinct set.
+    ///
+    /// Adding an element that already exists does nothing, and returns the id of the set that it
+    /// belongs to.
     pub fn add(&mut self, v: T) -> SetId {
-        let new_index = *self.index.entry(v).or_insert_with(|| {
-            let next_index = self.nodes.len();
+        let index = self.index_of(v);
+        SetId(self.compress_root_of(index))
+    }
 
-            self.nodes.push(Node {
-                parent: next_index,
-                size: 1,
-            });
+    /// Get the id of the set containing element `v`, or `None` if `v` has not been added.
+    pub fn set_id(&self, v: &T) -> Option<SetId> {
+        self.index.get(v).map(|i| SetId(self.root_of(*i)))
+    }
 
-            next_index
-        });
+    /// Get the representative element of the component containing `x`. Two elements are in the
+    /// same component when they share the same representative.
+    ///
+    /// `x` is added as its own component if it has not been seen before.
+    pub fn find(&mut self, x: T) -> T {
+        let index = self.index_of(x);
+        let root = self.compress_root_of(index);
 
-        SetId(new_index)
+        self.elements[root].clone()
     }
 
-    /// Determines the root of the set containing element `v`.
-    pub fn find(&self, v: &T) -> Option<SetId> {
-        if let Some(mut index) = self.index.get(v) {
-            let mut node = &self.nodes[*index];
+    /// Merge the components containing the elements `a` and `b` into a single component. Elements
+    /// that have not been seen before are added first.
+    ///
+    /// Returns `true` if `a` and `b` were in different components, or `false` if they were already
+    /// in the same component.
+    pub fn union(&mut self, a: T, b: T) -> bool {
+        let a = self.index_of(a);
+        let b = self.index_of(b);
 
-            while node.parent != *index {
-                index = &node.parent;
-                node = &self.nodes[*index]
-            }
+        let a = self.compress_root_of(a);
+        let b = self.compress_root_of(b);
 
-            Some(SetId(*index))
+        // Skip the union operation if a and b belong to the same set.
+        if a == b {
+            return false;
+        }
+
+        // Attach the shorter tree under the root of the taller tree so the height of the combined
+        // tree only grows when both trees have the same rank.
+        let (root, child) = if self.nodes[a].rank >= self.nodes[b].rank {
+            (a, b)
         } else {
-            None
+            (b, a)
+        };
+
+        if self.nodes[root].rank == self.nodes[child].rank {
+            self.nodes[root].rank += 1;
         }
+
+        self.nodes[child].parent = root;
+        self.nodes[root].size += self.nodes[child].size;
+        self.component_count -= 1;
+
+        true
     }
 
-    /// Merge the sets containing the elements `a` and `b` into a single set.
-    ///
-    /// This method returns the root of the combined set, or `None` if the two elements are already
-    /// in the same set.
-    pub fn union(&mut self, a: &T, b: &T) -> Option<SetId> {
-        let ai = self.find(a).unwrap().value();
-        let bi = self.find(b).unwrap().value();
+    /// Check if elements `a` and `b` belong to the same component. An element that has not been
+    /// added is only in the same component as itself.
+    pub fn same_component(&self, a: T, b: T) -> bool {
+        match (self.set_id(&a), self.set_id(&b)) {
+            (Some(a), Some(b)) => a == b,
+            _ => a == b,
+        }
+    }
 
-        // Skip the union operation if a and b belong to the same set.
-        if ai == bi {
-            return None;
+    /// Get the node index of `v`, adding it as its own component if it has not been seen before.
+    fn index_of(&mut self, v: T) -> usize {
+        if let Some(index) = self.index.get(&v) {
+            return *index;
         }
 
-        // Swap a and b if needed to make sure `a` has the fewest nodes since
-        // it will be the new root.
-        let (ai, bi) = if self.nodes[ai].size <= self.nodes[bi].size {
-            (ai, bi)
-        } else {
-            (bi, ai)
-        };
+        let new_index = self.nodes.len();
 
-        // Perform set union on x and y.
-        self.nodes[bi].parent = ai;
-        self.nodes[ai].size += self.nodes[bi].size;
+        self.nodes.push(Node {
+            parent: new_index,
+            rank: 0,
+            size: 1,
+        });
+        self.elements.push(v.clone());
+        self.index.insert(v, new_index);
+        self.component_count += 1;
 
-        Some(SetId(ai))
+        new_index
     }
+}
 
-    /// Check if element `b` and `b` belong to the same set.
-    pub fn is_connected(&self, _a: &T, _b: &T) -> bool {
-        todo!("implement me! -- disjoint_set.rs:97");
+impl<T> UnionFind<T> {
+    /// Get a list of the sets in this disjoint set container including the number of elements in
+    /// each set, ordered from the largest set to the smallest.
+    pub fn sets(&self) -> Vec<(SetId, usize)> {
+        let mut sets = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(i, node)| node.parent == *i)
+            .map(|(i, node)| (SetId(i), node.size))
+            .collect::<Vec<_>>();
+        sets.sort_by_key(|s| std::cmp::Reverse(s.1));
+        sets
     }
 }
 
 impl<T> UnionFind<T>
 where
-    T: Hash + Eq,
+    T: Clone,
 {
-    /// Get a list of the sets in this disjoint set container including the number of elements in
-    /// each set.
-    pub fn sets(&self) -> Vec<(SetId, usize)> {
-        let mut c: Counter<SetId> = Counter::new();
+    /// Get the members of every component, ordered by when each component's first member was
+    /// added. The representative element of each component comes first, followed by the rest of
+    /// the members in the order they were added.
+    pub fn components(&self) -> Vec<Vec<T>> {
+        let mut component_by_root: HashMap<usize, usize> = HashMap::new();
+        let mut components: Vec<Vec<T>> = Vec::new();
+
+        for index in 0..self.nodes.len() {
+            let root = self.root_of(index);
+            let component = *component_by_root.entry(root).or_insert_with(|| {
+                components.push(vec![self.elements[root].clone()]);
+                components.len() - 1
+            });
 
-        for v in self.index.keys() {
-            c.add(self.find(v).unwrap());
+            if index != root {
+                components[component].push(self.elements[index].clone());
+            }
         }
 
-        c.most_common()
+        components
+    }
+
+    /// Get the members of the component containing `x`, or `None` if `x` has not been added. The
+    /// representative element comes first, followed by the rest of the members in the order they
+    /// were added.
+    pub fn component(&self, x: T) -> Option<Vec<T>>
+    where
+        T: Hash + Eq,
+    {
+        let root = self.root_of(*self.index.get(&x)?);
+        let members = (0..self.nodes.len())
+            .filter(|i| *i != root && self.root_of(*i) == root)
+            .map(|i| self.elements[i].clone());
+
+        Some(
+            std::iter::once(self.elements[root].clone())
+                .chain(members)
+                .collect(),
+        )
     }
 }
 
 impl<T> FromIterator<T> for UnionFind<T>
 where
-    T: Eq + Hash,
+    T: Clone + Eq + Hash,
 {
     fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
-        let mut index: HashMap<T, usize> = Default::default();
-        let mut nodes: Vec<Node> = Default::default();
+        let mut s = Self::new();
 
-        iter.into_iter().enumerate().for_each(|(i, v)| {
-            index.insert(v, nodes.len());
-            nodes.push(Node { parent: i, size: 1 });
-        });
+        for v in iter {
+            s.add(v);
+        }
 
-        Self { index, nodes }
+        s
     }
 }
 
@@ -158,21 +256,11 @@ where
     T: Clone + Eq + Hash,
 {
     fn from(value: [T; N]) -> Self {
-        Self {
-            index: HashMap::from_iter(value.iter().enumerate().map(|(i, v)| (v.clone(), i))),
-            nodes: value
-                .iter()
-                .enumerate()
-                .map(|(i, _)| Node { parent: i, size: 1 })
-                .collect::<Vec<_>>(),
-        }
+        Self::from_iter(value)
     }
 }
 
-impl<T> Default for UnionFind<T>
-where
-    T: Hash + Eq,
-{
+impl<T> Default for UnionFind<T> {
     fn default() -> Self {
         Self::new()
     }
@@ -193,8 +281,8 @@ mod tests {
 
         assert_ne!(id_1, id_2);
 
-        assert_eq!(s.find(&Point3 { x: 1, y: 2, z: 3 }), Some(id_1));
-        assert_eq!(s.find(&Point3 { x: 5, y: 8, z: 4 }), Some(id_2));
+        assert_eq!(s.set_id(&Point3 { x: 1, y: 2, z: 3 }), Some(id_1));
+        assert_eq!(s.set_id(&Point3 { x: 5, y: 8, z: 4 }), Some(id_2));
     }
 
     #[test]
@@ -202,7 +290,7 @@ mod tests {
         let mut s: UnionFind<Point3> = Default::default();
         s.add(Point3 { x: 1, y: 2, z: 3 });
 
-        assert!(s.find(&Point3 { x: 1, y: 2, z: -3 }).is_none());
+        assert!(s.set_id(&Point3 { x: 1, y: 2, z: -3 }).is_none());
     }
 
     #[test]
@@ -262,26 +350,24 @@ mod tests {
         s.add(b);
         s.add(c);
 
-        assert_ne!(s.find(&a), s.find(&b));
-        assert_ne!(s.find(&b), s.find(&c));
+        assert_ne!(s.set_id(&a), s.set_id(&b));
+        assert_ne!(s.set_id(&b), s.set_id(&c));
 
         // First iteration - union a & b, with c being its own set.
-        let id_u = s.union(&a, &b);
+        assert!(s.union(a, b));
 
-        assert_eq!(s.find(&a), id_u);
-        assert_eq!(s.find(&b), id_u);
-        assert_ne!(s.find(&a), s.find(&c));
+        assert_eq!(s.set_id(&a), s.set_id(&b));
+        assert_ne!(s.set_id(&a), s.set_id(&c));
 
         // Second iteration - union a & c. All points will be in the same set.
-        let id_u = s.union(&a, &c);
+        assert!(s.union(a, c));
 
-        assert_eq!(s.find(&a), id_u);
-        assert_eq!(s.find(&b), id_u);
-        assert_eq!(s.find(&c), id_u);
+        assert_eq!(s.set_id(&a), s.set_id(&b));
+        assert_eq!(s.set_id(&b), s.set_id(&c));
     }
 
     #[test]
-    fn union_returns_none_if_both_elements_in_same_set() {
+    fn union_returns_false_if_both_elements_in_same_set() {
         let mut s: UnionFind<Point3> = Default::default();
         let a = Point3 {
             x: 162,
@@ -298,12 +384,246 @@ mod tests {
         s.add(a);
         s.add(b);
 
-        let id_u = s.union(&a, &b);
+        assert!(s.union(a, b));
+        assert_eq!(s.set_id(&a), s.set_id(&b));
 
-        assert_eq!(s.find(&a), id_u);
-        assert_eq!(s.find(&b), id_u);
+        // Both elements are in the same set now so calling should return `false`.
+        assert!(!s.union(a, b));
+        assert!(!s.union(b, a));
+    }
+
+    #[test]
+    fn find_returns_same_representative_for_component() {
+        let mut s: UnionFind<&str> = UnionFind::from(["a", "b", "c", "d"]);
+
+        assert_eq!(s.find("a"), "a");
+        assert_eq!(s.find("d"), "d");
+
+        s.union("a", "b");
+        s.union("c", "d");
+
+        assert_eq!(s.find("a"), s.find("b"));
+        assert_eq!(s.find("c"), s.find("d"));
+        assert_ne!(s.find("a"), s.find("c"));
+
+        s.union("b", "d");
+
+        let root = s.find("a");
+        assert!(["a", "b", "c", "d"].contains(&root));
+
+        for v in ["a", "b", "c", "d"] {
+            assert_eq!(s.find(v), root);
+        }
+    }
+
+    #[test]
+    fn find_adds_unknown_elements() {
+        let mut s: UnionFind<i32> = Default::default();
+
+        assert_eq!(s.find(7), 7);
+        assert_eq!(s.len(), 1);
+        assert_eq!(s.component_count(), 1);
+    }
+
+    #[test]
+    fn component_count_tracks_unions() {
+        let mut s: UnionFind<usize> = (0..10).collect();
+        assert_eq!(s.component_count(), 10);
 
-        // Both elements are in the same set now so calling should return `None`.
-        assert!(s.union(&a, &b).is_none());
+        // Join the even numbers into one component and the odd numbers into another.
+        for i in 2..10 {
+            assert!(s.union(i - 2, i));
+        }
+
+        assert_eq!(s.component_count(), 2);
+        assert_eq!(s.len(), 10);
+
+        // Unions within a component do not change the count.
+        assert!(!s.union(0, 8));
+        assert!(!s.union(9, 1));
+        assert_eq!(s.component_count(), 2);
+
+        assert!(s.union(3, 4));
+        assert_eq!(s.component_count(), 1);
+    }
+
+    #[test]
+    fn same_component_for_chain_and_star() {
+        let mut s: UnionFind<usize> = Default::default();
+
+        // A chain 0 - 1 - 2 - 3 - 4.
+        for i in 1..5 {
+            s.union(i - 1, i);
+        }
+
+        // A star with 10 at the center and 11..15 as leaves.
+        for i in 11..15 {
+            s.union(10, i);
+        }
+
+        assert!(s.same_component(0, 4));
+        assert!(s.same_component(4, 2));
+        assert!(s.same_component(11, 14));
+        assert!(s.same_component(10, 13));
+        assert!(!s.same_component(0, 10));
+        assert!(!s.same_component(4, 14));
+
+        assert_eq!(s.component_count(), 2);
+        assert_eq!(
+            s.sets().iter().map(|(_, size)| *size).collect::<Vec<_>>(),
+            vec![5, 5]
+        );
+
+        // Joining the chain to the star puts every element in one component.
+        assert!(s.union(2, 12));
+        assert!(s.same_component(0, 14));
+        assert_eq!(s.component_count(), 1);
+        assert_eq!(s.sets().len(), 1);
+        assert_eq!(s.sets()[0].1, 10);
+    }
+
+    #[test]
+    fn same_component_with_unknown_elements() {
+        let s: UnionFind<usize> = UnionFind::from([1, 2]);
+
+        assert!(s.same_component(5, 5));
+        assert!(!s.same_component(1, 5));
+        assert!(!s.same_component(1, 2));
+        assert!(s.same_component(1, 1));
+    }
+
+    #[test]
+    fn union_by_rank_keeps_trees_shallow() {
+        let mut s: UnionFind<usize> = Default::default();
+
+        // Union 1024 elements pairwise, then pairs of pairs and so on. Each round doubles the size
+        // of every component but union by rank only adds one level to the trees.
+        let mut step = 1;
+
+        while step < 1024 {
+            for i in (0..1024).step_by(step * 2) {
+                assert!(s.union(i, i + step));
+            }
+
+            step *= 2;
+        }
+
+        assert_eq!(s.component_count(), 1);
+
+        let max_depth = (0..1024).map(|i| depth_of(&s, &i)).max().unwrap();
+        assert!(max_depth <= 10, "tree depth {max_depth} exceeds log2(n)");
+    }
+
+    /// Get the number of parent links between `v` and the root of its tree.
+    fn depth_of<T: Hash + Eq>(s: &UnionFind<T>, v: &T) -> usize {
+        let mut index = s.index[v];
+        let mut depth = 0;
+
+        while s.nodes[index].parent != index {
+            index = s.nodes[index].parent;
+            depth += 1;
+        }
+
+        depth
+    }
+
+    #[test]
+    fn find_compresses_path_to_root() {
+        let mut s: UnionFind<usize> = Default::default();
+
+        // Build a tree of 16 elements by merging pairs, then pairs of pairs and so on. The rank
+        // rules leave the last element four links away from the root.
+        let mut step = 1;
+
+        while step < 16 {
+            for i in (0..16).step_by(step * 2) {
+                s.union(i, i + step);
+            }
+
+            step *= 2;
+        }
+
+        let deepest = (0..16).max_by_key(|i| depth_of(&s, i)).unwrap();
+        let depth_before = depth_of(&s, &deepest);
+        assert!(
+            depth_before >= 3,
+            "expected a deep tree, got depth {depth_before}"
+        );
+
+        let root = s.find(deepest);
+
+        assert!(depth_of(&s, &deepest) < depth_before);
+        assert_eq!(s.find(deepest), root);
+    }
+
+    #[test]
+    fn from_iter_ignores_duplicates() {
+        let s: UnionFind<char> = "hello".chars().collect();
+
+        assert_eq!(s.len(), 4);
+        assert_eq!(s.component_count(), 4);
+    }
+
+    #[test]
+    fn components_of_isolated_nodes() {
+        let s: UnionFind<&str> = UnionFind::from(["a", "b", "c"]);
+
+        assert_eq!(s.components(), vec![vec!["a"], vec!["b"], vec!["c"]]);
+        assert_eq!(s.component("b"), Some(vec!["b"]));
+        assert_eq!(s.component("z"), None);
+    }
+
+    #[test]
+    fn components_of_one_big_component() {
+        let mut s: UnionFind<usize> = (0..6).collect();
+
+        for i in 1..6 {
+            s.union(i, i - 1);
+        }
+
+        let root = s.find(3);
+        let mut expected = vec![root];
+        expected.extend((0..6).filter(|i| *i != root));
+
+        assert_eq!(s.components(), vec![expected.clone()]);
+
+        for i in 0..6 {
+            assert_eq!(s.component(i), Some(expected.clone()));
+        }
+    }
+
+    #[test]
+    fn components_of_mixed_sizes() {
+        let mut s: UnionFind<char> = "abcdefg".chars().collect();
+
+        // Components: {a, c, f}, {b}, {d, e}, {g}
+        s.union('a', 'c');
+        s.union('f', 'c');
+        s.union('e', 'd');
+
+        let components = s.components();
+        assert_eq!(components.len(), 4);
+        assert_eq!(components.len(), s.component_count());
+
+        // Components are ordered by their first added member, and each component starts with its
+        // representative followed by the other members in insertion order.
+        let expected_members = [vec!['a', 'c', 'f'], vec!['b'], vec!['d', 'e'], vec!['g']];
+
+        for (component, members) in components.iter().zip(expected_members) {
+            let representative = component[0];
+            assert_eq!(s.set_id(&representative), s.set_id(&members[0]));
+
+            let mut sorted = component.clone();
+            sorted.sort();
+            assert_eq!(sorted, members);
+
+            let rest: Vec<char> = members
+                .into_iter()
+                .filter(|m| *m != representative)
+                .collect();
+            assert_eq!(component[1..], rest);
+
+            assert_eq!(s.component(representative).as_ref(), Some(component));
+        }
     }
 }
@@ -1,7 +1,8 @@
 use regex::Regex;
-use std::{str::FromStr, sync::OnceLock};
+use std::{collections::HashMap, hash::Hash, str::FromStr, sync::OnceLock};
 
 static RE_CELL_FIND_INTS: OnceLock<Regex> = OnceLock::new();
+static RE_CELL_FIND_UINTS: OnceLock<Regex> = OnceLock::new();
 
 /// Find all digits (0-9 chars) in `text`, ignoring any values that are not
 /// digit characters.
@@ -45,6 +46,47 @@ pub fn find_ints<F: FromStr>(text: &str) -> Result<Vec<F>, <F as FromStr>::Err>
         .collect()
 }
 
+/// Extract every signed integer in `text`, where an integer is a run of digits
+/// that is optionally preceded by `-`. Everything between the integers is
+/// ignored, and integers too large for an `i64` are skipped.
+///
+/// Ranges such as `3-5` are read as `3` and `-5`; use `extract_uints` when the
+/// input never contains negative values.
+///
+/// ```
+/// use ube::utils::extract_ints;
+///
+/// assert_eq!(extract_ints("move 3 from 1 to 2"), vec![3, 1, 2]);
+/// assert_eq!(extract_ints("p=0,4 v=3,-3"), vec![0, 4, 3, -3]);
+/// ```
+pub fn extract_ints(text: &str) -> Vec<i64> {
+    let re = RE_CELL_FIND_INTS
+        .get_or_init(|| Regex::new(r"-?[0-9]+").expect("find_ints regex failed to compile"));
+
+    re.find_iter(text)
+        .filter_map(|m| m.as_str().parse().ok())
+        .collect()
+}
+
+/// Extract every unsigned integer in `text`, where an integer is a run of
+/// digits. Everything between the integers (including `-` signs) is ignored,
+/// and integers too large for a `u64` are skipped.
+///
+/// ```
+/// use ube::utils::extract_uints;
+///
+/// assert_eq!(extract_uints("2-4,6-8"), vec![2, 4, 6, 8]);
+/// assert_eq!(extract_uints("Game 12: 3 blue"), vec![12, 3]);
+/// ```
+pub fn extract_uints(text: &str) -> Vec<u64> {
+    let re = RE_CELL_FIND_UINTS
+        .get_or_init(|| Regex::new(r"[0-9]+").expect("extract_uints regex failed to compile"));
+
+    re.find_iter(text)
+        .filter_map(|m| m.as_str().parse().ok())
+        .collect()
+}
+
 /// Return an iterator over the combination of `item`s taken two at a time with-
 /// out repetition.
 ///
@@ -97,6 +139,220 @@ impl<'a, T> Iterator for PairwiseCombinations<'a, T> {
     }
 }
 
+/// Find every prime number up to and including `limit` with the sieve of
+/// Eratosthenes. The returned vector has `limit + 1` entries, and `result[i]`
+/// is `true` if `i` is prime.
+///
+/// ```
+/// use ube::utils::sieve_of_eratosthenes;
+///
+/// let sieve = sieve_of_eratosthenes(10);
+/// assert_eq!(sieve.len(), 11);
+/// assert!(sieve[7]);
+/// assert!(!sieve[9]);
+/// ```
+pub fn sieve_of_eratosthenes(limit: usize) -> Vec<bool> {
+    let mut sieve = vec![true; limit + 1];
+
+    sieve[0] = false;
+
+    if limit >= 1 {
+        sieve[1] = false;
+    }
+
+    let mut i = 2;
+
+    while i * i <= limit {
+        if sieve[i] {
+            for multiple in (i * i..=limit).step_by(i) {
+                sieve[multiple] = false;
+            }
+        }
+
+        i += 1;
+    }
+
+    sieve
+}
+
+/// Returns every prime number up to and including `limit` in ascending order.
+///
+/// ```
+/// use ube::utils::primes_up_to;
+///
+/// assert_eq!(primes_up_to(20), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+/// ```
+pub fn primes_up_to(limit: usize) -> Vec<usize> {
+    sieve_of_eratosthenes(limit)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, is_prime)| is_prime.then_some(i))
+        .collect()
+}
+
+/// Check if `n` is a prime number. Small values are checked with trial
+/// division, and larger values use a deterministic Miller-Rabin test that is
+/// exact for every `u64`.
+///
+/// ```
+/// use ube::utils::is_prime;
+///
+/// assert!(is_prime(7919));
+/// assert!(!is_prime(7917));
+/// assert!(is_prime(18_446_744_073_709_551_557));
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    const TRIAL_DIVISION_LIMIT: u64 = 1 << 20;
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+
+    if n < TRIAL_DIVISION_LIMIT {
+        return (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d));
+    }
+
+    if WITNESSES.iter().any(|w| n.is_multiple_of(*w)) {
+        return false;
+    }
+
+    // Write n - 1 as d * 2^r where d is odd.
+    let r = (n - 1).trailing_zeros();
+    let d = (n - 1) >> r;
+
+    let mul_mod = |a: u64, b: u64| ((a as u128 * b as u128) % n as u128) as u64;
+    let pow_mod = |mut base: u64, mut exp: u64| {
+        let mut result = 1;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = mul_mod(result, base);
+            }
+
+            base = mul_mod(base, base);
+            exp >>= 1;
+        }
+
+        result
+    };
+
+    WITNESSES.iter().all(|w| {
+        let mut x = pow_mod(*w, d);
+
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+
+        for _ in 1..r {
+            x = mul_mod(x, x);
+
+            if x == n - 1 {
+                return true;
+            }
+        }
+
+        false
+    })
+}
+
+/// Caches the values computed for each key so that expensive or recursive
+/// calculations only run once per key.
+///
+/// ```
+/// use ube::utils::Memo;
+///
+/// let mut memo = Memo::new();
+///
+/// assert_eq!(*memo.get_or_insert_with("a", || 1), 1);
+/// assert_eq!(*memo.get_or_insert_with("a", || 2), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Memo<K: Eq + Hash, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K: Eq + Hash, V> Memo<K, V> {
+    /// Initialize as an empty memo with no cached values.
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Get the cached value for `key`, or `None` if no value has been cached.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// Get the cached value for `key`, calling `f` to compute and cache the
+    /// value if it is not already cached.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce() -> V) -> &V {
+        self.cache.entry(key).or_insert_with(f)
+    }
+
+    /// Get the number of cached values.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Check if there are no cached values.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl<K: Eq + Hash, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wrap the recursive function `f` so that the value for each key is only
+/// computed once.
+///
+/// Rather than calling itself directly, `f` is given a `recurse` function as
+/// its first argument that must be used for recursive calls so they can be
+/// answered from the cache.
+///
+/// ```
+/// use ube::utils::memoize;
+///
+/// let mut fib = memoize(|recurse: &mut dyn FnMut(u64) -> u64, n: u64| match n {
+///     0 | 1 => n,
+///     _ => recurse(n - 1) + recurse(n - 2),
+/// });
+///
+/// assert_eq!(fib(80), 23416728348467685);
+/// ```
+pub fn memoize<K, V, F>(f: F) -> impl FnMut(K) -> V
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    fn call<K, V, F>(f: &F, cache: &mut HashMap<K, V>, key: K) -> V
+    where
+        K: Eq + Hash + Clone,
+        V: Clone,
+        F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    {
+        if let Some(value) = cache.get(&key) {
+            return value.clone();
+        }
+
+        let value = f(&mut |k| call(f, cache, k), key.clone());
+        cache.insert(key, value.clone());
+
+        value
+    }
+
+    let mut cache = HashMap::new();
+    move |key| call(&f, &mut cache, key)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +368,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extract_ints_from_aoc_lines() {
+        assert_eq!(extract_ints(""), Vec::<i64>::new());
+        assert_eq!(extract_ints("move 13 from 1 to 20"), vec![13, 1, 20]);
+        assert_eq!(
+            extract_ints("Sensor at x=-2, y=18: closest beacon is at x=-2, y=15"),
+            vec![-2, 18, -2, 15]
+        );
+        assert_eq!(extract_ints("a-5b--6 c7-"), vec![-5, -6, 7]);
+        assert_eq!(extract_ints("x=99999999999999999999 y=1"), vec![1]);
+    }
+
+    #[test]
+    fn extract_uints_from_aoc_lines() {
+        assert_eq!(extract_uints("no numbers here"), Vec::<u64>::new());
+        assert_eq!(extract_uints("move 13 from 1 to 20"), vec![13, 1, 20]);
+        assert_eq!(extract_uints("x=-2, y=18"), vec![2, 18]);
+        assert_eq!(extract_uints("12-80,12-81"), vec![12, 80, 12, 81]);
+    }
+
+    #[test]
+    fn sieve_marks_primes_and_composites() {
+        let sieve = sieve_of_eratosthenes(30);
+
+        for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29] {
+            assert!(sieve[p], "{p} is prime");
+        }
+
+        for c in [0, 1, 4, 6, 8, 9, 15, 21, 25, 27, 30] {
+            assert!(!sieve[c], "{c} is not prime");
+        }
+
+        assert_eq!(sieve_of_eratosthenes(0), vec![false]);
+        assert_eq!(sieve_of_eratosthenes(1), vec![false, false]);
+    }
+
+    #[test]
+    fn primes_up_to_100() {
+        let primes = primes_up_to(100);
+
+        assert_eq!(primes.len(), 25);
+        assert_eq!(primes.first(), Some(&2));
+        assert_eq!(primes.last(), Some(&97));
+        assert_eq!(primes_up_to(1), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn is_prime_small_values() {
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+        assert!(is_prime(2));
+        assert!(is_prime(3));
+        assert!(!is_prime(4));
+        assert!(is_prime(97));
+        assert!(!is_prime(91));
+    }
+
+    #[test]
+    fn is_prime_matches_sieve() {
+        let sieve = sieve_of_eratosthenes(2000);
+
+        for (n, expected) in sieve.iter().enumerate() {
+            assert_eq!(is_prime(n as u64), *expected, "{n}");
+        }
+    }
+
+    #[test]
+    fn is_prime_large_values() {
+        assert!(is_prime(1_000_000_007));
+        assert!(is_prime(2_305_843_009_213_693_951));
+        assert!(!is_prime(1_000_000_007 * 998_244_353));
+        // A strong pseudoprime to bases 2, 3, 5, 7 and 11.
+        assert!(!is_prime(2_152_302_898_747));
+        assert!(!is_prime(u64::MAX));
+    }
+
     #[test]
     fn test_combinations() {
         assert_eq!(
@@ -151,4 +483,74 @@ mod tests {
             vec![(36, -18)]
         );
     }
+
+    fn fibonacci(n: u64) -> u64 {
+        let (mut a, mut b) = (0, 1);
+
+        for _ in 0..n {
+            (a, b) = (b, a + b);
+        }
+
+        a
+    }
+
+    fn memo_fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+
+        if let Some(v) = memo.get(&n) {
+            return *v;
+        }
+
+        let v = memo_fibonacci(memo, n - 1) + memo_fibonacci(memo, n - 2);
+        *memo.get_or_insert_with(n, || v)
+    }
+
+    #[test]
+    fn memo_fibonacci_matches_direct_computation() {
+        let mut memo = Memo::new();
+
+        for n in 0..=90 {
+            assert_eq!(memo_fibonacci(&mut memo, n), fibonacci(n));
+        }
+
+        assert_eq!(memo.len(), 89);
+    }
+
+    #[test]
+    fn memo_only_computes_value_once() {
+        let mut memo: Memo<&str, usize> = Memo::default();
+        assert!(memo.is_empty());
+
+        assert_eq!(*memo.get_or_insert_with("a", || 1), 1);
+        assert_eq!(
+            *memo.get_or_insert_with("a", || unreachable!("value is cached")),
+            1
+        );
+        assert_eq!(memo.get(&"b"), None);
+    }
+
+    #[test]
+    fn memoize_fibonacci_matches_direct_computation() {
+        let calls = std::cell::Cell::new(0);
+        let mut fib = memoize(|recurse: &mut dyn FnMut(u64) -> u64, n: u64| {
+            calls.set(calls.get() + 1);
+
+            match n {
+                0 | 1 => n,
+                _ => recurse(n - 1) + recurse(n - 2),
+            }
+        });
+
+        assert_eq!(fib(90), fibonacci(90));
+        assert_eq!(calls.get(), 91);
+
+        // Every value up to 90 is cached by the first call.
+        for n in 0..=90 {
+            assert_eq!(fib(n), fibonacci(n));
+        }
+
+        assert_eq!(calls.get(), 91);
+    }
 }
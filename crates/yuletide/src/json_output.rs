@@ -0,0 +1,261 @@
+use std::{io::Write, time::Duration};
+
+use advent_of_code_data::{data::CheckResult, Answer, Part};
+use serde::Serialize;
+
+use crate::{
+    runner::{PartEvent, RunDetails, RunSummary, RunnerError, RunnerEventHandler},
+    Solver,
+};
+
+/// The outcome of running one solver part, as written by
+/// `JsonRunnerEventHandler`.
+#[derive(Debug, Serialize)]
+struct RunRecord {
+    year: usize,
+    day: usize,
+    part: u8,
+    /// The answer returned by the solver, or `None` if the solver failed.
+    answer: Option<String>,
+    /// The result of checking the answer, or `None` if the answer could not
+    /// be checked.
+    check_result: Option<&'static str>,
+    duration_ms: f64,
+    /// The error returned when running the solver part failed.
+    error: Option<String>,
+}
+
+/// Event handler that collects the result of every solver part, and writes
+/// them as a JSON array once all of the solvers have finished.
+pub struct JsonRunnerEventHandler {
+    writer: Box<dyn Write>,
+    records: Vec<RunRecord>,
+}
+
+impl JsonRunnerEventHandler {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self {
+            writer,
+            records: Vec::new(),
+        }
+    }
+
+    fn push_record(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        duration: Duration,
+        answer: Option<&Answer>,
+        check_result: Option<&CheckResult>,
+        error: Option<String>,
+    ) {
+        self.records.push(RunRecord {
+            year: solver.year.0,
+            day: solver.day.0,
+            part: part.as_number(),
+            answer: answer.map(|a| a.to_string()),
+            check_result: check_result.map(|c| match c {
+                CheckResult::Correct => "correct",
+                CheckResult::Wrong => "wrong",
+                CheckResult::TooLow { .. } => "too_low",
+                CheckResult::TooHigh { .. } => "too_high",
+            }),
+            duration_ms: duration.as_secs_f64() * 1000.0,
+            error,
+        });
+    }
+}
+
+impl RunnerEventHandler for JsonRunnerEventHandler {
+    fn on_examples_finish(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        duration: Duration,
+        pass_count: usize,
+        fail_count: usize,
+    ) {
+        // Parts with failing examples are never run against the real input, so
+        // record the failure here or the part would be missing from the output.
+        if fail_count > 0 {
+            self.push_record(
+                solver,
+                part,
+                duration,
+                None,
+                None,
+                Some(format!(
+                    "{fail_count} of {} examples failed",
+                    pass_count + fail_count
+                )),
+            );
+        }
+    }
+
+    fn on_part_finish(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        duration: Duration,
+        result: &Result<(Answer, CheckResult), RunnerError>,
+    ) {
+        match result {
+            Ok((answer, check_result)) => self.push_record(
+                solver,
+                part,
+                duration,
+                Some(answer),
+                Some(check_result),
+                None,
+            ),
+            Err(e) => self.push_record(solver, part, duration, None, None, Some(e.to_string())),
+        }
+    }
+
+    fn on_part_event(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        duration: Duration,
+        event: &PartEvent,
+    ) {
+        match event {
+            PartEvent::DryRun {
+                answer,
+                check_result,
+            } => self.push_record(
+                solver,
+                part,
+                duration,
+                Some(answer),
+                check_result.as_ref(),
+                None,
+            ),
+            PartEvent::Benchmark { answer, .. } | PartEvent::SubmitSkipped { answer } => {
+                self.push_record(solver, part, duration, Some(answer), None, None)
+            }
+        }
+    }
+
+    fn on_solver_finish(&mut self, _solver: &Solver, _duration: Duration, _details: RunDetails) {}
+
+    fn on_all_finished(&mut self, _summary: &RunSummary) {
+        let result = serde_json::to_writer_pretty(&mut self.writer, &self.records)
+            .map_err(std::io::Error::from)
+            .and_then(|_| writeln!(self.writer))
+            .and_then(|_| self.writer.flush());
+
+        if let Err(error) = result {
+            tracing::error!(?error, "failed to write JSON run results");
+        }
+
+        self.records.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::{
+        test_support::{create_solver, SharedBuffer},
+        SolverError,
+    };
+
+    use super::*;
+
+    #[test]
+    fn writes_all_records_when_finished() {
+        let buffer = SharedBuffer::default();
+        let mut handler = JsonRunnerEventHandler::new(Box::new(buffer.clone()));
+        let solver = create_solver(2024, 3);
+
+        handler.on_part_finish(
+            &solver,
+            Part::One,
+            Duration::from_millis(250),
+            &Ok((Answer::Int(42), CheckResult::Correct)),
+        );
+        handler.on_part_finish(
+            &solver,
+            Part::Two,
+            Duration::from_millis(5),
+            &Err(RunnerError::Solver(SolverError::NotFinished)),
+        );
+
+        // Nothing is written until all of the solvers have finished.
+        assert!(buffer.is_empty());
+        handler.on_all_finished(&RunSummary::default());
+
+        let output: Value = serde_json::from_slice(&buffer.bytes()).unwrap();
+        let records = output.as_array().unwrap();
+        assert_eq!(records.len(), 2);
+
+        assert_eq!(records[0]["year"].as_u64(), Some(2024));
+        assert_eq!(records[0]["day"].as_u64(), Some(3));
+        assert_eq!(records[0]["part"].as_u64(), Some(1));
+        assert_eq!(records[0]["answer"].as_str(), Some("42"));
+        assert_eq!(records[0]["check_result"].as_str(), Some("correct"));
+        assert_eq!(records[0]["duration_ms"].as_f64(), Some(250.0));
+        assert!(records[0]["error"].is_null());
+
+        assert_eq!(records[1]["part"].as_u64(), Some(2));
+        assert!(records[1]["answer"].is_null());
+        assert!(records[1]["check_result"].is_null());
+        assert_eq!(records[1]["duration_ms"].as_f64(), Some(5.0));
+        assert!(records[1]["error"].is_string());
+    }
+
+    #[test]
+    fn writes_empty_array_when_nothing_ran() {
+        let buffer = SharedBuffer::default();
+        let mut handler = JsonRunnerEventHandler::new(Box::new(buffer.clone()));
+
+        handler.on_all_finished(&RunSummary::default());
+
+        let output: Value = serde_json::from_slice(&buffer.bytes()).unwrap();
+        assert_eq!(output, Value::Array(Vec::new()));
+    }
+
+    #[test]
+    fn parts_with_failing_examples_are_recorded() {
+        let buffer = SharedBuffer::default();
+        let mut handler = JsonRunnerEventHandler::new(Box::new(buffer.clone()));
+        let solver = create_solver(2024, 3);
+
+        handler.on_examples_finish(&solver, Part::One, Duration::from_millis(2), 2, 0);
+        handler.on_examples_finish(&solver, Part::Two, Duration::from_millis(3), 1, 2);
+        handler.on_all_finished(&RunSummary::default());
+
+        let output: Value = serde_json::from_slice(&buffer.bytes()).unwrap();
+        let records = output.as_array().unwrap();
+        assert_eq!(records.len(), 1);
+
+        assert_eq!(records[0]["part"].as_u64(), Some(2));
+        assert!(records[0]["answer"].is_null());
+        assert!(records[0]["check_result"].is_null());
+        assert_eq!(records[0]["duration_ms"].as_f64(), Some(3.0));
+        assert_eq!(records[0]["error"].as_str(), Some("2 of 3 examples failed"));
+    }
+
+    #[test]
+    fn dry_run_answers_are_recorded() {
+        let buffer = SharedBuffer::default();
+        let mut handler = JsonRunnerEventHandler::new(Box::new(buffer.clone()));
+
+        handler.on_part_event(
+            &create_solver(2023, 12),
+            Part::One,
+            Duration::from_millis(1),
+            &PartEvent::DryRun {
+                answer: Answer::String("abc".to_string()),
+                check_result: Some(CheckResult::TooLow { hint: None }),
+            },
+        );
+        handler.on_all_finished(&RunSummary::default());
+
+        let output: Value = serde_json::from_slice(&buffer.bytes()).unwrap();
+        assert_eq!(output[0]["answer"].as_str(), Some("abc"));
+        assert_eq!(output[0]["check_result"].as_str(), Some("too_low"));
+    }
+}
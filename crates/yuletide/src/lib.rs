@@ -1,8 +1,17 @@
 #![doc = include_str!("../README.md")]
 
+pub mod json_output;
+mod macros;
+pub mod markdown_output;
 mod registry;
 pub mod runner;
 pub mod terminal_output;
+#[cfg(test)]
+mod test_support;
+
+use std::str::FromStr;
+
+use ube::spatial::{Grid, IteratorItemCountError};
 
 pub use registry::*;
 
@@ -11,3 +20,145 @@ pub struct SolverArgs<'a> {
     /// The puzzle input provided to the solver.
     pub input: &'a str,
 }
+
+impl<'a> SolverArgs<'a> {
+    /// Iterate the lines of the puzzle input. A trailing empty line is not
+    /// included.
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> {
+        self.input.lines()
+    }
+
+    /// Iterate the blocks of text in the puzzle input that are separated by an
+    /// empty line.
+    pub fn paragraphs(&self) -> impl Iterator<Item = &'a str> {
+        self.input
+            .trim_end_matches('\n')
+            .split("\n\n")
+            .filter(|p| !p.is_empty())
+    }
+
+    /// Get every integer in the puzzle input, in the order they appear.
+    ///
+    /// A `-` in front of a number is treated as a minus sign unless `T` cannot
+    /// hold a negative value, in which case it is treated as a separator (for
+    /// example `"2-4"` is `[2, 4]` when parsed as `usize`). Numbers that do not
+    /// fit in `T` are skipped.
+    pub fn ints<T: FromStr>(&self) -> Vec<T> {
+        let mut ints = Vec::new();
+        let mut rest = self.input;
+
+        while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+            let end = rest[start..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(rest.len(), |e| start + e);
+            let digits = &rest[start..end];
+
+            let value = if rest[..start].ends_with('-') {
+                rest[start - 1..end]
+                    .parse()
+                    .or_else(|_| digits.parse())
+                    .ok()
+            } else {
+                digits.parse().ok()
+            };
+
+            ints.extend(value);
+            rest = &rest[end..];
+        }
+
+        ints
+    }
+
+    /// Parse the puzzle input as a grid of characters, with one row per line.
+    pub fn parse_grid(&self) -> std::result::Result<Grid<char>, IteratorItemCountError> {
+        Grid::from_str(self.input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ube::spatial::Point2;
+
+    use super::*;
+
+    #[test]
+    fn lines_without_trailing_empty_line() {
+        let args = SolverArgs {
+            input: "1abc2\npqr3stu8vwx\n\na1b2c3d4e5f\n",
+        };
+
+        assert_eq!(
+            args.lines().collect::<Vec<_>>(),
+            vec!["1abc2", "pqr3stu8vwx", "", "a1b2c3d4e5f"]
+        );
+        assert_eq!(SolverArgs { input: "" }.lines().count(), 0);
+    }
+
+    #[test]
+    fn paragraphs_split_on_empty_lines() {
+        let args = SolverArgs {
+            input: "1000\n2000\n3000\n\n4000\n\n5000\n6000\n",
+        };
+
+        assert_eq!(
+            args.paragraphs().collect::<Vec<_>>(),
+            vec!["1000\n2000\n3000", "4000", "5000\n6000"]
+        );
+        assert_eq!(SolverArgs { input: "" }.paragraphs().count(), 0);
+    }
+
+    #[test]
+    fn ints_with_signs() {
+        let args = SolverArgs {
+            input: "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15\n",
+        };
+
+        assert_eq!(args.ints::<i64>(), vec![2, -18, -2, 15]);
+    }
+
+    #[test]
+    fn ints_treat_dash_as_separator_for_unsigned() {
+        let args = SolverArgs {
+            input: "2-4,6-8\n2-3,4-5",
+        };
+
+        assert_eq!(args.ints::<usize>(), vec![2, 4, 6, 8, 2, 3, 4, 5]);
+        assert_eq!(args.ints::<i32>(), vec![2, -4, 6, -8, 2, -3, 4, -5]);
+    }
+
+    #[test]
+    fn ints_skip_values_that_do_not_fit() {
+        let args = SolverArgs {
+            input: "move 300 from 1 to 9",
+        };
+
+        assert_eq!(args.ints::<u8>(), vec![1, 9]);
+        assert_eq!(args.ints::<u32>(), vec![300, 1, 9]);
+        assert!(SolverArgs {
+            input: "no numbers"
+        }
+        .ints::<u32>()
+        .is_empty());
+    }
+
+    #[test]
+    fn parse_grid_of_chars() {
+        let args = SolverArgs {
+            input: "#.#\n..#\n",
+        };
+        let grid = args.parse_grid().unwrap();
+
+        assert_eq!(grid.x_count(), 3);
+        assert_eq!(grid.y_count(), 2);
+        assert_eq!(grid[Point2::new(0, 0)], '#');
+        assert_eq!(grid[Point2::new(1, 0)], '.');
+        assert_eq!(grid[Point2::new(2, 1)], '#');
+    }
+
+    #[test]
+    fn parse_grid_with_uneven_rows() {
+        let args = SolverArgs { input: "#.#\n..\n" };
+
+        assert!(args.parse_grid().is_err());
+    }
+}
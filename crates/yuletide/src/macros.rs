@@ -0,0 +1,129 @@
+/// Run a solver part against one of its built-in examples, and assert that the
+/// solver returns the example's expected answer.
+///
+/// `$solver_part` is a `SolverPart` such as `SOLVER.part_one`, and
+/// `$example_index` is the index of the example in the part's `examples`.
+///
+/// ```
+/// use advent_of_code_data::Answer;
+/// use yuletide::{assert_solver_example, Example, SolverArgs, SolverPart};
+///
+/// fn count_lines(args: &SolverArgs) -> yuletide::Result<Answer> {
+///     Ok(args.lines().count().into())
+/// }
+///
+/// let part = SolverPart {
+///     func: count_lines,
+///     examples: &[Example {
+///         input: "a\nb\nc",
+///         expected: Answer::Int(3),
+///     }],
+/// };
+///
+/// assert_solver_example!(part, 0);
+/// ```
+#[macro_export]
+macro_rules! assert_solver_example {
+    ($solver_part:expr, $example_index:expr) => {{
+        let solver_part: &$crate::SolverPart = &$solver_part;
+        let example_index: usize = $example_index;
+        let example = solver_part.example(example_index);
+
+        match (solver_part.func)(&$crate::SolverArgs {
+            input: example.input,
+        }) {
+            Ok(answer) => assert_eq!(
+                answer, example.expected,
+                "example {} returned the wrong answer",
+                example_index
+            ),
+            Err(error) => panic!("example {} returned an error: {}", example_index, error),
+        }
+    }};
+}
+
+/// Run a solver part against all of its built-in examples, and assert that the
+/// solver returns the expected answer for each one.
+///
+/// `$solver_part` is a `SolverPart` such as `SOLVER.part_two`.
+#[macro_export]
+macro_rules! assert_all_examples {
+    ($solver_part:expr) => {{
+        let solver_part: &$crate::SolverPart = &$solver_part;
+
+        for example_index in 0..solver_part.examples.len() {
+            $crate::assert_solver_example!(solver_part, example_index);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code_data::Answer;
+
+    use crate::{Example, SolverArgs, SolverError, SolverPart};
+
+    fn sum_ints(args: &SolverArgs) -> crate::Result<Answer> {
+        Ok(args.ints::<i64>().into_iter().sum::<i64>().into())
+    }
+
+    fn unfinished(_args: &SolverArgs) -> crate::Result<Answer> {
+        Err(SolverError::NotFinished)
+    }
+
+    const SUM_PART: SolverPart = SolverPart {
+        func: sum_ints,
+        examples: &[
+            Example {
+                input: "1 2 3",
+                expected: Answer::Int(6),
+            },
+            Example {
+                input: "10\n-4",
+                expected: Answer::Int(6),
+            },
+            Example {
+                input: "7",
+                expected: Answer::Int(8),
+            },
+        ],
+    };
+
+    #[test]
+    fn example_passes() {
+        assert_solver_example!(SUM_PART, 0);
+        assert_solver_example!(SUM_PART, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "example 2 returned the wrong answer")]
+    fn example_with_wrong_answer_fails() {
+        assert_solver_example!(SUM_PART, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "example 0 returned an error: this solver is not finished")]
+    fn example_with_error_fails() {
+        assert_solver_example!(
+            SolverPart {
+                func: unfinished,
+                examples: SUM_PART.examples,
+            },
+            0
+        );
+    }
+
+    #[test]
+    fn all_examples_pass() {
+        assert_all_examples!(SolverPart {
+            func: sum_ints,
+            examples: &SUM_PART.examples[..2],
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "example 2 returned the wrong answer")]
+    fn all_examples_reports_failing_example() {
+        assert_all_examples!(SUM_PART);
+    }
+}
@@ -0,0 +1,317 @@
+use std::{io::Write, time::Duration};
+
+use advent_of_code_data::{data::CheckResult, Answer, Part};
+
+use crate::{
+    runner::{PartEvent, RunDetails, RunSummary, RunnerError, RunnerEventHandler},
+    Solver, SolverError,
+};
+
+const STATUS_CORRECT: &str = "✅";
+const STATUS_WRONG: &str = "❌";
+const STATUS_SKIPPED: &str = "⏭";
+
+/// The outcome of running one solver part, as written by
+/// `MarkdownRunnerEventHandler`.
+#[derive(Debug)]
+struct TableRow {
+    year: usize,
+    day: usize,
+    part: u8,
+    /// The answer returned by the solver, or `None` if the solver failed.
+    answer: Option<String>,
+    status: &'static str,
+    duration_ms: f64,
+}
+
+/// Event handler that collects the result of every solver part, and writes
+/// them as a GitHub flavored Markdown table once all of the solvers have
+/// finished.
+///
+/// Rows are sorted by year, day and part. The status column is ✅ for correct
+/// answers, ❌ for wrong answers or solver errors, and ⏭ for parts that were
+/// skipped or whose answer was not checked or submitted.
+pub struct MarkdownRunnerEventHandler {
+    writer: Box<dyn Write>,
+    rows: Vec<TableRow>,
+}
+
+impl MarkdownRunnerEventHandler {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self {
+            writer,
+            rows: Vec::new(),
+        }
+    }
+
+    fn push_row(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        duration: Duration,
+        answer: Option<&Answer>,
+        status: &'static str,
+    ) {
+        self.rows.push(TableRow {
+            year: solver.year.0,
+            day: solver.day.0,
+            part: part.as_number(),
+            answer: answer.map(|a| a.to_string()),
+            status,
+            duration_ms: duration.as_secs_f64() * 1000.0,
+        });
+    }
+
+    fn write_table(&mut self) -> std::io::Result<()> {
+        self.rows.sort_by_key(|r| (r.year, r.day, r.part));
+
+        writeln!(
+            self.writer,
+            "| Year | Day | Part | Answer | Status | Duration (ms) |"
+        )?;
+        writeln!(
+            self.writer,
+            "| ---: | --: | ---: | ------ | :----: | ------------: |"
+        )?;
+
+        for row in &self.rows {
+            writeln!(
+                self.writer,
+                "| {} | {} | {} | {} | {} | {:.2} |",
+                row.year,
+                row.day,
+                row.part,
+                row.answer
+                    .as_deref()
+                    .map(escape_markdown)
+                    .unwrap_or_default(),
+                row.status,
+                row.duration_ms
+            )?;
+        }
+
+        self.writer.flush()
+    }
+}
+
+/// Get the status column value for the result of checking an answer.
+fn check_result_status(check_result: &CheckResult) -> &'static str {
+    match check_result {
+        CheckResult::Correct => STATUS_CORRECT,
+        CheckResult::Wrong | CheckResult::TooLow { .. } | CheckResult::TooHigh { .. } => {
+            STATUS_WRONG
+        }
+    }
+}
+
+/// Escape characters in `text` that would otherwise be treated as Markdown
+/// formatting or break the table's pipe syntax. Line breaks are replaced with
+/// `<br>` so multi-line answers stay in a single table row.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.replace("\r\n", "\n").chars() {
+        if matches!(c, '\n' | '\r') {
+            escaped.push_str("<br>");
+            continue;
+        }
+
+        if matches!(
+            c,
+            '\\' | '|' | '*' | '_' | '`' | '[' | ']' | '<' | '>' | '#' | '~'
+        ) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+impl RunnerEventHandler for MarkdownRunnerEventHandler {
+    fn on_examples_finish(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        duration: Duration,
+        _pass_count: usize,
+        fail_count: usize,
+    ) {
+        // Parts with failing examples are never run against the real input, so
+        // add their row here or the part would be missing from the table.
+        if fail_count > 0 {
+            self.push_row(solver, part, duration, None, STATUS_WRONG);
+        }
+    }
+
+    fn on_part_finish(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        duration: Duration,
+        result: &Result<(Answer, CheckResult), RunnerError>,
+    ) {
+        match result {
+            Ok((answer, check_result)) => self.push_row(
+                solver,
+                part,
+                duration,
+                Some(answer),
+                check_result_status(check_result),
+            ),
+            Err(RunnerError::Solver(SolverError::NotFinished)) => {
+                self.push_row(solver, part, duration, None, STATUS_SKIPPED)
+            }
+            Err(_) => self.push_row(solver, part, duration, None, STATUS_WRONG),
+        }
+    }
+
+    fn on_part_event(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        duration: Duration,
+        event: &PartEvent,
+    ) {
+        match event {
+            PartEvent::DryRun {
+                answer,
+                check_result,
+            } => self.push_row(
+                solver,
+                part,
+                duration,
+                Some(answer),
+                check_result
+                    .as_ref()
+                    .map_or(STATUS_SKIPPED, check_result_status),
+            ),
+            PartEvent::Benchmark { answer, .. } | PartEvent::SubmitSkipped { answer } => {
+                self.push_row(solver, part, duration, Some(answer), STATUS_SKIPPED)
+            }
+        }
+    }
+
+    fn on_solver_finish(&mut self, _solver: &Solver, _duration: Duration, _details: RunDetails) {}
+
+    fn on_all_finished(&mut self, _summary: &RunSummary) {
+        if let Err(error) = self.write_table() {
+            tracing::error!(?error, "failed to write Markdown run results");
+        }
+
+        self.rows.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{create_solver, SharedBuffer};
+
+    use super::*;
+
+    #[test]
+    fn writes_sorted_table_when_finished() {
+        let buffer = SharedBuffer::default();
+        let mut handler = MarkdownRunnerEventHandler::new(Box::new(buffer.clone()));
+
+        handler.on_part_finish(
+            &create_solver(2024, 3),
+            Part::Two,
+            Duration::from_millis(5),
+            &Err(RunnerError::Solver(SolverError::NotFinished)),
+        );
+        handler.on_part_finish(
+            &create_solver(2024, 3),
+            Part::One,
+            Duration::from_micros(1500),
+            &Ok((Answer::Int(42), CheckResult::Correct)),
+        );
+        handler.on_part_finish(
+            &create_solver(2023, 12),
+            Part::One,
+            Duration::from_millis(250),
+            &Ok((Answer::Int(7), CheckResult::TooLow { hint: None })),
+        );
+
+        // Nothing is written until all of the solvers have finished.
+        assert!(buffer.is_empty());
+        handler.on_all_finished(&RunSummary::default());
+
+        assert_eq!(
+            buffer.lines(),
+            vec![
+                "| Year | Day | Part | Answer | Status | Duration (ms) |",
+                "| ---: | --: | ---: | ------ | :----: | ------------: |",
+                "| 2023 | 12 | 1 | 7 | ❌ | 250.00 |",
+                "| 2024 | 3 | 1 | 42 | ✅ | 1.50 |",
+                "| 2024 | 3 | 2 |  | ⏭ | 5.00 |",
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_only_header_when_nothing_ran() {
+        let buffer = SharedBuffer::default();
+        let mut handler = MarkdownRunnerEventHandler::new(Box::new(buffer.clone()));
+
+        handler.on_all_finished(&RunSummary::default());
+
+        assert_eq!(buffer.lines().len(), 2);
+    }
+
+    #[test]
+    fn special_characters_in_answers_are_escaped() {
+        let buffer = SharedBuffer::default();
+        let mut handler = MarkdownRunnerEventHandler::new(Box::new(buffer.clone()));
+
+        handler.on_part_event(
+            &create_solver(2022, 10),
+            Part::Two,
+            Duration::from_millis(1),
+            &PartEvent::DryRun {
+                answer: Answer::String("a|b*c_d".to_string()),
+                check_result: None,
+            },
+        );
+        handler.on_part_event(
+            &create_solver(2022, 11),
+            Part::One,
+            Duration::from_millis(1),
+            &PartEvent::DryRun {
+                answer: Answer::String("#.#\n.#.\r\n#<#".to_string()),
+                check_result: None,
+            },
+        );
+        handler.on_all_finished(&RunSummary::default());
+
+        assert_eq!(
+            buffer.lines()[2],
+            r"| 2022 | 10 | 2 | a\|b\*c\_d | ⏭ | 1.00 |"
+        );
+        assert_eq!(
+            buffer.lines()[3],
+            r"| 2022 | 11 | 1 | \#.\#<br>.\#.<br>\#\<\# | ⏭ | 1.00 |"
+        );
+    }
+
+    #[test]
+    fn parts_with_failing_examples_are_written_as_wrong() {
+        let buffer = SharedBuffer::default();
+        let mut handler = MarkdownRunnerEventHandler::new(Box::new(buffer.clone()));
+        let solver = create_solver(2024, 3);
+
+        handler.on_examples_finish(&solver, Part::One, Duration::from_millis(2), 2, 0);
+        handler.on_examples_finish(&solver, Part::Two, Duration::from_millis(3), 1, 2);
+        handler.on_all_finished(&RunSummary::default());
+
+        assert_eq!(
+            buffer.lines(),
+            vec![
+                "| Year | Day | Part | Answer | Status | Duration (ms) |",
+                "| ---: | --: | ---: | ------ | :----: | ------------: |",
+                "| 2024 | 3 | 2 |  | ❌ | 3.00 |",
+            ]
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use regex::Regex;
+use serde::Deserialize;
 use thiserror::Error;
 
 use advent_of_code_data::{Answer, Day, Part, Year};
@@ -14,12 +15,27 @@ pub enum SolverError {
     NotFinished,
     #[error("the answer was submitted too soon after an incorrect answer, please wait before trying again")]
     TooSoon,
+    #[error("solver timed out after {:.2}s", .0.as_secs_f64())]
+    Timeout(std::time::Duration),
     #[error(transparent)]
     Io(#[from] std::io::Error),
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
 
+/// Represents an error that can happen when creating a `SolverRegistry`.
+#[derive(Error, Debug)]
+pub enum RegistryError {
+    #[error("failed to parse solver list: {}", .0)]
+    Toml(#[from] toml::de::Error),
+    #[error("{} is not an Advent of Code day (expected 1-25)", .0)]
+    InvalidDay(usize),
+    #[error("{} is not an Advent of Code year (expected {} or later)", .0, Year::FIRST)]
+    InvalidYear(usize),
+    #[error("the solver list has more than one entry for year {} day {}", .0, .1)]
+    DuplicateSolver(Year, Day),
+}
+
 /// The result of running an Advent of Code solver.
 pub type Result<T> = core::result::Result<T, SolverError>;
 
@@ -37,6 +53,17 @@ pub struct SolverAutoRegister {
     ///
     /// Example: `my_aoc_solutions::y2024::day15`
     pub modpath: &'static str,
+    /// An optional label used to tell apart alternative solvers registered for
+    /// the same puzzle day (e.g., `"brute force"` and `"optimized"`).
+    pub name: Option<&'static str>,
+    /// The puzzle title (e.g., `"Historian Hysteria"`).
+    pub title: Option<&'static str>,
+    /// A short description of the puzzle or the approach taken by the solver.
+    pub description: Option<&'static str>,
+    /// Never submit answers from this solver to the Advent of Code service.
+    /// Answers are computed and reported, but not checked. Use this for
+    /// solvers that are non-deterministic or too slow to verify.
+    pub skip_submit: bool,
     /// A function that solves part one of the puzzle.
     pub part_one: SolverPart,
     /// A function that solves part two of the puzzle.
@@ -51,6 +78,20 @@ pub struct Solver {
     pub day: Day,
     /// Advent of Code puzzle year.
     pub year: Year,
+    /// An optional label distinguishing this solver from other solvers for the
+    /// same puzzle.
+    pub name: Option<&'static str>,
+    /// The puzzle title, if one was provided when registering the solver.
+    pub title: Option<&'static str>,
+    /// A short description of the puzzle or solver, if one was provided when
+    /// registering the solver.
+    pub description: Option<&'static str>,
+    /// True if the runner should never submit answers from this solver.
+    pub skip_submit: bool,
+    /// Answers for part one and part two that are returned instead of calling
+    /// the part functions. Only set for solver stubs created with
+    /// `SolverRegistry::from_toml`.
+    pub stored_answers: [Option<Answer>; 2],
     /// A function that solves part one.
     pub part_one: SolverPart,
     /// A function that solves part two.
@@ -65,6 +106,19 @@ impl Solver {
             Part::Two => &self.part_two,
         }
     }
+
+    /// Get the stored answer for the requested puzzle part, if this solver is
+    /// a stub created with `SolverRegistry::from_toml`.
+    pub fn stored_answer(&self, part: Part) -> Option<&Answer> {
+        self.stored_answers[part.index()].as_ref()
+    }
+
+    /// Returns true if this solver is a stub created with
+    /// `SolverRegistry::from_toml` that has at least one stored answer. Stubs
+    /// never read their puzzle input.
+    pub fn is_stub(&self) -> bool {
+        self.stored_answers.iter().any(Option::is_some)
+    }
 }
 
 /// Holds the function that solves for an Advent of Code puzzle part, and any
@@ -93,16 +147,19 @@ pub struct Example {
 }
 
 /// Holds a collection of puzzle solvers that can be looked up by year and day.
+///
+/// More than one solver can be registered for the same year and day. The
+/// solvers for a day are kept in the order they were registered, and the first
+/// registered solver is treated as the default.
 pub struct SolverRegistry {
-    solvers: HashMap<Year, HashMap<Day, Solver>>,
+    solvers: HashMap<Year, HashMap<Day, Vec<Solver>>>,
 }
 
 impl SolverRegistry {
     /// Create a `SolverRegistry` from a set of partial solver registration
     /// entries.
     pub fn compiled_from(all_solvers: &[SolverAutoRegister]) -> Self {
-        // TODO: Support multiple solvers for the same year + day (e.g., alternative solutions).
-        let mut solvers: HashMap<Year, HashMap<Day, Solver>> = Default::default();
+        let mut solvers: HashMap<Year, HashMap<Day, Vec<Solver>>> = Default::default();
         let re = Regex::new(r"::y(?<year>\d{4,4})::day(?<day>(\d+))$").unwrap();
 
         for registration in all_solvers.iter() {
@@ -130,23 +187,116 @@ impl SolverRegistry {
             let solver = Solver {
                 year,
                 day,
+                name: registration.name,
+                title: registration.title,
+                description: registration.description,
+                skip_submit: registration.skip_submit,
+                stored_answers: [None, None],
                 part_one: registration.part_one.clone(),
                 part_two: registration.part_two.clone(),
             };
 
-            match solvers.get_mut(&year) {
-                Some(solvers_for_year) => {
-                    solvers_for_year.insert(day, solver);
-                }
-                None => {
-                    solvers.insert(year, HashMap::from([(day, solver)]));
-                }
-            }
+            solvers
+                .entry(year)
+                .or_default()
+                .entry(day)
+                .or_default()
+                .push(solver);
         }
 
         Self { solvers }
     }
 
+    /// Create a `SolverRegistry` of solver stubs from a TOML list of solved
+    /// puzzles and their answers, for checking answers without compiling a
+    /// solver for each puzzle.
+    ///
+    /// Each `[[solver]]` table has a `year`, a `day`, and optional
+    /// `part_one_answer` and `part_two_answer` strings. Running a stub returns
+    /// the stored answer, and a part without an answer is reported as not
+    /// finished. An error is returned if an entry has an invalid year or day,
+    /// or if there is more than one entry for the same puzzle.
+    ///
+    /// ```
+    /// use advent_of_code_data::{Answer, Day, Part, Year};
+    /// use yuletide::SolverRegistry;
+    ///
+    /// let registry = SolverRegistry::from_toml(
+    ///     r#"
+    ///     [[solver]]
+    ///     year = 2024
+    ///     day = 1
+    ///     part_one_answer = "12345"
+    ///     "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let solver = registry.solver(Year(2024), Day(1)).unwrap();
+    /// assert_eq!(solver.stored_answer(Part::One), Some(&Answer::Int(12345)));
+    /// ```
+    pub fn from_toml(toml_text: &str) -> std::result::Result<Self, RegistryError> {
+        #[derive(Deserialize)]
+        struct SolverList {
+            #[serde(default)]
+            solver: Vec<SolverEntry>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct SolverEntry {
+            year: usize,
+            day: usize,
+            part_one_answer: Option<String>,
+            part_two_answer: Option<String>,
+        }
+
+        fn stored_answer_part(_args: &SolverArgs) -> Result<Answer> {
+            Err(SolverError::NotFinished)
+        }
+
+        let stub_part = SolverPart {
+            func: stored_answer_part,
+            examples: &[],
+        };
+
+        let mut solvers: HashMap<Year, HashMap<Day, Vec<Solver>>> = Default::default();
+
+        for entry in toml::from_str::<SolverList>(toml_text)?.solver {
+            let year = Year::checked(entry.year).ok_or(RegistryError::InvalidYear(entry.year))?;
+            let day = Day::checked(entry.day).ok_or(RegistryError::InvalidDay(entry.day))?;
+
+            let solvers_for_day = solvers.entry(year).or_default().entry(day).or_default();
+
+            if !solvers_for_day.is_empty() {
+                return Err(RegistryError::DuplicateSolver(year, day));
+            }
+
+            let to_answer = |text: Option<String>| {
+                text.map(|t| {
+                    t.parse::<Answer>()
+                        .expect("Answer::from_str does not return Err")
+                })
+            };
+
+            solvers_for_day.push(Solver {
+                year,
+                day,
+                name: None,
+                title: None,
+                description: None,
+                skip_submit: false,
+                stored_answers: [
+                    to_answer(entry.part_one_answer),
+                    to_answer(entry.part_two_answer),
+                ],
+                part_one: stub_part.clone(),
+                part_two: stub_part.clone(),
+            });
+        }
+
+        Ok(Self { solvers })
+    }
+
     /// Get a list of the years for which there is a `Solver` in the registry.
     pub fn years(&self) -> Vec<Year> {
         let mut y: Vec<_> = self.solvers.keys().cloned().collect();
@@ -157,16 +307,72 @@ impl SolverRegistry {
     /// Get a list of the days for the provided `year` for which there is a
     /// `Solver` in the registry.
     pub fn days(&self, year: Year) -> Option<Vec<Day>> {
-        let mut d: Vec<_> = self.solvers.get(&year)?.values().map(|s| s.day).collect();
+        let mut d: Vec<_> = self.solvers.get(&year)?.keys().cloned().collect();
         d.sort();
         Some(d)
     }
 
-    /// Get the solver for the requested `day` and `year`.
+    /// Get the default (first registered) solver for the requested `day` and
+    /// `year`.
     pub fn solver(&self, year: Year, day: Day) -> Option<&Solver> {
+        self.solvers(year, day).and_then(|solvers| solvers.first())
+    }
+
+    /// Get all of the solvers registered for the requested `day` and `year` in
+    /// the order they were registered.
+    pub fn solvers(&self, year: Year, day: Day) -> Option<&[Solver]> {
         self.solvers
             .get(&year)
             .and_then(|solvers_for_year| solvers_for_year.get(&day))
+            .map(|solvers| solvers.as_slice())
+    }
+
+    /// Get an iterator over every `Solver` in the registry in ascending
+    /// `(Year, Day)` order.
+    pub fn all_solvers(&self) -> impl Iterator<Item = &Solver> {
+        let mut all: Vec<&Solver> = self
+            .solvers
+            .values()
+            .flat_map(|solvers_for_year| solvers_for_year.values().flatten())
+            .collect();
+        all.sort_by_key(|s| (s.year, s.day)); // Stable sort keeps registration order.
+        all.into_iter()
+    }
+
+    /// Get the total number of solvers in the registry across all years.
+    pub fn solver_count(&self) -> usize {
+        self.solvers
+            .values()
+            .flat_map(|solvers_for_year| solvers_for_year.values())
+            .map(|s| s.len())
+            .sum()
+    }
+
+    /// Check if the registry has a solver for the requested `day` and `year`.
+    pub fn has_solver(&self, year: Year, day: Day) -> bool {
+        self.solver(year, day).is_some()
+    }
+
+    /// Create a new registry holding a copy of every solver whose `year` and
+    /// `day` match `pred`. Solvers for the same day keep their registration
+    /// order.
+    pub fn filter(&self, pred: impl Fn(Year, Day) -> bool) -> SolverRegistry {
+        let solvers = self
+            .solvers
+            .iter()
+            .map(|(year, solvers_for_year)| {
+                let days: HashMap<Day, Vec<Solver>> = solvers_for_year
+                    .iter()
+                    .filter(|(day, _)| pred(*year, **day))
+                    .map(|(day, solvers)| (*day, solvers.clone()))
+                    .collect();
+
+                (*year, days)
+            })
+            .filter(|(_, days)| !days.is_empty())
+            .collect();
+
+        Self { solvers }
     }
 }
 
@@ -181,6 +387,10 @@ mod tests {
     fn create_solver(modpath: &'static str) -> SolverAutoRegister {
         SolverAutoRegister {
             modpath,
+            name: None,
+            title: None,
+            description: None,
+            skip_submit: false,
             part_one: SolverPart {
                 func: test_part,
                 examples: &[],
@@ -273,4 +483,296 @@ mod tests {
         );
         assert_eq!(registry.solver(Year(2025), Day(2)).unwrap().day, Day(2));
     }
+
+    #[test]
+    fn all_solvers_in_year_and_day_order() {
+        let registry = SolverRegistry::compiled_from(&[
+            create_solver("testcrate::y2025::day2"),
+            create_solver("testcrate::y2024::day10"),
+            create_solver("testcrate::y1999::day19"),
+            create_solver("testcrate::y2024::day5"),
+            create_solver("testcrate::y2024::day1"),
+        ]);
+
+        assert_eq!(
+            registry
+                .all_solvers()
+                .map(|s| (s.year, s.day))
+                .collect::<Vec<_>>(),
+            vec![
+                (Year(1999), Day(19)),
+                (Year(2024), Day(1)),
+                (Year(2024), Day(5)),
+                (Year(2024), Day(10)),
+                (Year(2025), Day(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn multiple_solvers_for_same_day_are_kept() {
+        let registry = SolverRegistry::compiled_from(&[
+            SolverAutoRegister {
+                name: Some("brute force"),
+                ..create_solver("testcrate::y2024::day1")
+            },
+            create_solver("testcrate::y2024::day5"),
+            SolverAutoRegister {
+                name: Some("optimized"),
+                ..create_solver("testcrate::y2024::day1")
+            },
+        ]);
+
+        let solvers = registry.solvers(Year(2024), Day(1)).unwrap();
+        assert_eq!(solvers.len(), 2);
+        assert_eq!(solvers[0].name, Some("brute force"));
+        assert_eq!(solvers[1].name, Some("optimized"));
+
+        assert_eq!(
+            registry.solver(Year(2024), Day(1)).unwrap().name,
+            Some("brute force")
+        );
+        assert_eq!(registry.solvers(Year(2024), Day(5)).unwrap().len(), 1);
+        assert!(registry.solvers(Year(2024), Day(2)).is_none());
+
+        assert_eq!(registry.days(Year(2024)), Some(vec![Day(1), Day(5)]));
+        assert_eq!(registry.solver_count(), 3);
+        assert_eq!(
+            registry
+                .all_solvers()
+                .map(|s| (s.day, s.name))
+                .collect::<Vec<_>>(),
+            vec![
+                (Day(1), Some("brute force")),
+                (Day(1), Some("optimized")),
+                (Day(5), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn title_and_description_are_none_by_default() {
+        let registry = SolverRegistry::compiled_from(&[create_solver("testcrate::y2024::day1")]);
+        let solver = registry.solver(Year(2024), Day(1)).unwrap();
+
+        assert!(solver.title.is_none());
+        assert!(solver.description.is_none());
+    }
+
+    #[test]
+    fn title_and_description_are_preserved() {
+        let registry = SolverRegistry::compiled_from(&[SolverAutoRegister {
+            title: Some("Historian Hysteria"),
+            description: Some("Compare two lists of location ids"),
+            ..create_solver("testcrate::y2024::day1")
+        }]);
+        let solver = registry.solver(Year(2024), Day(1)).unwrap();
+
+        assert_eq!(solver.title, Some("Historian Hysteria"));
+        assert_eq!(
+            solver.description,
+            Some("Compare two lists of location ids")
+        );
+    }
+
+    #[test]
+    fn all_solvers_empty_if_no_solvers() {
+        let registry = SolverRegistry::compiled_from(&[]);
+        assert_eq!(registry.all_solvers().count(), 0);
+        assert_eq!(registry.solver_count(), 0);
+    }
+
+    #[test]
+    fn solver_count_is_total_across_years() {
+        let registry = SolverRegistry::compiled_from(&[
+            create_solver("testcrate::y2024::day1"),
+            create_solver("testcrate::y2024::day5"),
+            create_solver("testcrate::y2025::day2"),
+        ]);
+
+        assert_eq!(registry.solver_count(), 3);
+    }
+
+    #[test]
+    fn has_solver_for_present_and_absent_entries() {
+        let registry = SolverRegistry::compiled_from(&[
+            create_solver("testcrate::y2024::day1"),
+            create_solver("testcrate::y2025::day2"),
+        ]);
+
+        assert!(registry.has_solver(Year(2024), Day(1)));
+        assert!(registry.has_solver(Year(2025), Day(2)));
+        assert!(!registry.has_solver(Year(2024), Day(2)));
+        assert!(!registry.has_solver(Year(2023), Day(1)));
+    }
+
+    #[test]
+    fn timeout_error_display_includes_duration() {
+        assert_eq!(
+            SolverError::Timeout(std::time::Duration::from_millis(2500)).to_string(),
+            "solver timed out after 2.50s"
+        );
+    }
+
+    fn create_filter_registry() -> SolverRegistry {
+        SolverRegistry::compiled_from(&[
+            create_solver("testcrate::y2023::day1"),
+            create_solver("testcrate::y2023::day8"),
+            create_solver("testcrate::y2024::day1"),
+            create_solver("testcrate::y2024::day3"),
+            create_solver("testcrate::y2024::day6"),
+            create_solver("testcrate::y2024::day3"),
+        ])
+    }
+
+    #[test]
+    fn filter_by_year() {
+        let registry = create_filter_registry().filter(|y, _| y == Year(2024));
+
+        assert_eq!(registry.years(), vec![Year(2024)]);
+        assert_eq!(registry.solver_count(), 4);
+        assert!(registry.all_solvers().all(|s| s.year == Year(2024)));
+        assert_eq!(registry.solvers(Year(2024), Day(3)).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn filter_by_day() {
+        let registry = create_filter_registry().filter(|_, d| (1..=5).contains(&d.0));
+
+        assert_eq!(
+            registry
+                .all_solvers()
+                .map(|s| (s.year, s.day))
+                .collect::<Vec<_>>(),
+            vec![
+                (Year(2023), Day(1)),
+                (Year(2024), Day(1)),
+                (Year(2024), Day(3)),
+                (Year(2024), Day(3)),
+            ]
+        );
+        assert_eq!(registry.days(Year(2023)), Some(vec![Day(1)]));
+    }
+
+    #[test]
+    fn filter_matching_nothing_is_empty() {
+        let original = create_filter_registry();
+        let registry = original.filter(|_, _| false);
+
+        assert!(registry.years().is_empty());
+        assert_eq!(registry.solver_count(), 0);
+        assert_eq!(registry.all_solvers().count(), 0);
+
+        // The original registry is unchanged.
+        assert_eq!(original.solver_count(), 6);
+    }
+
+    #[test]
+    fn from_toml_creates_solver_stubs() {
+        let registry = SolverRegistry::from_toml(
+            r#"
+            [[solver]]
+            year = 2024
+            day = 1
+            part_one_answer = "12345"
+            part_two_answer = "abc"
+
+            [[solver]]
+            year = 2024
+            day = 7
+            part_one_answer = "-3"
+
+            [[solver]]
+            year = 2023
+            day = 25
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(registry.years(), vec![Year(2023), Year(2024)]);
+        assert_eq!(registry.days(Year(2023)), Some(vec![Day(25)]));
+        assert_eq!(registry.days(Year(2024)), Some(vec![Day(1), Day(7)]));
+        assert_eq!(registry.solver_count(), 3);
+
+        let solver = registry.solver(Year(2024), Day(1)).unwrap();
+        assert_eq!(solver.stored_answer(Part::One), Some(&Answer::Int(12345)));
+        assert_eq!(
+            solver.stored_answer(Part::Two),
+            Some(&Answer::String("abc".to_string()))
+        );
+
+        let solver = registry.solver(Year(2024), Day(7)).unwrap();
+        assert_eq!(solver.stored_answer(Part::One), Some(&Answer::Int(-3)));
+        assert_eq!(solver.stored_answer(Part::Two), None);
+
+        let solver = registry.solver(Year(2023), Day(25)).unwrap();
+        assert_eq!(solver.stored_answer(Part::One), None);
+        assert!(matches!(
+            (solver.part_two.func)(&SolverArgs { input: "" }),
+            Err(SolverError::NotFinished)
+        ));
+    }
+
+    #[test]
+    fn from_toml_empty_text_has_no_solvers() {
+        let registry = SolverRegistry::from_toml("").unwrap();
+        assert_eq!(registry.solver_count(), 0);
+    }
+
+    #[test]
+    fn from_toml_rejects_invalid_entries() {
+        // Missing the required `day` key.
+        assert!(matches!(
+            SolverRegistry::from_toml("[[solver]]\nyear = 2024\n"),
+            Err(RegistryError::Toml(_))
+        ));
+
+        // Misspelled answer key.
+        assert!(matches!(
+            SolverRegistry::from_toml("[[solver]]\nyear = 2024\nday = 1\npart_1_answer = \"5\"\n"),
+            Err(RegistryError::Toml(_))
+        ));
+    }
+
+    #[test]
+    fn from_toml_rejects_invalid_days_and_years() {
+        assert!(matches!(
+            SolverRegistry::from_toml("[[solver]]\nyear = 2024\nday = 0\n"),
+            Err(RegistryError::InvalidDay(0))
+        ));
+        assert!(matches!(
+            SolverRegistry::from_toml("[[solver]]\nyear = 2024\nday = 99\n"),
+            Err(RegistryError::InvalidDay(99))
+        ));
+        assert!(matches!(
+            SolverRegistry::from_toml("[[solver]]\nyear = 1999\nday = 1\n"),
+            Err(RegistryError::InvalidYear(1999))
+        ));
+    }
+
+    #[test]
+    fn from_toml_rejects_duplicate_puzzles() {
+        let result = SolverRegistry::from_toml(
+            r#"
+            [[solver]]
+            year = 2024
+            day = 3
+            part_one_answer = "1"
+
+            [[solver]]
+            year = 2023
+            day = 3
+
+            [[solver]]
+            year = 2024
+            day = 3
+            part_two_answer = "2"
+            "#,
+        );
+
+        assert!(matches!(
+            result,
+            Err(RegistryError::DuplicateSolver(Year(2024), Day(3)))
+        ));
+    }
 }
@@ -1,5 +1,10 @@
-use std::time::{Duration, Instant};
+use std::{
+    collections::VecDeque,
+    sync::mpsc,
+    time::{Duration, Instant},
+};
 
+use rayon::prelude::*;
 use thiserror::Error;
 
 use advent_of_code_data::{
@@ -9,43 +14,48 @@ use advent_of_code_data::{
 };
 
 use crate::{
-    registry::{Solver, SolverError},
+    registry::{Example, Solver, SolverError, SolverPart, SolverPartFn},
     SolverArgs,
 };
 
-// TODO:
-//  - on_start_solver(solver)
-//    - on_start_part(solver, part)
-//      - on_start_examples(solver, part)
-//        - on_start_example(solver, part, example_index)
-//        _ on_finish_example(solver, part, example_index, duration, result)
-//      - on_finish_examples(solver, part, did_all_pass)
-//      - on_start_solver_part(solver, part)
-//      - on_finish_solver_part(solver, part, duration, result)
-//   - on_finish_part(solver, part, duration, result)
-// - on_finish_solver(solver, duration, result)
+/// Receives notifications as a `SolverRunner` runs solvers.
+///
+/// Callbacks are made in this order for each solver:
+///
+/// - `on_solver_start`
+///   - `on_part_start` (once for each part that is run)
+///     - `on_example_start`, then `on_example_finish` (once for each example)
+///     - `on_examples_finish`
+///     - `on_part_finish` or `on_part_event` (only when all of the examples
+///       passed, and the part was run against the real puzzle input)
+/// - `on_solver_finish`
+///
+/// `on_all_finished` is called once after every solver has finished.
 pub trait RunnerEventHandler {
-    fn on_start_solver(&mut self, _solver: &Solver) {}
-    fn on_start_part(&mut self, _solver: &Solver, _part: Part) {}
-    fn on_start_part_example(&mut self, _solver: &Solver, _part: Part, _example_index: usize) {}
-    fn on_example_pass(
-        &mut self,
-        _solver: &Solver,
-        _part: Part,
-        _duration: Duration,
-        _example_index: usize,
-    ) {
-    }
-    fn on_example_fail(
+    /// Called before any part of `solver` is run.
+    fn on_solver_start(&mut self, _solver: &Solver) {}
+
+    /// Called before the examples for a solver part are checked.
+    fn on_part_start(&mut self, _solver: &Solver, _part: Part) {}
+
+    /// Called before a solver part is run against one of its examples.
+    fn on_example_start(&mut self, _solver: &Solver, _part: Part, _example_index: usize) {}
+
+    /// Called after a solver part has been run against one of its examples.
+    fn on_example_finish(
         &mut self,
         _solver: &Solver,
         _part: Part,
         _duration: Duration,
         _example_index: usize,
-        _result: Result<Answer, RunnerError>,
+        _result: ExampleResult,
     ) {
     }
-    fn on_finish_part_examples(
+
+    /// Called after all of the examples for a solver part have been checked.
+    /// The part is only run against the real puzzle input when `fail_count` is
+    /// zero.
+    fn on_examples_finish(
         &mut self,
         solver: &Solver,
         part: Part,
@@ -53,7 +63,10 @@ pub trait RunnerEventHandler {
         pass_count: usize,
         fail_count: usize,
     );
-    fn on_finish_part(
+
+    /// Called after a solver part has been run against the real puzzle input
+    /// and its answer submitted.
+    fn on_part_finish(
         &mut self,
         solver: &Solver,
         part: Part,
@@ -61,11 +74,108 @@ pub trait RunnerEventHandler {
         result: &Result<(Answer, CheckResult), RunnerError>,
     );
 
-    fn on_finish_solver(&mut self, solver: &Solver, duration: Duration, details: RunDetails);
+    /// Called when a solver part produces an event that is specific to one of
+    /// the optional runner modes (e.g., dry run).
+    fn on_part_event(
+        &mut self,
+        _solver: &Solver,
+        _part: Part,
+        _duration: Duration,
+        _event: &PartEvent,
+    ) {
+    }
+
+    /// Called after every part of `solver` has been run.
+    fn on_solver_finish(&mut self, solver: &Solver, duration: Duration, details: RunDetails);
+
+    /// Called once after every solver in the runner has finished, with the
+    /// totals for the run.
+    fn on_all_finished(&mut self, _summary: &RunSummary) {}
+}
+
+/// The outcome of running a solver part against one of its examples.
+#[derive(Debug)]
+pub enum ExampleResult {
+    /// The solver part returned the example's expected answer.
+    Passed,
+    /// The solver part returned the wrong answer, or returned an error instead
+    /// of an answer.
+    Failed(Result<Answer, RunnerError>),
+}
+
+/// Events for a solver part that are only produced when the runner has an
+/// optional mode enabled.
+#[derive(Debug)]
+pub enum PartEvent {
+    /// The solver part produced an answer, but the answer was not submitted
+    /// because the runner is in dry run mode. `check_result` holds the result
+    /// of checking the answer against cached answers, or `None` if the cache
+    /// could not determine if the answer is correct.
+    DryRun {
+        answer: Answer,
+        check_result: Option<CheckResult>,
+    },
+    /// The solver part was run repeatedly against the puzzle input because the
+    /// runner is in benchmark mode. The answer is not submitted.
+    Benchmark {
+        answer: Answer,
+        result: BenchmarkResult,
+    },
+    /// The solver part produced an answer, but the answer was not submitted
+    /// because the solver was registered with `skip_submit`, or because it is a
+    /// solver stub with a stored answer and the runner is in benchmark mode.
+    SubmitSkipped { answer: Answer },
+}
+
+/// Timing statistics from running a solver part multiple times.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchmarkResult {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    /// The population standard deviation of the timed runs.
+    pub std_dev: Duration,
+    /// The number of timed runs, which excludes warm up runs.
+    pub iterations: u32,
+}
+
+impl BenchmarkResult {
+    /// Calculate timing statistics from a list of run durations.
+    ///
+    /// # Panics
+    /// Panics if `durations` is empty.
+    fn from_durations(mut durations: Vec<Duration>) -> Self {
+        assert!(!durations.is_empty());
+        durations.sort();
+
+        let mid = durations.len() / 2;
+        let median = if durations.len().is_multiple_of(2) {
+            (durations[mid - 1] + durations[mid]) / 2
+        } else {
+            durations[mid]
+        };
+
+        let count = durations.len() as f64;
+        let mean = durations.iter().map(Duration::as_secs_f64).sum::<f64>() / count;
+        let variance = durations
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        Self {
+            min: durations[0],
+            median,
+            max: durations[durations.len() - 1],
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+            iterations: durations.len() as u32,
+        }
+    }
 }
 
 pub struct SolverRunner {
     solvers_to_run: Vec<Solver>,
+    options: RunOptions,
     pub client: Box<dyn Client>,
     pub event_handler: Box<dyn RunnerEventHandler>,
 }
@@ -74,11 +184,76 @@ impl SolverRunner {
     pub fn new(client: Box<dyn Client>, event_handler: Box<dyn RunnerEventHandler>) -> Self {
         Self {
             solvers_to_run: Vec::new(),
+            options: Default::default(),
             client,
             event_handler,
         }
     }
 
+    /// Limit how long a solver part can run before it is treated as a failure.
+    ///
+    /// The timeout applies separately to each call of a solver part, including
+    /// calls made to check examples. A solver part that runs longer than the
+    /// timeout is reported as `SolverError::Timeout`, and is left running in a
+    /// detached background thread because Rust threads cannot be killed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Run solvers without ever submitting answers to the Advent of Code
+    /// service.
+    ///
+    /// Answers are checked against cached answers instead, and the result is
+    /// reported with a `PartEvent::DryRun` event rather than `on_part_finish`.
+    pub fn with_dry_run(mut self) -> Self {
+        self.options.dry_run = true;
+        self
+    }
+
+    /// Run each solver part `iterations` times against the real puzzle input
+    /// and report timing statistics instead of submitting the answer.
+    ///
+    /// The solver part is run once before timing starts to warm up caches, and
+    /// that run is not included in the statistics. Use `with_benchmark_warmup`
+    /// to change the number of warm up runs. Timing statistics are reported
+    /// with a `PartEvent::Benchmark` event.
+    ///
+    /// # Panics
+    /// Panics if `iterations` is zero.
+    pub fn with_benchmark(mut self, iterations: u32) -> Self {
+        assert!(iterations > 0, "benchmark iterations must be at least one");
+        self.options.benchmark_iterations = Some(iterations);
+        self
+    }
+
+    /// Set the number of untimed runs made before benchmarking a solver part.
+    /// Defaults to one, and only has an effect when benchmarking is enabled.
+    ///
+    /// When `runs` is zero the run that produced the answer is included in the
+    /// timing statistics.
+    pub fn with_benchmark_warmup(mut self, runs: u32) -> Self {
+        self.options.benchmark_warmup = Some(runs);
+        self
+    }
+
+    /// Only check solver parts against their examples, and never fetch puzzle
+    /// input or submit answers.
+    ///
+    /// A part is counted as passed in the run summary when all of its examples
+    /// pass. Parts without any examples are not counted.
+    pub fn with_examples_only(mut self) -> Self {
+        self.options.examples_only = true;
+        self
+    }
+
+    /// Only run `part` of each solver. The other part is skipped entirely,
+    /// including its examples.
+    pub fn with_part(mut self, part: Part) -> Self {
+        self.options.only_part = Some(part);
+        self
+    }
+
     /// Add solver to the list of solvers to be run.
     /// Solvers are run in the order that they are pushed to the runner.
     pub fn push(&mut self, solver: Solver) {
@@ -91,27 +266,168 @@ impl SolverRunner {
         self.solvers_to_run.push(solver);
     }
 
-    pub fn run_all(&mut self) {
-        for solver in &self.solvers_to_run {
-            Self::run(solver, &mut *self.client, &mut *self.event_handler);
+    /// Run all of the solvers in the order they were pushed, and return a
+    /// summary of how many solver parts passed, failed or errored.
+    ///
+    /// An error is returned if the client could not fetch puzzle input for a
+    /// solver, and no further solvers are run.
+    pub fn run_all(&mut self) -> Result<RunSummary, RunnerError> {
+        let start_time = Instant::now();
+        let mut delivery = EventDelivery::new(self.options);
+
+        let result = self.solvers_to_run.iter().try_for_each(|solver| {
+            let mut sink = DirectSink {
+                client: &mut *self.client,
+                events: &mut *self.event_handler,
+                delivery: &mut delivery,
+                error: None,
+            };
+
+            Self::run_solver(solver, self.options, &mut sink);
+            sink.error.map_or(Ok(()), Err)
+        });
+
+        let mut summary = delivery.summary;
+        summary.duration = Instant::now() - start_time;
+        self.event_handler.on_all_finished(&summary);
+        result?;
+
+        Ok(summary)
+    }
+
+    /// Run all of the solvers concurrently using a thread pool.
+    ///
+    /// Solvers are run in parallel, but the event handler is always called from
+    /// the calling thread. Events are delivered in ascending `(Year, Day)` order
+    /// regardless of the order the solvers were pushed, or how long each solver
+    /// took to finish. Events for the first unfinished solver are delivered as
+    /// they happen, and events for the solvers after it are held back until it
+    /// has finished.
+    ///
+    /// Puzzle inputs are fetched and answers are submitted from the calling
+    /// thread because the client is not shared between threads. If any puzzle
+    /// input cannot be fetched then no further events are delivered, and the
+    /// error is returned once the running solvers have stopped.
+    pub fn run_all_parallel(&mut self) -> Result<RunSummary, RunnerError> {
+        let start_time = Instant::now();
+        let options = self.options;
+        let mut solvers = self.solvers_to_run.clone();
+        solvers.sort_by_key(|s| (s.year, s.day));
+
+        let (sender, receiver) = mpsc::channel();
+        let (input_senders, input_receivers): (Vec<_>, Vec<_>) =
+            solvers.iter().map(|_| mpsc::channel::<String>()).unzip();
+        let mut input_senders: Vec<_> = input_senders.into_iter().map(Some).collect();
+
+        let mut delivery = EventDelivery::new(options);
+        let mut pending: Vec<VecDeque<SolverMessage>> =
+            solvers.iter().map(|_| VecDeque::new()).collect();
+        let mut next_solver = 0;
+        let mut error: Option<RunnerError> = None;
+
+        std::thread::scope(|scope| {
+            let solvers = &solvers;
+
+            scope.spawn(move || {
+                solvers
+                    .par_iter()
+                    .zip(input_receivers)
+                    .enumerate()
+                    .for_each_with(sender, |sender, (index, (solver, input_receiver))| {
+                        let mut sink = ChannelSink {
+                            index,
+                            sender,
+                            input_receiver,
+                        };
+
+                        Self::run_solver(solver, options, &mut sink);
+                    });
+            });
+
+            // Serve puzzle input requests and deliver events until every solver
+            // has stopped running.
+            for message in receiver {
+                if error.is_some() {
+                    continue;
+                }
+
+                match message {
+                    WorkerMessage::InputNeeded(index) => {
+                        let solver = &solvers[index];
+
+                        match self.client.get_input(solver.day, solver.year) {
+                            Ok(input) => {
+                                if let Some(input_sender) = &input_senders[index] {
+                                    // The solver stops waiting if the runner
+                                    // already failed, so ignore errors.
+                                    let _ = input_sender.send(input);
+                                }
+                            }
+                            Err(e) => {
+                                // Dropping the senders stops every solver that
+                                // is still waiting for puzzle input.
+                                error = Some(e.into());
+                                input_senders.iter_mut().for_each(|s| *s = None);
+                            }
+                        }
+                    }
+                    WorkerMessage::Progress(index, message) => {
+                        pending[index].push_back(message);
+
+                        while let Some(message) =
+                            pending.get_mut(next_solver).and_then(VecDeque::pop_front)
+                        {
+                            let solver_finished = matches!(message, SolverMessage::FinishSolver(_));
+
+                            delivery.deliver(
+                                &solvers[next_solver],
+                                message,
+                                &mut *self.client,
+                                &mut *self.event_handler,
+                            );
+
+                            if solver_finished {
+                                next_solver += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        let mut summary = delivery.summary;
+        summary.duration = Instant::now() - start_time;
+        self.event_handler.on_all_finished(&summary);
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(summary),
         }
     }
 
-    fn run(solver: &Solver, client: &mut dyn Client, events: &mut dyn RunnerEventHandler) {
+    /// Run each part of `solver`, and report progress to `sink` as it happens.
+    ///
+    /// Each part is checked against its examples before it is run on the real
+    /// puzzle input, and the part is skipped if any of the examples fail. The
+    /// solver stops running if `sink` cannot provide the puzzle input.
+    fn run_solver(solver: &Solver, options: RunOptions, sink: &mut dyn SolverSink) {
         tracing::debug!(
             "start running solver day {} year {}",
             solver.day,
             solver.year
         );
 
-        events.on_start_solver(solver);
+        let solver_start_time = Instant::now();
+        sink.send(solver, SolverMessage::StartSolver);
 
-        let mut event_details = SolverEventDetails::new();
+        for part in Part::all() {
+            if !options.runs_part(part) {
+                continue;
+            }
 
-        for part in [Part::One, Part::Two] {
             let solver_part = solver.part(part);
 
-            events.on_start_part(solver, part);
+            sink.send(solver, SolverMessage::StartPart(part));
 
             // Validate examples listed for the current part prior to running the
             // part on real input. Use all of the examples associated with the
@@ -121,86 +437,536 @@ impl SolverRunner {
             let mut pass_count = 0;
             let mut fail_count = 0;
 
-            let examples = solver_part.examples;
-
-            for (index, example) in examples.iter().enumerate() {
-                let example_start_time = Instant::now();
-                let result = (solver_part.func)(&SolverArgs {
-                    input: example.input,
-                });
-                let example_duration = Instant::now() - example_start_time;
-
-                match result {
-                    Ok(answer) => {
-                        if answer == example.expected {
-                            pass_count += 1;
-                            events.on_example_pass(solver, part, example_duration, index);
-                        } else {
-                            fail_count += 1;
-                            events.on_example_fail(
-                                solver,
-                                part,
-                                example_duration,
-                                index,
-                                Ok(answer),
-                            );
-                        }
-                    }
-                    Err(err) => {
-                        fail_count += 1;
-                        events.on_example_fail(
-                            solver,
-                            part,
-                            example_duration,
-                            index,
-                            Err(err.into()),
-                        );
-                    }
+            for (index, example) in solver_part.examples.iter().enumerate() {
+                sink.send(solver, SolverMessage::StartExample(part, index));
+                let example_run = Self::run_example(solver_part, example, options.timeout);
+
+                if example_run.passed {
+                    pass_count += 1;
+                } else {
+                    fail_count += 1;
                 }
+
+                sink.send(
+                    solver,
+                    SolverMessage::FinishExample(part, index, example_run),
+                );
             }
 
-            // Notify the event manager that examples have passed, otherwise if
-            // any have failed then skip running the part with real input.
-            let all_examples_duration = Instant::now() - all_examples_start_time;
-            events.on_finish_part_examples(
+            // Report how many examples passed, and if any have failed then skip
+            // running the part with real input.
+            sink.send(
                 solver,
-                part,
-                all_examples_duration,
-                pass_count,
-                fail_count,
+                SolverMessage::FinishExamples {
+                    part,
+                    duration: Instant::now() - all_examples_start_time,
+                    pass_count,
+                    fail_count,
+                },
             );
 
-            if fail_count > 0 {
+            if fail_count > 0 || options.examples_only {
                 continue;
             }
 
             // Fetch input only after examples have passed, but before we start
-            // timing the execution of the solver.
-            let input = client.get_input(solver.day, solver.year).unwrap();
+            // timing the execution of the solver. Solver stubs never read their
+            // input, so avoid fetching it.
+            let input = if solver.is_stub() {
+                String::new()
+            } else {
+                match sink.input(solver) {
+                    Some(input) => input,
+                    None => return,
+                }
+            };
 
             // Run the solver against real puzzle input.
-            let solve_start_time = Instant::now();
-            let run_solver_result = (solver_part.func)(&SolverArgs { input: &input });
-            let solve_duration = Instant::now() - solve_start_time;
+            let solve_run = Self::solve(solver, part, &input, options);
+            sink.send(solver, SolverMessage::FinishPart(part, solve_run));
+        }
 
-            let part_result = run_solver_result
-                .map_err::<RunnerError, _>(|e| e.into())
-                .and_then(|answer| {
-                    let check_result = client
-                        .submit_answer(answer.clone(), part, solver.day, solver.year)
-                        .map_err::<RunnerError, _>(|e| e.into())?;
-                    Ok((answer, check_result))
-                });
+        sink.send(
+            solver,
+            SolverMessage::FinishSolver(Instant::now() - solver_start_time),
+        );
+    }
 
-            events.on_finish_part(solver, part, solve_duration, &part_result);
-            event_details.record_part(part, solve_duration, part_result);
+    /// Run a solver part against one of its examples.
+    fn run_example(
+        solver_part: &SolverPart,
+        example: &Example,
+        timeout: Option<Duration>,
+    ) -> ExampleRun {
+        let example_start_time = Instant::now();
+        let result = Self::call_solver(solver_part.func, example.input, timeout);
+        let duration = Instant::now() - example_start_time;
+
+        ExampleRun {
+            passed: matches!(&result, Ok(answer) if *answer == example.expected),
+            duration,
+            result,
         }
+    }
+
+    /// Notify the event handler of an example's outcome.
+    fn report_example(
+        solver: &Solver,
+        part: Part,
+        index: usize,
+        example_run: ExampleRun,
+        events: &mut dyn RunnerEventHandler,
+    ) {
+        let result = if example_run.passed {
+            ExampleResult::Passed
+        } else {
+            ExampleResult::Failed(example_run.result.map_err(|e| e.into()))
+        };
+
+        events.on_example_finish(solver, part, example_run.duration, index, result);
+    }
 
-        let run_details: RunDetails = event_details.into();
-        events.on_finish_solver(solver, run_details.duration, run_details);
+    /// Run the requested part of a solver against the real puzzle input.
+    ///
+    /// Solver stubs with a stored answer for `part` return it without running.
+    fn solve(solver: &Solver, part: Part, input: &str, options: RunOptions) -> SolveRun {
+        match solver.stored_answer(part) {
+            Some(answer) => SolveRun {
+                duration: Duration::ZERO,
+                result: Ok(answer.clone()),
+                benchmark: None,
+            },
+            None => Self::solve_part(solver.part(part), input, options),
+        }
+    }
+
+    /// Run a solver part against the real puzzle input.
+    ///
+    /// In benchmark mode the first run is treated as a warm up, and the solver
+    /// part is run again for any remaining warm up runs and then once for each
+    /// benchmark iteration.
+    fn solve_part(solver_part: &SolverPart, input: &str, options: RunOptions) -> SolveRun {
+        let solve_start_time = Instant::now();
+        let result = Self::call_solver(solver_part.func, input, options.timeout);
+        let duration = Instant::now() - solve_start_time;
+
+        let Some(iterations) = options.benchmark_iterations.filter(|_| result.is_ok()) else {
+            return SolveRun {
+                duration,
+                result,
+                benchmark: None,
+            };
+        };
+
+        let warmup_runs = options.benchmark_warmup.unwrap_or(1);
+        let mut remaining_warmup_runs = warmup_runs.saturating_sub(1);
+        let mut durations = Vec::with_capacity(iterations as usize);
+
+        if warmup_runs == 0 {
+            durations.push(duration);
+        }
+
+        while durations.len() < iterations as usize {
+            let iteration_start_time = Instant::now();
+
+            if let Err(e) = Self::call_solver(solver_part.func, input, options.timeout) {
+                return SolveRun {
+                    duration: Instant::now() - iteration_start_time,
+                    result: Err(e),
+                    benchmark: None,
+                };
+            }
+
+            if remaining_warmup_runs > 0 {
+                remaining_warmup_runs -= 1;
+            } else {
+                durations.push(Instant::now() - iteration_start_time);
+            }
+        }
+
+        let benchmark = BenchmarkResult::from_durations(durations);
+
+        SolveRun {
+            duration: benchmark.median,
+            result,
+            benchmark: Some(benchmark),
+        }
+    }
+
+    /// Call a solver part function with `input`, and give up waiting for an
+    /// answer if it takes longer than `timeout`.
+    ///
+    /// A solver part that panics is reported as an error so the remaining
+    /// solvers can still run.
+    fn call_solver(
+        func: SolverPartFn,
+        input: &str,
+        timeout: Option<Duration>,
+    ) -> Result<Answer, SolverError> {
+        let Some(timeout) = timeout else {
+            return std::panic::catch_unwind(|| func(&SolverArgs { input }))
+                .unwrap_or_else(|_| Err(Self::panicked_error()));
+        };
+
+        // Run the solver on a separate thread so the runner can stop waiting
+        // once the timeout has elapsed.
+        let (sender, receiver) = mpsc::channel();
+        let input = input.to_string();
+
+        std::thread::spawn(move || {
+            // The receiver is dropped if the solver timed out, so ignore errors.
+            let _ = sender.send(func(&SolverArgs { input: &input }));
+        });
+
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(SolverError::Timeout(timeout)),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err(Self::panicked_error()),
+        }
+    }
+
+    fn panicked_error() -> SolverError {
+        SolverError::Other(anyhow::anyhow!(
+            "the solver panicked before returning an answer"
+        ))
+    }
+
+    /// Submit the answer from a solver part and notify the event handler.
+    ///
+    /// In dry run mode the answer is only checked against the cached answers
+    /// for the puzzle, and is never submitted. Answers from solvers that skip
+    /// submission are reported without being checked.
+    ///
+    /// Returns how the part should be counted in the run summary.
+    fn finish_part(
+        solver: &Solver,
+        part: Part,
+        solve_run: SolveRun,
+        options: RunOptions,
+        client: &mut dyn Client,
+        events: &mut dyn RunnerEventHandler,
+        event_details: &mut SolverEventDetails,
+    ) -> PartOutcome {
+        // Answers are never submitted in benchmark mode.
+        if let (Ok(answer), Some(_)) = (&solve_run.result, options.benchmark_iterations) {
+            let event = match &solve_run.benchmark {
+                Some(benchmark) => PartEvent::Benchmark {
+                    answer: answer.clone(),
+                    result: benchmark.clone(),
+                },
+                // Solver stubs return a stored answer without running, so there
+                // is nothing to benchmark.
+                None => PartEvent::SubmitSkipped {
+                    answer: answer.clone(),
+                },
+            };
+
+            events.on_part_event(solver, part, solve_run.duration, &event);
+            return PartOutcome::Uncounted;
+        }
+
+        if options.dry_run {
+            if let Ok(answer) = &solve_run.result {
+                let check_result = client
+                    .get_answers(part, solver.day, solver.year)
+                    .map_err(|e| tracing::warn!("could not load cached answers: {e}"))
+                    .ok()
+                    .and_then(|answers| answers.check(answer));
+
+                if let Some(check_result) = &check_result {
+                    event_details.record_part(
+                        part,
+                        solve_run.duration,
+                        Ok((answer.clone(), check_result.clone())),
+                    );
+                }
+
+                events.on_part_event(
+                    solver,
+                    part,
+                    solve_run.duration,
+                    &PartEvent::DryRun {
+                        answer: answer.clone(),
+                        check_result: check_result.clone(),
+                    },
+                );
+
+                return check_result.map_or(PartOutcome::Uncounted, PartOutcome::Checked);
+            }
+        }
+
+        if solver.skip_submit {
+            if let Ok(answer) = &solve_run.result {
+                events.on_part_event(
+                    solver,
+                    part,
+                    solve_run.duration,
+                    &PartEvent::SubmitSkipped {
+                        answer: answer.clone(),
+                    },
+                );
+
+                return PartOutcome::Uncounted;
+            }
+        }
+
+        let part_result = solve_run
+            .result
+            .map_err::<RunnerError, _>(|e| e.into())
+            .and_then(|answer| {
+                let check_result = client
+                    .submit_answer(answer.clone(), part, solver.day, solver.year)
+                    .map_err::<RunnerError, _>(|e| e.into())?;
+                Ok((answer, check_result))
+            });
+
+        let outcome = part_result
+            .as_ref()
+            .map_or(PartOutcome::Errored, |(_, check_result)| {
+                PartOutcome::Checked(check_result.clone())
+            });
+
+        events.on_part_finish(solver, part, solve_run.duration, &part_result);
+        event_details.record_part(part, solve_run.duration, part_result);
+
+        outcome
+    }
+}
+
+/// Optional settings that change how the runner runs solvers.
+#[derive(Clone, Copy, Debug, Default)]
+struct RunOptions {
+    timeout: Option<Duration>,
+    dry_run: bool,
+    benchmark_iterations: Option<u32>,
+    benchmark_warmup: Option<u32>,
+    examples_only: bool,
+    only_part: Option<Part>,
+}
+
+impl RunOptions {
+    /// Returns true if `part` should be run.
+    fn runs_part(&self, part: Part) -> bool {
+        self.only_part.is_none_or(|only_part| only_part == part)
+    }
+}
+
+/// The outcome of running a solver part against one of its examples.
+struct ExampleRun {
+    passed: bool,
+    duration: Duration,
+    result: Result<Answer, SolverError>,
+}
+
+/// The outcome of running a solver part against the real puzzle input.
+struct SolveRun {
+    duration: Duration,
+    result: Result<Answer, SolverError>,
+    /// Timing statistics when the runner is in benchmark mode.
+    benchmark: Option<BenchmarkResult>,
+}
+
+/// Progress reported by `SolverRunner::run_solver` while a solver is running.
+/// Each message corresponds to one or more event handler callbacks.
+enum SolverMessage {
+    StartSolver,
+    StartPart(Part),
+    StartExample(Part, usize),
+    FinishExample(Part, usize, ExampleRun),
+    FinishExamples {
+        part: Part,
+        duration: Duration,
+        pass_count: usize,
+        fail_count: usize,
+    },
+    FinishPart(Part, SolveRun),
+    FinishSolver(Duration),
+}
+
+/// Receives the progress of a running solver, and provides its puzzle input.
+trait SolverSink {
+    fn send(&mut self, solver: &Solver, message: SolverMessage);
+
+    /// Get the puzzle input for `solver`, or `None` if the solver should stop
+    /// running because the input could not be fetched.
+    fn input(&mut self, solver: &Solver) -> Option<String>;
+}
+
+/// Delivers progress straight to the event handler for solvers that are run on
+/// the calling thread.
+struct DirectSink<'a> {
+    client: &'a mut dyn Client,
+    events: &'a mut dyn RunnerEventHandler,
+    delivery: &'a mut EventDelivery,
+    /// The error returned when the puzzle input could not be fetched.
+    error: Option<RunnerError>,
+}
+
+impl SolverSink for DirectSink<'_> {
+    fn send(&mut self, solver: &Solver, message: SolverMessage) {
+        self.delivery
+            .deliver(solver, message, self.client, self.events);
+    }
+
+    fn input(&mut self, solver: &Solver) -> Option<String> {
+        self.client
+            .get_input(solver.day, solver.year)
+            .map_err(|e| self.error = Some(e.into()))
+            .ok()
+    }
+}
+
+/// Messages sent from solvers running on the thread pool to the calling
+/// thread. Each message is tagged with the solver's index in the sorted list of
+/// solvers.
+enum WorkerMessage {
+    Progress(usize, SolverMessage),
+    InputNeeded(usize),
+}
+
+/// Sends progress from a solver running on the thread pool to the calling
+/// thread, and waits for the calling thread to fetch puzzle input.
+struct ChannelSink<'a> {
+    index: usize,
+    sender: &'a mpsc::Sender<WorkerMessage>,
+    input_receiver: mpsc::Receiver<String>,
+}
+
+impl SolverSink for ChannelSink<'_> {
+    fn send(&mut self, _solver: &Solver, message: SolverMessage) {
+        // The calling thread only stops receiving once every solver has stopped
+        // running, so this cannot fail.
+        let _ = self
+            .sender
+            .send(WorkerMessage::Progress(self.index, message));
+    }
+
+    fn input(&mut self, _solver: &Solver) -> Option<String> {
+        self.sender
+            .send(WorkerMessage::InputNeeded(self.index))
+            .ok()?;
+        self.input_receiver.recv().ok()
+    }
+}
+
+/// Turns the progress of running solvers into event handler callbacks, submits
+/// answers, and counts the outcome of each solver part.
+struct EventDelivery {
+    options: RunOptions,
+    summary: RunSummary,
+    /// Details for the solver that is currently being delivered.
+    event_details: SolverEventDetails,
+}
+
+impl EventDelivery {
+    fn new(options: RunOptions) -> Self {
+        Self {
+            options,
+            summary: Default::default(),
+            event_details: Default::default(),
+        }
+    }
+
+    fn deliver(
+        &mut self,
+        solver: &Solver,
+        message: SolverMessage,
+        client: &mut dyn Client,
+        events: &mut dyn RunnerEventHandler,
+    ) {
+        match message {
+            SolverMessage::StartSolver => {
+                self.event_details = SolverEventDetails::new();
+                events.on_solver_start(solver);
+            }
+            SolverMessage::StartPart(part) => events.on_part_start(solver, part),
+            SolverMessage::StartExample(part, index) => {
+                events.on_example_start(solver, part, index)
+            }
+            SolverMessage::FinishExample(part, index, example_run) => {
+                SolverRunner::report_example(solver, part, index, example_run, events)
+            }
+            SolverMessage::FinishExamples {
+                part,
+                duration,
+                pass_count,
+                fail_count,
+            } => {
+                events.on_examples_finish(solver, part, duration, pass_count, fail_count);
+
+                if fail_count > 0 {
+                    self.summary.record(PartOutcome::ExamplesFailed);
+                } else if self.options.examples_only && pass_count > 0 {
+                    self.summary.record(PartOutcome::ExamplesPassed);
+                }
+            }
+            SolverMessage::FinishPart(part, solve_run) => {
+                let outcome = SolverRunner::finish_part(
+                    solver,
+                    part,
+                    solve_run,
+                    self.options,
+                    client,
+                    events,
+                    &mut self.event_details,
+                );
+                self.summary.record(outcome);
+            }
+            SolverMessage::FinishSolver(duration) => {
+                let run_details = RunDetails {
+                    duration,
+                    ..std::mem::take(&mut self.event_details).into()
+                };
+
+                events.on_solver_finish(solver, run_details.duration, run_details);
+            }
+        }
+    }
+}
+
+/// Totals from running all of the solvers in a runner.
+///
+/// A part passes when its answer is correct (or all of its examples pass in
+/// examples only mode), and fails when its answer is incorrect or any of its
+/// examples fail. A part errors when the solver or the
+/// client returned an error instead of a checked answer. Benchmarked parts,
+/// and dry run answers that could not be checked, are not counted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RunSummary {
+    pub passed: usize,
+    pub failed: usize,
+    pub errored: usize,
+    pub duration: Duration,
+}
+
+impl RunSummary {
+    fn record(&mut self, outcome: PartOutcome) {
+        match outcome {
+            PartOutcome::Checked(CheckResult::Correct) | PartOutcome::ExamplesPassed => {
+                self.passed += 1
+            }
+            PartOutcome::Checked(_) | PartOutcome::ExamplesFailed => self.failed += 1,
+            PartOutcome::Errored => self.errored += 1,
+            PartOutcome::Uncounted => {}
+        }
     }
 }
 
+/// How a solver part is counted in a `RunSummary`.
+enum PartOutcome {
+    /// The answer was checked against the Advent of Code service or cache.
+    Checked(CheckResult),
+    /// At least one example failed so the part was not run on real input.
+    ExamplesFailed,
+    /// All of the examples passed, and the part was not run on real input
+    /// because the runner is in examples only mode.
+    ExamplesPassed,
+    /// The solver or client returned an error instead of a checked answer.
+    Errored,
+    /// The part ran but its answer could not be checked.
+    Uncounted,
+}
+
 #[derive(Error, Debug)]
 pub enum RunnerError {
     #[error(transparent)]
@@ -276,3 +1042,1150 @@ impl From<SolverEventDetails> for RunDetails {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use advent_of_code_data::{
+        data::{Answers, Puzzle},
+        Day, Year,
+    };
+
+    use super::*;
+
+    /// A client that returns the same input for every puzzle, and treats every
+    /// submitted answer as correct. The cached answers for part one of every
+    /// puzzle say `2` is correct, and part two has no cached answers.
+    #[derive(Default)]
+    struct MockClient {
+        submit_count: Rc<Cell<usize>>,
+        input_count: Rc<Cell<usize>>,
+        /// Return an error when fetching input for this puzzle.
+        missing_input: Option<(Year, Day)>,
+    }
+
+    impl Client for MockClient {
+        fn years(&self) -> Vec<Year> {
+            Vec::new()
+        }
+
+        fn days(&self, _year: Year) -> Option<Vec<Day>> {
+            None
+        }
+
+        fn get_input(&self, day: Day, year: Year) -> Result<String, ClientError> {
+            self.input_count.set(self.input_count.get() + 1);
+
+            if self.missing_input == Some((year, day)) {
+                return Err(ClientError::PuzzleNotFound(day, year));
+            }
+
+            Ok("puzzle input".to_string())
+        }
+
+        fn submit_answer(
+            &mut self,
+            _answer: Answer,
+            _part: Part,
+            _day: Day,
+            _year: Year,
+        ) -> Result<CheckResult, ClientError> {
+            self.submit_count.set(self.submit_count.get() + 1);
+            Ok(CheckResult::Correct)
+        }
+
+        fn get_puzzle(&self, day: Day, year: Year) -> Result<Puzzle, ClientError> {
+            Ok(Puzzle {
+                day,
+                year,
+                input: self.get_input(day, year)?,
+                part_one_answers: self.get_answers(Part::One, day, year)?,
+                part_two_answers: self.get_answers(Part::Two, day, year)?,
+            })
+        }
+
+        fn get_answers(&self, part: Part, _day: Day, _year: Year) -> Result<Answers, ClientError> {
+            let mut answers = Answers::new();
+
+            if part == Part::One {
+                answers.set_correct_answer(Answer::Int(2));
+            }
+
+            Ok(answers)
+        }
+    }
+
+    /// Records the order that solvers were started and finished in.
+    struct RecordingEventHandler {
+        events: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl RunnerEventHandler for RecordingEventHandler {
+        fn on_solver_start(&mut self, solver: &Solver) {
+            self.events
+                .borrow_mut()
+                .push(format!("start {} {}", solver.year, solver.day));
+        }
+
+        fn on_example_finish(
+            &mut self,
+            solver: &Solver,
+            part: Part,
+            _duration: Duration,
+            example_index: usize,
+            result: ExampleResult,
+        ) {
+            let ExampleResult::Failed(result) = result else {
+                return;
+            };
+
+            let answer = result.map_or_else(|e| e.to_string(), |answer| answer.to_string());
+
+            self.events.borrow_mut().push(format!(
+                "example {} {} part {part} #{example_index} failed: {answer}",
+                solver.year, solver.day
+            ));
+        }
+
+        fn on_examples_finish(
+            &mut self,
+            _solver: &Solver,
+            _part: Part,
+            _duration: Duration,
+            _pass_count: usize,
+            _fail_count: usize,
+        ) {
+        }
+
+        fn on_part_finish(
+            &mut self,
+            solver: &Solver,
+            part: Part,
+            _duration: Duration,
+            result: &Result<(Answer, CheckResult), RunnerError>,
+        ) {
+            let answer = result
+                .as_ref()
+                .map_or_else(|e| e.to_string(), |(answer, _)| answer.to_string());
+
+            self.events.borrow_mut().push(format!(
+                "finish {} {} part {part}: {answer}",
+                solver.year, solver.day
+            ));
+        }
+
+        fn on_part_event(
+            &mut self,
+            solver: &Solver,
+            part: Part,
+            _duration: Duration,
+            event: &PartEvent,
+        ) {
+            let event = match event {
+                PartEvent::DryRun {
+                    answer,
+                    check_result,
+                } => format!("dry run {answer} {check_result:?}"),
+                PartEvent::Benchmark { answer, result } => {
+                    format!("benchmark {answer} x{}", result.iterations)
+                }
+                PartEvent::SubmitSkipped { answer } => format!("submit skipped {answer}"),
+            };
+
+            self.events.borrow_mut().push(format!(
+                "{} {} part {part}: {event}",
+                solver.year, solver.day
+            ));
+        }
+
+        fn on_solver_finish(&mut self, solver: &Solver, _duration: Duration, _details: RunDetails) {
+            self.events
+                .borrow_mut()
+                .push(format!("finish {} {}", solver.year, solver.day));
+        }
+    }
+
+    /// Records the name of every callback made by the runner.
+    struct LifecycleEventHandler {
+        events: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl LifecycleEventHandler {
+        fn push(&self, event: String) {
+            self.events.borrow_mut().push(event);
+        }
+    }
+
+    impl RunnerEventHandler for LifecycleEventHandler {
+        fn on_solver_start(&mut self, solver: &Solver) {
+            self.push(format!("on_solver_start {}", solver.day));
+        }
+
+        fn on_part_start(&mut self, _solver: &Solver, part: Part) {
+            self.push(format!("on_part_start {part}"));
+        }
+
+        fn on_example_start(&mut self, _solver: &Solver, part: Part, example_index: usize) {
+            self.push(format!("on_example_start {part} #{example_index}"));
+        }
+
+        fn on_example_finish(
+            &mut self,
+            _solver: &Solver,
+            part: Part,
+            _duration: Duration,
+            example_index: usize,
+            result: ExampleResult,
+        ) {
+            let outcome = match result {
+                ExampleResult::Passed => "passed",
+                ExampleResult::Failed(_) => "failed",
+            };
+
+            self.push(format!(
+                "on_example_finish {part} #{example_index} {outcome}"
+            ));
+        }
+
+        fn on_examples_finish(
+            &mut self,
+            _solver: &Solver,
+            part: Part,
+            _duration: Duration,
+            pass_count: usize,
+            fail_count: usize,
+        ) {
+            self.push(format!(
+                "on_examples_finish {part} {pass_count}/{fail_count}"
+            ));
+        }
+
+        fn on_part_finish(
+            &mut self,
+            _solver: &Solver,
+            part: Part,
+            _duration: Duration,
+            result: &Result<(Answer, CheckResult), RunnerError>,
+        ) {
+            let answer = result
+                .as_ref()
+                .map_or_else(|e| e.to_string(), |(answer, _)| answer.to_string());
+            self.push(format!("on_part_finish {part}: {answer}"));
+        }
+
+        fn on_solver_finish(&mut self, solver: &Solver, _duration: Duration, _details: RunDetails) {
+            self.push(format!("on_solver_finish {}", solver.day));
+        }
+
+        fn on_all_finished(&mut self, summary: &RunSummary) {
+            self.push(format!(
+                "on_all_finished {}/{}/{}",
+                summary.passed, summary.failed, summary.errored
+            ));
+        }
+    }
+
+    fn slow_part(_args: &SolverArgs) -> crate::Result<Answer> {
+        std::thread::sleep(Duration::from_millis(50));
+        Ok(Answer::Int(1))
+    }
+
+    fn fast_part(_args: &SolverArgs) -> crate::Result<Answer> {
+        Ok(Answer::Int(2))
+    }
+
+    static COUNTED_PART_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn counted_part(_args: &SolverArgs) -> crate::Result<Answer> {
+        COUNTED_PART_CALLS.fetch_add(1, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(1));
+        Ok(Answer::Int(3))
+    }
+
+    fn unfinished_part(_args: &SolverArgs) -> crate::Result<Answer> {
+        Err(SolverError::NotFinished)
+    }
+
+    fn endless_part(_args: &SolverArgs) -> crate::Result<Answer> {
+        loop {
+            std::thread::sleep(Duration::from_secs(60));
+        }
+    }
+
+    fn create_solver(year: usize, day: usize, func: crate::SolverPartFn) -> Solver {
+        Solver {
+            year: Year(year),
+            day: Day(day),
+            name: None,
+            title: None,
+            description: None,
+            skip_submit: false,
+            stored_answers: [None, None],
+            part_one: SolverPart {
+                func,
+                examples: &[],
+            },
+            part_two: SolverPart {
+                func,
+                examples: &[],
+            },
+        }
+    }
+
+    fn create_runner(events: Rc<RefCell<Vec<String>>>) -> SolverRunner {
+        SolverRunner::new(
+            Box::new(MockClient::default()),
+            Box::new(RecordingEventHandler { events }),
+        )
+    }
+
+    #[test]
+    fn run_all_parallel_delivers_events_in_sorted_order() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut runner = create_runner(events.clone());
+
+        runner.push(create_solver(2024, 5, fast_part));
+        runner.push(create_solver(2024, 1, slow_part));
+        runner.push(create_solver(2023, 9, fast_part));
+
+        runner.run_all_parallel().unwrap();
+
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                "start 2023 9",
+                "finish 2023 9 part One: 2",
+                "finish 2023 9 part Two: 2",
+                "finish 2023 9",
+                "start 2024 1",
+                "finish 2024 1 part One: 1",
+                "finish 2024 1 part Two: 1",
+                "finish 2024 1",
+                "start 2024 5",
+                "finish 2024 5 part One: 2",
+                "finish 2024 5 part Two: 2",
+                "finish 2024 5",
+            ]
+        );
+    }
+
+    #[test]
+    fn run_all_parallel_matches_run_all() {
+        let serial_events = Rc::new(RefCell::new(Vec::new()));
+        let mut serial_runner = create_runner(serial_events.clone());
+
+        let parallel_events = Rc::new(RefCell::new(Vec::new()));
+        let mut parallel_runner = create_runner(parallel_events.clone());
+
+        for runner in [&mut serial_runner, &mut parallel_runner] {
+            runner.push(create_solver(2024, 1, slow_part));
+            runner.push(create_solver(2024, 2, fast_part));
+        }
+
+        serial_runner.run_all().unwrap();
+        parallel_runner.run_all_parallel().unwrap();
+
+        assert_eq!(*serial_events.borrow(), *parallel_events.borrow());
+    }
+
+    #[test]
+    fn solver_part_that_runs_too_long_times_out() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut runner = create_runner(events.clone()).with_timeout(Duration::from_millis(100));
+
+        runner.push(create_solver(2024, 1, endless_part));
+
+        let start_time = Instant::now();
+        runner.run_all().unwrap();
+
+        assert!(Instant::now() - start_time < Duration::from_millis(500));
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                "start 2024 1",
+                "finish 2024 1 part One: solver timed out after 0.10s",
+                "finish 2024 1 part Two: solver timed out after 0.10s",
+                "finish 2024 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn solver_part_that_finishes_in_time_does_not_time_out() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut runner = create_runner(events.clone()).with_timeout(Duration::from_secs(5));
+
+        runner.push(create_solver(2024, 1, fast_part));
+        runner.run_all().unwrap();
+
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                "start 2024 1",
+                "finish 2024 1 part One: 2",
+                "finish 2024 1 part Two: 2",
+                "finish 2024 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn dry_run_never_submits_answers() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let client = MockClient::default();
+        let submit_count = client.submit_count.clone();
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: events.clone(),
+            }),
+        )
+        .with_dry_run();
+
+        runner.push(create_solver(2024, 1, fast_part));
+        runner.push(create_solver(2024, 2, slow_part));
+        runner.run_all().unwrap();
+
+        assert_eq!(submit_count.get(), 0);
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                "start 2024 1",
+                "2024 1 part One: dry run 2 Some(Correct)",
+                "2024 1 part Two: dry run 2 None",
+                "finish 2024 1",
+                "start 2024 2",
+                "2024 2 part One: dry run 1 Some(Wrong)",
+                "2024 2 part Two: dry run 1 None",
+                "finish 2024 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn dry_run_never_submits_answers_when_run_in_parallel() {
+        let client = MockClient::default();
+        let submit_count = client.submit_count.clone();
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: Default::default(),
+            }),
+        )
+        .with_dry_run();
+
+        runner.push(create_solver(2024, 1, fast_part));
+        runner.run_all_parallel().unwrap();
+
+        assert_eq!(submit_count.get(), 0);
+    }
+
+    #[test]
+    fn answers_are_submitted_when_not_a_dry_run() {
+        let client = MockClient::default();
+        let submit_count = client.submit_count.clone();
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: Default::default(),
+            }),
+        );
+
+        runner.push(create_solver(2024, 1, fast_part));
+        runner.run_all().unwrap();
+
+        assert_eq!(submit_count.get(), 2);
+    }
+
+    #[test]
+    fn skip_submit_never_submits_answers() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let client = MockClient::default();
+        let submit_count = client.submit_count.clone();
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: events.clone(),
+            }),
+        );
+
+        runner.push(Solver {
+            skip_submit: true,
+            ..create_solver(2024, 1, fast_part)
+        });
+        runner.push(create_solver(2024, 2, fast_part));
+        let summary = runner.run_all().unwrap();
+
+        // Only the solver without `skip_submit` has its answers submitted.
+        assert_eq!(submit_count.get(), 2);
+        assert_eq!(summary.passed, 2);
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                "start 2024 1",
+                "2024 1 part One: submit skipped 2",
+                "2024 1 part Two: submit skipped 2",
+                "finish 2024 1",
+                "start 2024 2",
+                "finish 2024 2 part One: 2",
+                "finish 2024 2 part Two: 2",
+                "finish 2024 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn skip_submit_never_submits_answers_when_run_in_parallel() {
+        let client = MockClient::default();
+        let submit_count = client.submit_count.clone();
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: Default::default(),
+            }),
+        );
+
+        runner.push(Solver {
+            skip_submit: true,
+            ..create_solver(2024, 1, fast_part)
+        });
+        runner.run_all_parallel().unwrap();
+
+        assert_eq!(submit_count.get(), 0);
+    }
+
+    #[test]
+    fn solver_stubs_return_stored_answers() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let registry = crate::SolverRegistry::from_toml(
+            "[[solver]]\nyear = 2024\nday = 1\npart_one_answer = \"12345\"\n",
+        )
+        .unwrap();
+
+        let mut runner = create_runner(events.clone());
+        runner.push(registry.solver(Year(2024), Day(1)).unwrap().clone());
+        let summary = runner.run_all().unwrap();
+
+        assert_eq!(summary.passed, 1);
+        assert_eq!(summary.errored, 1);
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                "start 2024 1",
+                "finish 2024 1 part One: 12345",
+                "finish 2024 1 part Two: this solver is not finished",
+                "finish 2024 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn solver_stubs_never_fetch_input() {
+        let client = MockClient::default();
+        let input_count = client.input_count.clone();
+        let registry = crate::SolverRegistry::from_toml(
+            "[[solver]]\nyear = 2024\nday = 1\npart_one_answer = \"12345\"\n",
+        )
+        .unwrap();
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: Default::default(),
+            }),
+        );
+
+        runner.push(registry.solver(Year(2024), Day(1)).unwrap().clone());
+        runner.run_all().unwrap();
+        let summary = runner.run_all_parallel().unwrap();
+
+        assert_eq!(input_count.get(), 0);
+        assert_eq!(summary.passed, 1);
+    }
+
+    #[test]
+    fn solver_stubs_never_fetch_input_in_dry_run() {
+        let client = MockClient::default();
+        let input_count = client.input_count.clone();
+        let submit_count = client.submit_count.clone();
+        let registry = crate::SolverRegistry::from_toml(
+            "[[solver]]\nyear = 2024\nday = 1\npart_one_answer = \"2\"\n",
+        )
+        .unwrap();
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: Default::default(),
+            }),
+        )
+        .with_dry_run();
+
+        runner.push(registry.solver(Year(2024), Day(1)).unwrap().clone());
+        let summary = runner.run_all().unwrap();
+
+        assert_eq!(input_count.get(), 0);
+        assert_eq!(submit_count.get(), 0);
+        assert_eq!(summary.passed, 1);
+    }
+
+    #[test]
+    fn benchmark_never_submits_stored_answers() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let client = MockClient::default();
+        let submit_count = client.submit_count.clone();
+        let registry = crate::SolverRegistry::from_toml(
+            "[[solver]]\nyear = 2024\nday = 1\npart_one_answer = \"12345\"\n",
+        )
+        .unwrap();
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: events.clone(),
+            }),
+        )
+        .with_benchmark(3)
+        .with_part(Part::One);
+
+        runner.push(registry.solver(Year(2024), Day(1)).unwrap().clone());
+        runner.run_all().unwrap();
+        runner.run_all_parallel().unwrap();
+
+        assert_eq!(submit_count.get(), 0);
+        assert_eq!(events.borrow()[1], "2024 1 part One: submit skipped 12345");
+    }
+
+    #[test]
+    fn benchmark_runs_each_part_requested_number_of_times() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let client = MockClient::default();
+        let submit_count = client.submit_count.clone();
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: events.clone(),
+            }),
+        )
+        .with_benchmark(5);
+
+        runner.push(create_solver(2024, 1, counted_part));
+        runner.run_all().unwrap();
+
+        // Each part is run once to warm up, and then once per iteration.
+        assert_eq!(COUNTED_PART_CALLS.load(Ordering::SeqCst), 12);
+        assert_eq!(submit_count.get(), 0);
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                "start 2024 1",
+                "2024 1 part One: benchmark 3 x5",
+                "2024 1 part Two: benchmark 3 x5",
+                "finish 2024 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn benchmark_durations_are_ordered() {
+        let solver_part = SolverPart {
+            func: slow_part,
+            examples: &[],
+        };
+
+        let options = RunOptions {
+            benchmark_iterations: Some(4),
+            ..Default::default()
+        };
+
+        let benchmark = SolverRunner::solve_part(&solver_part, "", options)
+            .benchmark
+            .unwrap();
+
+        assert_eq!(benchmark.iterations, 4);
+        assert!(benchmark.min >= Duration::from_millis(50));
+        assert!(benchmark.min <= benchmark.median);
+        assert!(benchmark.median <= benchmark.max);
+    }
+
+    #[test]
+    fn benchmark_result_median_of_even_and_odd_runs() {
+        let ms = Duration::from_millis;
+
+        let odd = BenchmarkResult::from_durations(vec![ms(9), ms(1), ms(4)]);
+        assert_eq!((odd.min, odd.median, odd.max), (ms(1), ms(4), ms(9)));
+        assert_eq!(odd.iterations, 3);
+
+        let even = BenchmarkResult::from_durations(vec![ms(8), ms(2), ms(4), ms(6)]);
+        assert_eq!((even.min, even.median, even.max), (ms(2), ms(5), ms(8)));
+        assert_eq!(even.iterations, 4);
+    }
+
+    #[test]
+    fn benchmark_result_std_dev() {
+        let durations = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_millis);
+        let result = BenchmarkResult::from_durations(durations.to_vec());
+        assert!((result.std_dev.as_secs_f64() - 0.002).abs() < 1e-9);
+
+        let result = BenchmarkResult::from_durations(vec![Duration::from_millis(3); 4]);
+        assert_eq!(result.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn benchmark_warmup_runs_are_not_timed() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        fn counted(_args: &SolverArgs) -> crate::Result<Answer> {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            Ok(Answer::Int(1))
+        }
+
+        let solver_part = SolverPart {
+            func: counted,
+            examples: &[],
+        };
+
+        let options = RunOptions {
+            benchmark_iterations: Some(4),
+            benchmark_warmup: Some(3),
+            ..Default::default()
+        };
+
+        let benchmark = SolverRunner::solve_part(&solver_part, "", options)
+            .benchmark
+            .unwrap();
+        assert_eq!(CALLS.load(Ordering::SeqCst), 7);
+        assert_eq!(benchmark.iterations, 4);
+
+        // Without any warm up runs the first run is also timed.
+        let options = RunOptions {
+            benchmark_warmup: Some(0),
+            ..options
+        };
+
+        SolverRunner::solve_part(&solver_part, "", options);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 11);
+    }
+
+    fn create_solver_with_examples(
+        year: usize,
+        day: usize,
+        func: crate::SolverPartFn,
+        examples: &'static [Example],
+    ) -> Solver {
+        let mut solver = create_solver(year, day, func);
+        solver.part_one.examples = examples;
+        solver
+    }
+
+    #[test]
+    fn run_all_returns_summary_with_part_counts() {
+        let mut runner = create_runner(Default::default());
+
+        // Every answer is submitted as correct by the mock client.
+        runner.push(create_solver(2024, 1, fast_part));
+
+        // Part one has a failing example, so only part two is submitted.
+        runner.push(create_solver_with_examples(
+            2024,
+            2,
+            fast_part,
+            &[Example {
+                input: "",
+                expected: Answer::Int(100),
+            }],
+        ));
+
+        // Unfinished solvers are counted as errors.
+        runner.push(create_solver(2024, 3, unfinished_part));
+
+        let summary = runner.run_all().unwrap();
+
+        assert_eq!(summary.passed, 3);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.errored, 2);
+    }
+
+    #[test]
+    fn run_all_parallel_returns_same_summary_as_run_all() {
+        let mut serial_runner = create_runner(Default::default());
+        let mut parallel_runner = create_runner(Default::default());
+
+        for runner in [&mut serial_runner, &mut parallel_runner] {
+            runner.push(create_solver(2024, 1, fast_part));
+            runner.push(create_solver(2024, 2, unfinished_part));
+        }
+
+        let serial_summary = serial_runner.run_all().unwrap();
+        let parallel_summary = parallel_runner.run_all_parallel().unwrap();
+
+        assert_eq!(
+            (
+                serial_summary.passed,
+                serial_summary.failed,
+                serial_summary.errored
+            ),
+            (
+                parallel_summary.passed,
+                parallel_summary.failed,
+                parallel_summary.errored
+            )
+        );
+    }
+
+    #[test]
+    fn client_error_is_returned_from_run_all() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let client = MockClient {
+            missing_input: Some((Year(2024), Day(2))),
+            ..Default::default()
+        };
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: events.clone(),
+            }),
+        );
+
+        runner.push(create_solver(2024, 1, fast_part));
+        runner.push(create_solver(2024, 2, fast_part));
+        runner.push(create_solver(2024, 3, fast_part));
+
+        let result = runner.run_all();
+
+        assert!(matches!(
+            result,
+            Err(RunnerError::Client(ClientError::PuzzleNotFound(
+                Day(2),
+                Year(2024)
+            )))
+        ));
+
+        // Solvers after the failed solver are not run.
+        assert_eq!(events.borrow().last().unwrap(), "start 2024 2");
+    }
+
+    #[test]
+    fn client_error_is_returned_from_run_all_parallel() {
+        let client = MockClient {
+            missing_input: Some((Year(2024), Day(1))),
+            ..Default::default()
+        };
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: Default::default(),
+            }),
+        );
+
+        runner.push(create_solver(2024, 1, fast_part));
+
+        assert!(matches!(
+            runner.run_all_parallel(),
+            Err(RunnerError::Client(ClientError::PuzzleNotFound(..)))
+        ));
+    }
+
+    #[test]
+    fn examples_only_never_fetches_input_or_submits_answers() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let client = MockClient::default();
+        let input_count = client.input_count.clone();
+        let submit_count = client.submit_count.clone();
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: events.clone(),
+            }),
+        )
+        .with_examples_only();
+
+        static EXAMPLES: [Example; 2] = [
+            Example {
+                input: "",
+                expected: Answer::Int(2),
+            },
+            Example {
+                input: "",
+                expected: Answer::Int(5),
+            },
+        ];
+
+        runner.push(create_solver_with_examples(
+            2024,
+            1,
+            fast_part,
+            &EXAMPLES[..1],
+        ));
+        runner.push(create_solver_with_examples(2024, 2, fast_part, &EXAMPLES));
+
+        let summary = runner.run_all().unwrap();
+
+        assert_eq!(input_count.get(), 0);
+        assert_eq!(submit_count.get(), 0);
+        assert_eq!((summary.passed, summary.failed, summary.errored), (1, 1, 0));
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                "start 2024 1",
+                "finish 2024 1",
+                "start 2024 2",
+                "example 2024 2 part One #1 failed: 2",
+                "finish 2024 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn examples_only_never_fetches_input_when_run_in_parallel() {
+        let client = MockClient::default();
+        let input_count = client.input_count.clone();
+
+        let mut runner = SolverRunner::new(
+            Box::new(client),
+            Box::new(RecordingEventHandler {
+                events: Default::default(),
+            }),
+        )
+        .with_examples_only();
+
+        static EXAMPLES: [Example; 1] = [Example {
+            input: "",
+            expected: Answer::Int(2),
+        }];
+
+        runner.push(create_solver_with_examples(2024, 1, fast_part, &EXAMPLES));
+        let summary = runner.run_all_parallel().unwrap();
+
+        assert_eq!(input_count.get(), 0);
+        assert_eq!((summary.passed, summary.failed, summary.errored), (1, 0, 0));
+    }
+
+    #[test]
+    fn solver_part_that_panics_is_reported_as_error() {
+        fn panicking_part(_args: &SolverArgs) -> crate::Result<Answer> {
+            panic!("this solver is broken");
+        }
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut runner = create_runner(events.clone());
+
+        runner.push(create_solver(2024, 1, panicking_part));
+        runner.push(create_solver(2024, 2, fast_part));
+
+        let summary = runner.run_all().unwrap();
+
+        assert_eq!((summary.passed, summary.errored), (2, 2));
+        assert_eq!(
+            events.borrow()[1],
+            "finish 2024 1 part One: the solver panicked before returning an answer"
+        );
+    }
+
+    #[test]
+    fn with_part_skips_the_other_part_entirely() {
+        static PART_TWO_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        fn part_two(_args: &SolverArgs) -> crate::Result<Answer> {
+            PART_TWO_CALLS.fetch_add(1, Ordering::SeqCst);
+            Ok(Answer::Int(4))
+        }
+
+        static EXAMPLES: [Example; 1] = [Example {
+            input: "",
+            expected: Answer::Int(4),
+        }];
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut runner = create_runner(events.clone()).with_part(Part::One);
+
+        let mut solver = create_solver(2024, 1, fast_part);
+        solver.part_two = SolverPart {
+            func: part_two,
+            examples: &EXAMPLES,
+        };
+
+        runner.push(solver.clone());
+        runner.run_all().unwrap();
+        runner.run_all_parallel().unwrap();
+
+        assert_eq!(PART_TWO_CALLS.load(Ordering::SeqCst), 0);
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                "start 2024 1",
+                "finish 2024 1 part One: 2",
+                "finish 2024 1",
+                "start 2024 1",
+                "finish 2024 1 part One: 2",
+                "finish 2024 1",
+            ]
+        );
+    }
+
+    /// Records when examples were started and finished.
+    struct ExampleTimingEventHandler {
+        start_times: Rc<RefCell<Vec<Instant>>>,
+        finish_times: Rc<RefCell<Vec<Instant>>>,
+    }
+
+    impl RunnerEventHandler for ExampleTimingEventHandler {
+        fn on_example_start(&mut self, _solver: &Solver, _part: Part, _example_index: usize) {
+            self.start_times.borrow_mut().push(Instant::now());
+        }
+
+        fn on_example_finish(
+            &mut self,
+            _solver: &Solver,
+            _part: Part,
+            _duration: Duration,
+            _example_index: usize,
+            _result: ExampleResult,
+        ) {
+            self.finish_times.borrow_mut().push(Instant::now());
+        }
+
+        fn on_examples_finish(
+            &mut self,
+            _solver: &Solver,
+            _part: Part,
+            _duration: Duration,
+            _pass_count: usize,
+            _fail_count: usize,
+        ) {
+        }
+
+        fn on_part_finish(
+            &mut self,
+            _solver: &Solver,
+            _part: Part,
+            _duration: Duration,
+            _result: &Result<(Answer, CheckResult), RunnerError>,
+        ) {
+        }
+
+        fn on_solver_finish(
+            &mut self,
+            _solver: &Solver,
+            _duration: Duration,
+            _details: RunDetails,
+        ) {
+        }
+    }
+
+    #[test]
+    fn example_start_is_delivered_before_the_example_runs() {
+        for parallel in [false, true] {
+            let start_times = Rc::new(RefCell::new(Vec::new()));
+            let finish_times = Rc::new(RefCell::new(Vec::new()));
+            let mut runner = SolverRunner::new(
+                Box::new(MockClient::default()),
+                Box::new(ExampleTimingEventHandler {
+                    start_times: start_times.clone(),
+                    finish_times: finish_times.clone(),
+                }),
+            )
+            .with_examples_only();
+
+            runner.push(create_solver_with_examples(
+                2024,
+                1,
+                slow_part,
+                &[Example {
+                    input: "",
+                    expected: Answer::Int(1),
+                }],
+            ));
+
+            if parallel {
+                runner.run_all_parallel().unwrap();
+            } else {
+                runner.run_all().unwrap();
+            }
+
+            // The slow part sleeps for 50ms, which is only seen by the event
+            // handler if the start of the example is delivered before it runs.
+            let elapsed = finish_times.borrow()[0] - start_times.borrow()[0];
+            assert!(elapsed >= Duration::from_millis(25), "parallel: {parallel}");
+        }
+    }
+
+    #[test]
+    fn callbacks_are_made_in_lifecycle_order() {
+        for parallel in [false, true] {
+            let events = Rc::new(RefCell::new(Vec::new()));
+            let mut runner = SolverRunner::new(
+                Box::new(MockClient::default()),
+                Box::new(LifecycleEventHandler {
+                    events: events.clone(),
+                }),
+            );
+
+            // Part one has a passing and a failing example, so only part two is
+            // run against the real puzzle input.
+            runner.push(create_solver_with_examples(
+                2024,
+                1,
+                fast_part,
+                &[
+                    Example {
+                        input: "",
+                        expected: Answer::Int(2),
+                    },
+                    Example {
+                        input: "",
+                        expected: Answer::Int(5),
+                    },
+                ],
+            ));
+            runner.push(create_solver(2024, 2, fast_part));
+
+            if parallel {
+                runner.run_all_parallel().unwrap();
+            } else {
+                runner.run_all().unwrap();
+            }
+
+            assert_eq!(
+                *events.borrow(),
+                vec![
+                    "on_solver_start 1",
+                    "on_part_start One",
+                    "on_example_start One #0",
+                    "on_example_finish One #0 passed",
+                    "on_example_start One #1",
+                    "on_example_finish One #1 failed",
+                    "on_examples_finish One 1/1",
+                    "on_part_start Two",
+                    "on_examples_finish Two 0/0",
+                    "on_part_finish Two: 2",
+                    "on_solver_finish 1",
+                    "on_solver_start 2",
+                    "on_part_start One",
+                    "on_examples_finish One 0/0",
+                    "on_part_finish One: 2",
+                    "on_part_start Two",
+                    "on_examples_finish Two 0/0",
+                    "on_part_finish Two: 2",
+                    "on_solver_finish 2",
+                    "on_all_finished 3/1/0",
+                ],
+                "parallel: {parallel}"
+            );
+        }
+    }
+}
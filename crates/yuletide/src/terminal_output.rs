@@ -1,47 +1,149 @@
-use std::time::Duration;
+use std::{io::Write, time::Duration};
 
 use crate::{
-    runner::{RunDetails, RunnerError, RunnerEventHandler},
+    runner::{
+        BenchmarkResult, ExampleResult, PartEvent, RunDetails, RunSummary, RunnerError,
+        RunnerEventHandler,
+    },
     {Solver, SolverError},
 };
 use advent_of_code_data::{data::CheckResult, Answer, Part};
 use tracing::{event, Level};
 
-pub struct ConsoleRunnerEventHandler {}
+/// ANSI escape codes used to highlight console output. Every code is printed
+/// before the highlighted text, and `reset` is printed after it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorTheme {
+    /// Highlights correct answers and passing examples.
+    pub success: &'static str,
+    /// Highlights wrong answers and failing examples.
+    pub failure: &'static str,
+    /// Highlights answers that were neither correct nor wrong, such as timeouts.
+    pub info: &'static str,
+    pub reset: &'static str,
+}
+
+impl ColorTheme {
+    /// A theme that never prints escape codes, for terminals without color
+    /// support or when output is redirected to a file.
+    pub fn no_color() -> Self {
+        Self {
+            success: "",
+            failure: "",
+            info: "",
+            reset: "",
+        }
+    }
+
+    /// A theme that uses bold text instead of colors.
+    pub fn minimal() -> Self {
+        Self {
+            success: "\x1b[1m",
+            failure: "\x1b[1m",
+            info: "\x1b[1m",
+            reset: "\x1b[0m",
+        }
+    }
+}
+
+impl Default for ColorTheme {
+    /// Green for success, red for failure and yellow for info.
+    fn default() -> Self {
+        Self {
+            success: "\x1b[32m",
+            failure: "\x1b[31m",
+            info: "\x1b[33m",
+            reset: "\x1b[0m",
+        }
+    }
+}
+
+pub struct ConsoleRunnerEventHandler {
+    theme: ColorTheme,
+    writer: Box<dyn Write>,
+}
 
 impl ConsoleRunnerEventHandler {
-    pub fn new() -> Self {
-        Self {}
+    /// Create a handler that prints to stdout using `theme`, or the default
+    /// theme when `theme` is `None`.
+    pub fn new(theme: Option<ColorTheme>) -> Self {
+        Self {
+            theme: theme.unwrap_or_default(),
+            writer: Box::new(std::io::stdout()),
+        }
+    }
+
+    /// Print to `writer` instead of stdout.
+    pub fn with_writer(mut self, writer: Box<dyn Write>) -> Self {
+        self.writer = writer;
+        self
+    }
+
+    /// Print a line of output. Errors are ignored because there is nowhere
+    /// else to report them.
+    fn print(&mut self, line: std::fmt::Arguments) {
+        let _ = writeln!(self.writer, "{line}");
     }
 }
 
 impl Default for ConsoleRunnerEventHandler {
     fn default() -> Self {
-        Self::new()
+        Self::new(None)
     }
 }
 
 impl RunnerEventHandler for ConsoleRunnerEventHandler {
-    fn on_start_solver(&mut self, solver: &Solver) {
-        println!("Solving day {} year {}...", solver.day, solver.year);
+    fn on_solver_start(&mut self, solver: &Solver) {
+        match solver.title {
+            Some(title) => self.print(format_args!(
+                "Solving day {} year {} ({title})...",
+                solver.day, solver.year
+            )),
+            None => self.print(format_args!(
+                "Solving day {} year {}...",
+                solver.day, solver.year
+            )),
+        }
     }
 
-    fn on_start_part(&mut self, _solver: &Solver, part: Part) {
-        println!("  Running part {part}...",);
+    fn on_part_start(&mut self, _solver: &Solver, part: Part) {
+        self.print(format_args!("  Running part {part}..."));
     }
 
-    fn on_example_fail(
+    fn on_example_finish(
         &mut self,
         solver: &Solver,
         part: Part,
         duration: Duration,
         example_index: usize,
-        result: Result<Answer, RunnerError>,
+        result: ExampleResult,
     ) {
+        let ColorTheme {
+            success,
+            failure,
+            reset,
+            ..
+        } = self.theme;
+
+        let ExampleResult::Failed(result) = result else {
+            self.print(format_args!(
+                "    {success}Example {} passed ✅{reset} [{:.3}s]",
+                example_index + 1,
+                duration.as_secs_f32()
+            ));
+            return;
+        };
+
         let expected = &solver.part(part).example(example_index).expected;
         let input = solver.part(part).example(example_index).input;
         let actual = result.map_or_else(|e| e.to_string(), |v| v.to_string());
 
+        self.print(format_args!(
+            "    {failure}Example {} failed ❌{reset} [{:.3}s]",
+            example_index + 1,
+            duration.as_secs_f32()
+        ));
+
         event!(
             Level::WARN,
             %solver.year,
@@ -56,7 +158,7 @@ impl RunnerEventHandler for ConsoleRunnerEventHandler {
         );
     }
 
-    fn on_finish_part_examples(
+    fn on_examples_finish(
         &mut self,
         _solver: &Solver,
         _part: Part,
@@ -64,60 +166,80 @@ impl RunnerEventHandler for ConsoleRunnerEventHandler {
         pass_count: usize,
         fail_count: usize,
     ) {
+        let ColorTheme {
+            success,
+            failure,
+            reset,
+            ..
+        } = self.theme;
+
         if fail_count > 0 {
-            println!(
-                "    Checked {} examples and {fail_count} failed ❌ [{:.3}s]",
+            self.print(format_args!(
+                "    {failure}Checked {} examples and {fail_count} failed ❌{reset} [{:.3}s]",
                 pass_count + fail_count,
                 duration.as_secs_f32()
-            )
+            ))
         } else if pass_count > 0 {
-            println!(
-                "    Checked {pass_count} examples ✅ [{:.3}s]",
+            self.print(format_args!(
+                "    {success}Checked {pass_count} examples ✅{reset} [{:.3}s]",
                 duration.as_secs_f32()
-            )
+            ))
         }
     }
 
-    fn on_finish_part(
+    fn on_part_finish(
         &mut self,
         solver: &Solver,
         part: Part,
         duration: Duration,
         result: &Result<(Answer, CheckResult), RunnerError>,
     ) {
+        let ColorTheme {
+            success,
+            failure,
+            info,
+            reset,
+        } = self.theme;
+
         // Catch the examples failed condition early, and print it before trying
         // to calculate runtime of the solution which isn't possible because the
         // solution never ran.
         match result {
             Ok((answer, CheckResult::Correct)) => {
-                println!(
-                    "    Answer is correct 👍 [{:.3}s]: {answer}",
-                    duration.as_secs_f32()
-                );
-            }
-            Ok((answer, CheckResult::Wrong)) => {
-                println!(
-                    "    Answer is wrong 👎 [{:.3}s]: {answer}",
+                self.print(format_args!(
+                    "    {success}Answer is correct 👍{reset} [{:.3}s]: {answer}",
                     duration.as_secs_f32()
-                )
-            }
-            Ok((answer, CheckResult::TooLow)) => {
-                println!(
-                    "    Answer is too low 📉 [{:.3}s]: {answer}",
-                    duration.as_secs_f32()
-                )
-            }
-            Ok((answer, CheckResult::TooHigh)) => {
-                println!(
-                    "    Answer is too high 📈 [{:.3}s]: {answer}",
-                    duration.as_secs_f32()
-                )
+                ));
             }
+            Ok((answer, CheckResult::Wrong)) => self.print(format_args!(
+                "    {failure}Answer is wrong 👎{reset} [{:.3}s]: {answer}",
+                duration.as_secs_f32()
+            )),
+            Ok((answer, CheckResult::TooLow { hint })) => self.print(format_args!(
+                "    {failure}Answer is too low 📉{reset} [{:.3}s]: {answer}{}",
+                duration.as_secs_f32(),
+                hint.as_ref()
+                    .map(|h| format!(" (should be at least {h})"))
+                    .unwrap_or_default()
+            )),
+            Ok((answer, CheckResult::TooHigh { hint })) => self.print(format_args!(
+                "    {failure}Answer is too high 📈{reset} [{:.3}s]: {answer}{}",
+                duration.as_secs_f32(),
+                hint.as_ref()
+                    .map(|h| format!(" (should be at most {h})"))
+                    .unwrap_or_default()
+            )),
             Err(RunnerError::Solver(SolverError::NotFinished)) => {
-                println!(
-                    "    Solution is not finished 👻 [{:.3}s]",
+                self.print(format_args!(
+                    "    {info}Solution is not finished 👻{reset} [{:.3}s]",
                     duration.as_secs_f32()
-                );
+                ));
+            }
+            Err(RunnerError::Solver(SolverError::Timeout(limit))) => {
+                self.print(format_args!(
+                    "    {info}Solution timed out after {:.3}s ⏰{reset}",
+                    limit.as_secs_f32()
+                ));
             }
             Err(RunnerError::Solver(SolverError::TooSoon)) => {
                 event!(
@@ -143,7 +265,319 @@ impl RunnerEventHandler for ConsoleRunnerEventHandler {
         }
     }
 
-    fn on_finish_solver(&mut self, _solver: &Solver, duration: Duration, _details: RunDetails) {
-        println!("Solved in {:.3} seconds", duration.as_secs_f32())
+    fn on_part_event(
+        &mut self,
+        _solver: &Solver,
+        _part: Part,
+        duration: Duration,
+        event: &PartEvent,
+    ) {
+        let ColorTheme {
+            success,
+            failure,
+            info,
+            reset,
+        } = self.theme;
+
+        match event {
+            PartEvent::DryRun {
+                answer,
+                check_result,
+            } => {
+                let (color, status) = match check_result {
+                    Some(CheckResult::Correct) => (success, "matches cached answer 👍"),
+                    Some(CheckResult::Wrong) => (failure, "is a known wrong answer 👎"),
+                    Some(CheckResult::TooLow { .. }) => (failure, "is too low 📉"),
+                    Some(CheckResult::TooHigh { .. }) => (failure, "is too high 📈"),
+                    None => (info, "is not in the cache ❔"),
+                };
+
+                self.print(format_args!(
+                    "    {color}Answer {status}{reset} (dry run) [{:.3}s]: {answer}",
+                    duration.as_secs_f32()
+                ));
+            }
+            PartEvent::Benchmark { answer, result } => {
+                self.print(format_args!(
+                    "    Benchmarked {} runs: min {:.3}s, median {:.3}s, max {:.3}s: {answer}",
+                    result.iterations,
+                    result.min.as_secs_f32(),
+                    result.median.as_secs_f32(),
+                    result.max.as_secs_f32()
+                ));
+            }
+            PartEvent::SubmitSkipped { answer } => {
+                self.print(format_args!(
+                    "    {info}Answer was not submitted ⏸️{reset} (computed only) [{:.3}s]: {answer}",
+                    duration.as_secs_f32()
+                ));
+            }
+        }
+    }
+
+    fn on_solver_finish(&mut self, _solver: &Solver, duration: Duration, _details: RunDetails) {
+        self.print(format_args!(
+            "Solved in {:.3} seconds",
+            duration.as_secs_f32()
+        ))
+    }
+
+    fn on_all_finished(&mut self, summary: &RunSummary) {
+        self.print(format_args!(
+            "Finished in {:.3} seconds: {} passed, {} failed, {} errored",
+            summary.duration.as_secs_f32(),
+            summary.passed,
+            summary.failed,
+            summary.errored
+        ))
+    }
+}
+
+/// Event handler that collects benchmark results for each solver part, and
+/// prints them as a table once all of the solvers have finished.
+#[derive(Default)]
+pub struct BenchmarkTableEventHandler {
+    rows: Vec<(Solver, Part, BenchmarkResult)>,
+}
+
+impl BenchmarkTableEventHandler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RunnerEventHandler for BenchmarkTableEventHandler {
+    fn on_example_finish(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        _duration: Duration,
+        example_index: usize,
+        result: ExampleResult,
+    ) {
+        if matches!(result, ExampleResult::Passed) {
+            return;
+        }
+
+        event!(
+            Level::WARN,
+            %solver.year,
+            %solver.day,
+            %part,
+            example_index,
+            "example {example_index} failed so the part will not be benchmarked",
+        );
+    }
+
+    fn on_examples_finish(
+        &mut self,
+        _solver: &Solver,
+        _part: Part,
+        _duration: Duration,
+        _pass_count: usize,
+        _fail_count: usize,
+    ) {
+    }
+
+    fn on_part_finish(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        _duration: Duration,
+        result: &Result<(Answer, CheckResult), RunnerError>,
+    ) {
+        if let Err(error) = result {
+            event!(
+                Level::ERROR,
+                %solver.year,
+                %solver.day,
+                %part,
+                ?error,
+                "solver returned an error rather than an answer",
+            );
+        }
+    }
+
+    fn on_part_event(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        _duration: Duration,
+        event: &PartEvent,
+    ) {
+        if let PartEvent::Benchmark { result, .. } = event {
+            self.rows.push((solver.clone(), part, result.clone()));
+        }
+    }
+
+    fn on_solver_finish(&mut self, _solver: &Solver, _duration: Duration, _details: RunDetails) {}
+
+    fn on_all_finished(&mut self, _summary: &RunSummary) {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+
+        println!(
+            "{:>4} {:>3} {:>4} {:>12} {:>12} {:>12} {:>12}",
+            "Year", "Day", "Part", "Min (ms)", "Median (ms)", "Max (ms)", "Std dev (ms)"
+        );
+
+        for (solver, part, result) in &self.rows {
+            println!(
+                "{:>4} {:>3} {:>4} {:>12.3} {:>12.3} {:>12.3} {:>12.3}",
+                solver.year.to_string(),
+                solver.day.to_string(),
+                part.to_string(),
+                ms(result.min),
+                ms(result.median),
+                ms(result.max),
+                ms(result.std_dev)
+            );
+        }
+    }
+}
+
+/// Event handler that prints the outcome of every example, for use when a runner
+/// is in examples only mode.
+#[derive(Default)]
+pub struct ExampleReportEventHandler {}
+
+impl ExampleReportEventHandler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RunnerEventHandler for ExampleReportEventHandler {
+    fn on_solver_start(&mut self, solver: &Solver) {
+        println!("Validating day {} year {}...", solver.day, solver.year);
+    }
+
+    fn on_example_finish(
+        &mut self,
+        solver: &Solver,
+        part: Part,
+        duration: Duration,
+        example_index: usize,
+        result: ExampleResult,
+    ) {
+        let ExampleResult::Failed(result) = result else {
+            println!(
+                "  Part {part} example {} passed ✅ [{:.3}s]",
+                example_index + 1,
+                duration.as_secs_f32()
+            );
+            return;
+        };
+
+        let expected = &solver.part(part).example(example_index).expected;
+        let actual = result.map_or_else(|e| e.to_string(), |v| v.to_string());
+
+        println!(
+            "  Part {part} example {} failed ❌ [{:.3}s]: expected `{expected}` but got `{actual}`",
+            example_index + 1,
+            duration.as_secs_f32()
+        );
+    }
+
+    fn on_examples_finish(
+        &mut self,
+        _solver: &Solver,
+        part: Part,
+        _duration: Duration,
+        pass_count: usize,
+        fail_count: usize,
+    ) {
+        if pass_count + fail_count == 0 {
+            println!("  Part {part} has no examples");
+        }
+    }
+
+    fn on_part_finish(
+        &mut self,
+        _solver: &Solver,
+        _part: Part,
+        _duration: Duration,
+        _result: &Result<(Answer, CheckResult), RunnerError>,
+    ) {
+    }
+
+    fn on_solver_finish(&mut self, _solver: &Solver, _duration: Duration, _details: RunDetails) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{create_solver, SharedBuffer};
+
+    use super::*;
+
+    /// Print the results of a part with every kind of check result using
+    /// `theme`, and return the output.
+    fn render(theme: ColorTheme) -> String {
+        let buffer = SharedBuffer::default();
+        let mut handler =
+            ConsoleRunnerEventHandler::new(Some(theme)).with_writer(Box::new(buffer.clone()));
+        let solver = create_solver(2024, 3);
+
+        handler.on_examples_finish(&solver, Part::One, Duration::ZERO, 2, 0);
+        handler.on_examples_finish(&solver, Part::Two, Duration::ZERO, 1, 1);
+
+        for result in [
+            Ok((Answer::Int(1), CheckResult::Correct)),
+            Ok((Answer::Int(2), CheckResult::Wrong)),
+            Err(RunnerError::Solver(SolverError::Timeout(
+                Duration::from_secs(1),
+            ))),
+        ] {
+            handler.on_part_finish(&solver, Part::One, Duration::ZERO, &result);
+        }
+
+        buffer.text()
+    }
+
+    #[test]
+    fn default_theme_uses_colors() {
+        let output = render(ColorTheme::default());
+
+        assert!(output.contains("\x1b[32mAnswer is correct 👍\x1b[0m"));
+        assert!(output.contains("\x1b[31mAnswer is wrong 👎\x1b[0m"));
+        assert!(output.contains("\x1b[33mSolution timed out after 1.000s ⏰\x1b[0m"));
+    }
+
+    #[test]
+    fn minimal_theme_uses_bold_text() {
+        let output = render(ColorTheme::minimal());
+
+        assert!(output.contains("\x1b[1mChecked 2 examples ✅\x1b[0m"));
+        assert!(output.contains("\x1b[1mAnswer is wrong 👎\x1b[0m"));
+        assert!(!output.contains("\x1b[3"));
+    }
+
+    #[test]
+    fn no_color_theme_never_prints_escape_codes() {
+        let output = render(ColorTheme::no_color());
+
+        assert!(!output.contains('\x1b'));
+        assert!(output.contains("    Checked 2 examples and 1 failed ❌ [0.000s]\n"));
+        assert!(output.contains("    Answer is correct 👍 [0.000s]: 1\n"));
+    }
+
+    #[test]
+    fn skipped_submissions_are_marked_as_computed_only() {
+        let buffer = SharedBuffer::default();
+        let mut handler = ConsoleRunnerEventHandler::new(Some(ColorTheme::no_color()))
+            .with_writer(Box::new(buffer.clone()));
+
+        handler.on_part_event(
+            &create_solver(2024, 3),
+            Part::Two,
+            Duration::ZERO,
+            &PartEvent::SubmitSkipped {
+                answer: Answer::Int(5),
+            },
+        );
+
+        assert_eq!(
+            buffer.text(),
+            "    Answer was not submitted ⏸️ (computed only) [0.000s]: 5\n"
+        );
     }
 }
@@ -0,0 +1,67 @@
+//! Fixtures shared by the unit tests for the runner event handlers.
+use std::{cell::RefCell, io::Write, rc::Rc};
+
+use advent_of_code_data::{Answer, Day, Year};
+
+use crate::{Solver, SolverArgs, SolverPart};
+
+/// A writer that can be inspected after being given to an event handler.
+#[derive(Clone, Default)]
+pub struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuffer {
+    /// Get a copy of every byte written to the buffer.
+    pub fn bytes(&self) -> Vec<u8> {
+        self.0.borrow().clone()
+    }
+
+    /// Get the text written to the buffer.
+    pub fn text(&self) -> String {
+        String::from_utf8(self.bytes()).unwrap()
+    }
+
+    /// Get each line of text written to the buffer.
+    pub fn lines(&self) -> Vec<String> {
+        self.text().lines().map(|l| l.to_string()).collect()
+    }
+
+    /// Check if nothing has been written to the buffer.
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Create a solver for `year` and `day` whose parts must never be called.
+pub fn create_solver(year: usize, day: usize) -> Solver {
+    fn unused_part(_args: &SolverArgs) -> crate::Result<Answer> {
+        unreachable!()
+    }
+
+    Solver {
+        year: Year(year),
+        day: Day(day),
+        name: None,
+        title: None,
+        description: None,
+        skip_submit: false,
+        stored_answers: [None, None],
+        part_one: SolverPart {
+            func: unused_part,
+            examples: &[],
+        },
+        part_two: SolverPart {
+            func: unused_part,
+            examples: &[],
+        },
+    }
+}
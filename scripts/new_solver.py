@@ -31,6 +31,10 @@ use crate::SOLVERS;
 #[distributed_slice(SOLVERS)]
 static SOLVER: yt::SolverAutoRegister = yt::SolverAutoRegister {{
     modpath: std::module_path!(),
+    name: None,
+    title: None,
+    description: None,
+    skip_submit: false,
     part_one: yt::SolverPart {{
         func: day_{day}_1,
         examples: &[/*yt::Example {{
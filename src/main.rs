@@ -1,21 +1,30 @@
 mod y2024;
 mod y2025;
 
+mod scaffold;
+
 use advent_of_code_data::{
-    client::{Client, WebClient},
-    Day, Part, Year,
+    client::{Client, ClientError, PrefetchStatus, WebClient},
+    config::load_config,
+    data::{CheckResult, Puzzle},
+    Answer, Day, Part, Year,
 };
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use linkme::distributed_slice;
 use thiserror::Error;
-use yuletide::terminal_output::ConsoleRunnerEventHandler;
-use yuletide::{runner::SolverRunner, SolverAutoRegister, SolverRegistry};
+use yuletide::json_output::JsonRunnerEventHandler;
+use yuletide::markdown_output::MarkdownRunnerEventHandler;
+use yuletide::terminal_output::{
+    BenchmarkTableEventHandler, ColorTheme, ConsoleRunnerEventHandler, ExampleReportEventHandler,
+};
+use yuletide::{
+    runner::{RunnerEventHandler, SolverRunner},
+    Solver, SolverAutoRegister, SolverRegistry,
+};
 
 #[distributed_slice]
 pub static SOLVERS: [SolverAutoRegister];
 
-// TODO: validation day, year
-
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
 struct Cli {
@@ -23,52 +32,154 @@ struct Cli {
     #[arg(long, action)]
     debug: bool,
 
+    /// Name of the configuration profile to use, for example `dev`.
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
+    /// Format used to report solver results.
+    #[arg(long, value_enum, global = true, default_value_t = OutputFormat::Console)]
+    output: OutputFormat,
+
+    /// Print console output without colors.
+    #[arg(long, global = true)]
+    no_color: bool,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
 
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// Human readable progress and results.
+    Console,
+    /// A JSON array of part results printed to stdout when all solvers finish.
+    Json,
+    /// A Markdown table of part results printed to stdout when all solvers finish.
+    Markdown,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Runs one or more solvers and checks if the result is correct/incorrect.
     Run {
         /// Puzzle day (defaults to the most recent day with a solver).
-        #[arg(short, long)]
-        days: Option<Vec<usize>>,
+        #[arg(short, long, value_parser = parse_day)]
+        days: Option<Vec<Day>>,
 
         /// Puzzle year (defaults to the most recent year with a solver).
-        #[arg(short, long)]
-        year: Option<usize>,
+        #[arg(short, long, value_parser = parse_year)]
+        year: Option<Year>,
+
+        /// Index of the solver to run when a day has alternative solvers
+        /// (defaults to the first registered solver).
+        #[arg(long, default_value_t = 0)]
+        solver_index: usize,
+
+        /// Only run this part of each solver.
+        #[arg(long, value_name = "1|2", value_parser = part_parser())]
+        part: Option<Part>,
+
+        /// Benchmark each solver part by running it this many times, and print
+        /// timing statistics instead of submitting answers.
+        #[arg(long, value_name = "ITERATIONS", value_parser = clap::value_parser!(u32).range(1..))]
+        bench: Option<u32>,
     },
     /// Runs all puzzle solvers that have a solution, and reports which solvers
     /// are broken because they don't match the known answer.
     Check {
         /// Puzzle day (defaults to all if not specified).
-        #[arg(short, long)]
-        days: Option<Vec<usize>>,
+        #[arg(short, long, value_parser = parse_day)]
+        days: Option<Vec<Day>>,
 
         /// Puzzle year (defaults to all if not specified).
-        #[arg(short, long)]
-        year: Option<usize>,
+        #[arg(short, long, value_parser = parse_year)]
+        year: Option<Year>,
+
+        /// Only check this part of each solver.
+        #[arg(long, value_name = "1|2", value_parser = part_parser())]
+        part: Option<Part>,
+
+        /// Check the answers listed in this TOML file instead of running the
+        /// compiled solvers.
+        #[arg(long, value_name = "FILE")]
+        solvers_file: Option<std::path::PathBuf>,
+    },
+    /// Benchmarks one or more solvers and prints timing statistics for each part.
+    Bench {
+        /// Puzzle day (defaults to the most recent day with a solver).
+        #[arg(short, long, value_parser = parse_day)]
+        days: Option<Vec<Day>>,
+
+        /// Puzzle year (defaults to the most recent year with a solver).
+        #[arg(short, long, value_parser = parse_year)]
+        year: Option<Year>,
+
+        /// Number of timed runs for each solver part.
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// Number of untimed runs for each solver part before timing starts.
+        #[arg(long, default_value_t = 1)]
+        warmup: u32,
+    },
+    /// Lists all of the registered solvers.
+    List,
+    /// Checks that solvers pass their built-in examples. Does not need network access, a session
+    /// or a puzzle cache.
+    Validate {
+        /// Puzzle day (defaults to all if not specified).
+        #[arg(short, long, value_parser = parse_day)]
+        days: Option<Vec<Day>>,
+
+        /// Puzzle year (defaults to all if not specified).
+        #[arg(short, long, value_parser = parse_year)]
+        year: Option<Year>,
     },
     /// Runs a solver with visualization mode enabled.
     Visualize {
         /// Day of puzzle.
-        #[arg(short, long)]
-        day: usize,
+        #[arg(short, long, value_parser = parse_day)]
+        day: Day,
+
+        /// Year of puzzle.
+        #[arg(short, long, value_parser = parse_year)]
+        year: Year,
+    },
+    /// Downloads puzzle inputs for a year and saves them to the cache.
+    Fetch {
+        /// Puzzle year.
+        #[arg(short, long, value_parser = parse_year)]
+        year: Year,
+
+        /// Puzzle days (defaults to all available days in the year).
+        #[arg(short, long, value_parser = parse_day)]
+        days: Option<Vec<Day>>,
+    },
+    /// Creates a new solver file for a puzzle from a template.
+    Init {
+        /// Day of puzzle.
+        #[arg(short, long, value_parser = parse_day)]
+        day: Day,
 
         /// Year of puzzle.
-        #[arg(short, long)]
-        year: usize,
+        #[arg(short, long, value_parser = parse_year)]
+        year: Year,
+    },
+    /// Prints a shell completion script.
+    Completions {
+        /// Shell to generate completions for.
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
     },
     /// Prints the input for a puzzle.
     Input {
         /// Day of puzzle.
-        #[arg(short, long)]
-        day: usize,
+        #[arg(short, long, value_parser = parse_day)]
+        day: Day,
 
         /// Year of puzzle.
-        #[arg(short, long)]
-        year: usize,
+        #[arg(short, long, value_parser = parse_year)]
+        year: Year,
     },
 }
 
@@ -78,12 +189,26 @@ enum Commands {
 pub enum AppError {
     #[error("no puzzle solver found for year {} day {}", .0, .1)]
     SolverNotFound(Year, Day),
+    #[error("no puzzle solver with index {} found for year {} day {}", .2, .0, .1)]
+    SolverIndexNotFound(Year, Day, usize),
     #[error("no puzzle solvers were found for year {}", .0)]
     NoSolversForYear(Year),
     #[error("no puzzle solvers were found")]
     NoSolversFound,
+    #[error("{} solver parts have failing examples", .0)]
+    ExamplesFailed(usize),
+    #[error("the puzzle solver for year {} day {} has no examples for part {}", .0, .1, .2)]
+    NoExamplesForPart(Year, Day, Part),
     #[error("{}", .0)]
     ClientError(#[from] advent_of_code_data::client::ClientError),
+    #[error("{}", .0)]
+    ScaffoldError(#[from] scaffold::ScaffoldError),
+    #[error("{}", .0)]
+    RunnerError(#[from] yuletide::runner::RunnerError),
+    #[error("{}", .0)]
+    RegistryError(#[from] yuletide::RegistryError),
+    #[error("failed to read {}: {}", .0.display(), .1)]
+    ReadSolversFile(std::path::PathBuf, std::io::Error),
 }
 
 fn main() -> Result<(), AppError> {
@@ -96,23 +221,104 @@ fn main() -> Result<(), AppError> {
     };
 
     // Logging setup.
-    let subscriber = tracing_subscriber::fmt().with_max_level(log_level).finish();
+    // Log to stderr so that log output does not get mixed in with solver results.
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(log_level)
+        .with_writer(std::io::stderr)
+        .finish();
     tracing::subscriber::set_global_default(subscriber).unwrap();
 
     let solver_registry = SolverRegistry::compiled_from(&SOLVERS);
 
+    // Commands that only need the solver registry are handled before creating
+    // the client so they work without any configuration or cache.
+    match &cli.command {
+        Some(Commands::List) => {
+            print_solver_list(&solver_registry);
+            return Ok(());
+        }
+        Some(Commands::Validate { days, year }) => {
+            return run_validate_command(&solver_registry, days, year);
+        }
+        Some(Commands::Completions { shell }) => {
+            let mut command = Cli::command();
+            let name = command.get_name().to_string();
+
+            clap_complete::generate(*shell, &mut command, name, &mut std::io::stdout());
+            return Ok(());
+        }
+        Some(Commands::Init { day, year }) => {
+            let path = scaffold::create_solver(std::path::Path::new("src"), *year, *day)?;
+
+            println!("Created solver file {}", path.display());
+            return Ok(());
+        }
+        _ => {}
+    }
+
     // Create the Advent of Code client.
-    let client = WebClient::new()?;
+    let mut config = load_config().map_err(ClientError::from)?;
+
+    if let Some(profile) = &cli.profile {
+        config = config.with_profile(profile);
+    }
+
+    let client = WebClient::with_config(config.build().map_err(ClientError::from)?);
+    let theme = match cli.no_color {
+        true => ColorTheme::no_color(),
+        false => ColorTheme::default(),
+    };
 
     match &cli.command {
-        Some(Commands::Run { days, year }) => {
-            run_solver_command(&solver_registry, client, days, year)
-        }
-        Some(Commands::Check { days, year }) => {
-            run_check_command(&solver_registry, client, days, year)
+        Some(Commands::Run {
+            days,
+            year,
+            solver_index,
+            part,
+            bench,
+        }) => run_solver_command(
+            &solver_registry,
+            SolverRunner::new(Box::new(client), create_event_handler(cli.output, theme)),
+            days,
+            year,
+            *solver_index,
+            *part,
+            *bench,
+        ),
+        Some(Commands::Bench {
+            days,
+            year,
+            iterations,
+            warmup,
+        }) => run_bench_command(&solver_registry, client, days, year, *iterations, *warmup),
+        Some(Commands::Check {
+            days,
+            year,
+            part,
+            solvers_file,
+        }) => {
+            let file_registry = match solvers_file {
+                Some(path) => {
+                    let text = std::fs::read_to_string(path)
+                        .map_err(|e| AppError::ReadSolversFile(path.clone(), e))?;
+                    Some(SolverRegistry::from_toml(&text)?)
+                }
+                None => None,
+            };
+
+            run_check_command(
+                file_registry.as_ref().unwrap_or(&solver_registry),
+                client,
+                cli.output,
+                theme,
+                days,
+                year,
+                *part,
+            )
         }
+        Some(Commands::Fetch { year, days }) => run_fetch_command(client, *year, days),
         Some(Commands::Input { day, year }) => {
-            let puzzle_input = client.get_input(Day(*day), Year(*year))?;
+            let puzzle_input = client.get_input(*day, *year)?;
             println!("{puzzle_input}");
 
             Ok(())
@@ -123,12 +329,110 @@ fn main() -> Result<(), AppError> {
     }
 }
 
+/// Print a table of every registered solver grouped by year.
+fn print_solver_list(solver_registry: &SolverRegistry) {
+    let has_examples = |solver: &Solver, part: Part| match solver.part(part).examples.is_empty() {
+        true => "no",
+        false => "yes",
+    };
+
+    let mut last_year = None;
+
+    for solver in solver_registry.all_solvers() {
+        if last_year != Some(solver.year) {
+            if last_year.is_some() {
+                println!();
+            }
+
+            println!("Year {}", solver.year);
+            println!(
+                "  {:>3}  {:<15}  {:<15}  Title",
+                "Day", "Part 1 examples", "Part 2 examples"
+            );
+
+            last_year = Some(solver.year);
+        }
+
+        println!(
+            "  {:>3}  {:<15}  {:<15}  {}",
+            solver.day.to_string(),
+            has_examples(solver, Part::One),
+            has_examples(solver, Part::Two),
+            solver.title.unwrap_or("-")
+        );
+    }
+}
+
+/// Create an event handler that reports solver results in the requested format.
+fn create_event_handler(output: OutputFormat, theme: ColorTheme) -> Box<dyn RunnerEventHandler> {
+    match output {
+        OutputFormat::Console => Box::new(ConsoleRunnerEventHandler::new(Some(theme))),
+        OutputFormat::Json => Box::new(JsonRunnerEventHandler::new(Box::new(std::io::stdout()))),
+        OutputFormat::Markdown => {
+            Box::new(MarkdownRunnerEventHandler::new(Box::new(std::io::stdout())))
+        }
+    }
+}
+
 fn run_solver_command(
+    solver_registry: &SolverRegistry,
+    mut runner: SolverRunner,
+    days: &Option<Vec<Day>>,
+    year: &Option<Year>,
+    solver_index: usize,
+    part: Option<Part>,
+    bench: Option<u32>,
+) -> Result<(), AppError> {
+    let solvers = requested_solvers(solver_registry, days, year, solver_index)?;
+
+    if let Some(part) = part {
+        require_part_examples(&solvers, part)?;
+        runner = runner.with_part(part);
+    }
+
+    if let Some(iterations) = bench {
+        runner = runner.with_benchmark(iterations);
+    }
+
+    for solver in solvers {
+        runner.push(solver);
+    }
+
+    runner.run_all()?;
+    Ok(())
+}
+
+fn run_bench_command(
     solver_registry: &SolverRegistry,
     client: WebClient,
-    days: &Option<Vec<usize>>,
-    year: &Option<usize>,
+    days: &Option<Vec<Day>>,
+    year: &Option<Year>,
+    iterations: u32,
+    warmup: u32,
 ) -> Result<(), AppError> {
+    let solvers = requested_solvers(solver_registry, days, year, 0)?;
+    let mut runner = SolverRunner::new(
+        Box::new(client),
+        Box::new(BenchmarkTableEventHandler::new()),
+    )
+    .with_benchmark(iterations)
+    .with_benchmark_warmup(warmup);
+
+    for solver in solvers {
+        runner.push(solver);
+    }
+
+    runner.run_all()?;
+    Ok(())
+}
+
+/// Find the solvers for the days and year given on the command line.
+fn requested_solvers(
+    solver_registry: &SolverRegistry,
+    days: &Option<Vec<Day>>,
+    year: &Option<Year>,
+    solver_index: usize,
+) -> Result<Vec<Solver>, AppError> {
     // Use the puzzle year given on the command line, or if not specified find the most
     // recent year in the solver registry.
     let year = year.map_or_else(
@@ -139,7 +443,7 @@ fn run_solver_command(
                 .max()
                 .ok_or(AppError::NoSolversFound)
         },
-        |year| Ok(Year(year)),
+        Ok,
     )?;
 
     // Use the puzzle day given on the command line, or if not specified find the most
@@ -155,12 +459,9 @@ fn run_solver_command(
                     "expected only years with at least one solver from SolverRegistry::years()",
                 )])
         },
-        |days| Ok(days.iter().map(|d| Day(*d)).collect()),
+        |days| Ok(days.clone()),
     )?;
 
-    let mut runner =
-        SolverRunner::new(Box::new(client), Box::new(ConsoleRunnerEventHandler::new()));
-
     let available_days = solver_registry
         .days(year)
         .ok_or(AppError::NoSolversForYear(year))?;
@@ -173,78 +474,224 @@ fn run_solver_command(
         return Err(AppError::SolverNotFound(year, *missing_day));
     }
 
-    // Run a solver for each requested day.
-    for requested_day in requested_days {
-        if available_days.contains(&requested_day) {
-            runner.push(
-                solver_registry
-                    .solver(year, requested_day)
-                    .ok_or(AppError::SolverNotFound(year, requested_day))?
-                    .clone(),
-            );
-        }
+    // Select a solver for each requested day.
+    requested_days
+        .into_iter()
+        .map(|requested_day| {
+            solver_registry
+                .solvers(year, requested_day)
+                .ok_or(AppError::SolverNotFound(year, requested_day))?
+                .get(solver_index)
+                .cloned()
+                .ok_or(AppError::SolverIndexNotFound(
+                    year,
+                    requested_day,
+                    solver_index,
+                ))
+        })
+        .collect()
+}
+
+fn run_check_command(
+    solver_registry: &SolverRegistry,
+    client: WebClient,
+    output: OutputFormat,
+    theme: ColorTheme,
+    days: &Option<Vec<Day>>,
+    year: &Option<Year>,
+    part: Option<Part>,
+) -> Result<(), AppError> {
+    let parts: Vec<Part> = match part {
+        Some(part) => vec![part],
+        None => Part::all().collect(),
+    };
+
+    // Save a list of the requested puzzles that have at least one requested part with a correct
+    // answer in the puzzle cache.
+    let solvers: Vec<Solver> = filtered_solvers(solver_registry, days, year)?
+        .into_iter()
+        .filter(|s| {
+            parts.iter().any(|&part| {
+                matches!(
+                    client.puzzle_cache.load_answers(part, s.day, s.year),
+                    Ok(Some(answers)) if answers.is_solved()
+                )
+            })
+        })
+        .collect();
+
+    // Run selected puzzle days in ascending calendar order. Answers are only
+    // checked against the puzzle cache, and never submitted.
+    let mut runner =
+        SolverRunner::new(Box::new(client), create_event_handler(output, theme)).with_dry_run();
+
+    if let Some(part) = part {
+        require_part_examples(&solvers, part)?;
+        runner = runner.with_part(part);
     }
 
-    runner.run_all(); // TODO: This should return Result and be changed to ?.
+    for solver in solvers {
+        runner.push(solver);
+    }
+
+    runner.run_all()?;
     Ok(())
 }
 
-fn run_check_command(
+fn run_validate_command(
     solver_registry: &SolverRegistry,
+    days: &Option<Vec<Day>>,
+    year: &Option<Year>,
+) -> Result<(), AppError> {
+    let mut runner = SolverRunner::new(
+        Box::new(OfflineClient),
+        Box::new(ExampleReportEventHandler::new()),
+    )
+    .with_examples_only();
+
+    for solver in filtered_solvers(solver_registry, days, year)? {
+        runner.push(solver);
+    }
+
+    let summary = runner.run_all()?;
+
+    if summary.failed > 0 {
+        return Err(AppError::ExamplesFailed(summary.failed));
+    }
+
+    Ok(())
+}
+
+fn run_fetch_command(
     client: WebClient,
-    days: &Option<Vec<usize>>,
-    year: &Option<usize>,
+    year: Year,
+    days: &Option<Vec<Day>>,
 ) -> Result<(), AppError> {
-    // Iterate all the year(r) and day(s) from the arguments, and save a list of puzzles that have
-    // at least one part with a correct answer in the puzzle cache.
-    //
-    // If no years are given, assume the caller wants to run a check on all years with at least one
-    // solver.
-    let mut puzzles: Vec<(Year, Day)> = Vec::new();
-
-    for year in year.map(|y| vec![Year(y)]).unwrap_or_else(|| {
-        // When the caller does not provide a year, default to every year that
-        // has at least one solver.
-        solver_registry.years()
-    }) {
-        for day in days
-            .as_ref()
-            .map(|days| Ok(days.iter().map(|d| Day(*d)).collect()))
-            .unwrap_or_else(|| {
-                // When the daller does not provide a list of days, default to
-                // every day in the year that has a solver.
-                solver_registry
-                    .days(year)
-                    .ok_or(AppError::NoSolversForYear(year))
-            })?
+    // Fail early with a clear error rather than after checking the cache for the first day.
+    if client.config.session_id.is_none() {
+        return Err(ClientError::SessionIdRequired.into());
+    }
+
+    let summary = client.prefetch_year(year, days.as_deref(), &mut |day, status| match status {
+        PrefetchStatus::Fetched => println!("Fetched input for day {day} year {year}"),
+        PrefetchStatus::AlreadyCached => {
+            println!("Skipped day {day} year {year} because the input is already cached")
+        }
+    })?;
+
+    println!(
+        "Fetched {} inputs, skipped {} cached inputs",
+        summary.fetched, summary.already_cached
+    );
+
+    Ok(())
+}
+
+/// Parser for `--part` command line values. The possible values are listed so
+/// shell completions can suggest them.
+fn part_parser() -> impl clap::builder::TypedValueParser<Value = Part> {
+    use clap::builder::TypedValueParser;
+
+    clap::builder::PossibleValuesParser::new(["1", "2"]).map(|value| match value.as_str() {
+        "1" => Part::One,
+        _ => Part::Two,
+    })
+}
+
+/// Parser for puzzle day command line values, which must be in the range [1, 25].
+fn parse_day(value: &str) -> Result<Day, String> {
+    let day: usize = value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a number"))?;
+
+    Day::checked(day).ok_or_else(|| format!("{day} is not an Advent of Code day (expected 1-25)"))
+}
+
+/// Parser for puzzle year command line values, which must be 2015 or later.
+fn parse_year(value: &str) -> Result<Year, String> {
+    let year: usize = value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a number"))?;
+
+    Year::checked(year).ok_or_else(|| {
+        format!(
+            "{year} is not an Advent of Code year (the first event was in {})",
+            Year::FIRST
+        )
+    })
+}
+
+/// Error out if any of the solvers do not have examples for `part`. Solver stubs with a stored
+/// answer for `part` never need examples.
+fn require_part_examples(solvers: &[Solver], part: Part) -> Result<(), AppError> {
+    match solvers
+        .iter()
+        .find(|s| s.stored_answer(part).is_none() && s.part(part).examples.is_empty())
+    {
+        Some(s) => Err(AppError::NoExamplesForPart(s.year, s.day, part)),
+        None => Ok(()),
+    }
+}
+
+/// Find the solvers matching the optional days and year given on the command line. When no year
+/// or days are given every solver in the registry is returned.
+fn filtered_solvers(
+    solver_registry: &SolverRegistry,
+    days: &Option<Vec<Day>>,
+    year: &Option<Year>,
+) -> Result<Vec<Solver>, AppError> {
+    // Error out if the caller asked for a year or day that does not have a solver.
+    if let Some(year) = *year {
+        if solver_registry.days(year).is_none() {
+            return Err(AppError::NoSolversForYear(year));
+        }
+
+        if let Some(missing_day) = days
+            .iter()
+            .flatten()
+            .copied()
+            .find(|day| !solver_registry.has_solver(year, *day))
         {
-            for part in [Part::One, Part::Two] {
-                if let Ok(Some(answers)) = client.puzzle_cache.load_answers(part, day, year) {
-                    if answers.correct_answer_ref().is_some() {
-                        puzzles.push((year, day));
-                    }
-                }
-            }
+            return Err(AppError::SolverNotFound(year, missing_day));
         }
     }
 
-    // Start puzzles in ascending calendar order.
-    puzzles.sort();
+    Ok(solver_registry
+        .all_solvers()
+        .filter(|s| year.is_none_or(|y| s.year == y))
+        .filter(|s| days.as_ref().is_none_or(|days| days.contains(&s.day)))
+        .cloned()
+        .collect())
+}
 
-    // Run selected puzzle days.
-    // TODO: Specify runner should not submit any answers.
-    let mut runner =
-        SolverRunner::new(Box::new(client), Box::new(ConsoleRunnerEventHandler::new()));
+/// A client for commands that never fetch input or submit answers, and therefore do not need a
+/// session or puzzle cache.
+struct OfflineClient;
 
-    for (year, day) in puzzles {
-        runner.push(
-            solver_registry
-                .solver(year, day)
-                .expect("puzzles array is exepcted to contain only valid year/day values")
-                .clone(),
-        );
+impl Client for OfflineClient {
+    fn years(&self) -> Vec<Year> {
+        Vec::new()
     }
 
-    runner.run_all(); // TODO: This should return Result and be changed to ?.
-    Ok(())
+    fn days(&self, _year: Year) -> Option<Vec<Day>> {
+        None
+    }
+
+    fn get_input(&self, day: Day, year: Year) -> Result<String, ClientError> {
+        Err(ClientError::PuzzleNotFound(day, year))
+    }
+
+    fn submit_answer(
+        &mut self,
+        _answer: Answer,
+        _part: Part,
+        _day: Day,
+        _year: Year,
+    ) -> Result<CheckResult, ClientError> {
+        Err(ClientError::SessionIdRequired)
+    }
+
+    fn get_puzzle(&self, day: Day, year: Year) -> Result<Puzzle, ClientError> {
+        Err(ClientError::PuzzleNotFound(day, year))
+    }
 }
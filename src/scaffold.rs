@@ -0,0 +1,298 @@
+//! Generates the boilerplate for new puzzle solvers. This mirrors the behavior
+//! of `scripts/new_solver.py`.
+use std::path::{Path, PathBuf};
+
+use advent_of_code_data::{Day, Year};
+use thiserror::Error;
+
+const SOLVER_TEMPLATE: &str = r#"use advent_of_code_data as aoc;
+use yuletide as yt;
+
+use linkme::distributed_slice;
+
+use crate::SOLVERS;
+
+#[distributed_slice(SOLVERS)]
+static SOLVER: yt::SolverAutoRegister = yt::SolverAutoRegister {
+    modpath: std::module_path!(),
+    name: None,
+    title: None,
+    description: None,
+    skip_submit: false,
+    part_one: yt::SolverPart {
+        func: day_{DAY}_1,
+        examples: &[/*yt::Example {
+            input: "",
+            expected: aoc::Answer::Int(0),
+        }*/],
+    },
+    part_two: yt::SolverPart {
+        func: day_{DAY}_2,
+        examples: &[/*yt::Example {
+            input: "",
+            expected: aoc::Answer::Int(0),
+        }*/],
+    },
+};
+
+pub fn day_{DAY}_1(_args: &yt::SolverArgs) -> yt::Result<aoc::Answer> {
+    Err(yt::SolverError::NotFinished)
+}
+
+pub fn day_{DAY}_2(_args: &yt::SolverArgs) -> yt::Result<aoc::Answer> {
+    Err(yt::SolverError::NotFinished)
+}
+
+#[cfg(test)]
+mod tests {
+    // Tests for year {YEAR} day {DAY} go here.
+}
+"#;
+
+#[derive(Debug, Error)]
+pub enum ScaffoldError {
+    #[error("the solver file {} already exists", .0.display())]
+    SolverFileExists(PathBuf),
+    #[error("expected to find main.rs at {}", .0.display())]
+    MainNotFound(PathBuf),
+    #[error("{}", .0)]
+    Io(#[from] std::io::Error),
+}
+
+/// Returns the source code for a new solver.
+pub fn solver_source(year: Year, day: Day) -> String {
+    SOLVER_TEMPLATE
+        .replace("{YEAR}", &year.to_string())
+        .replace("{DAY}", &day.to_string())
+}
+
+/// Create a new solver file under `src_dir`, and register it with the year's
+/// `mod.rs` file. If this is the first solver for the year then `main.rs` is
+/// updated to include the year module.
+///
+/// Returns the path of the new solver file. Existing solver files are never
+/// overwritten.
+pub fn create_solver(src_dir: &Path, year: Year, day: Day) -> Result<PathBuf, ScaffoldError> {
+    let main_file = src_dir.join("main.rs");
+
+    if !main_file.exists() {
+        return Err(ScaffoldError::MainNotFound(main_file));
+    }
+
+    let year_dir = src_dir.join(format!("y{year}"));
+    let solver_file = year_dir.join(format!("day{day}.rs"));
+
+    if solver_file.exists() {
+        return Err(ScaffoldError::SolverFileExists(solver_file));
+    }
+
+    let is_new_year = !year_dir.exists();
+
+    std::fs::create_dir_all(&year_dir)?;
+    std::fs::write(&solver_file, solver_source(year, day))?;
+
+    update_mod_file(&year_dir.join("mod.rs"), day)?;
+
+    if is_new_year {
+        update_main_file(&main_file, year)?;
+    }
+
+    Ok(solver_file)
+}
+
+/// Add `mod day{day};` to the year's `mod.rs` after the day modules that come
+/// before it in numeric order. The rest of the file is left unchanged.
+fn update_mod_file(mod_file: &Path, day: Day) -> Result<(), ScaffoldError> {
+    let text = match std::fs::read_to_string(mod_file) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+
+    if let Some(new_text) = insert_mod_line(&text, "day", day.0) {
+        std::fs::write(mod_file, new_text)?;
+    }
+
+    Ok(())
+}
+
+/// Add `mod y{year};` to `main.rs` after the year modules that come before it
+/// in numeric order. The rest of the file is left unchanged.
+fn update_main_file(main_file: &Path, year: Year) -> Result<(), ScaffoldError> {
+    let text = std::fs::read_to_string(main_file)?;
+
+    if let Some(new_text) = insert_mod_line(&text, "y", year.0) {
+        std::fs::write(main_file, new_text)?;
+    }
+
+    Ok(())
+}
+
+/// Insert a `mod {prefix}{number};` line into `text` before the first module
+/// declared as `{prefix}` followed by a larger number. The line is added after
+/// the last of these modules if none are larger, or at the start of `text` if
+/// there are no such modules.
+///
+/// Returns `None` if the module is already declared.
+fn insert_mod_line(text: &str, prefix: &str, number: usize) -> Option<String> {
+    let mod_number = |line: &str| -> Option<usize> {
+        line.trim()
+            .strip_prefix("mod ")?
+            .strip_prefix(prefix)?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    };
+
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let mut insert_at = 0;
+
+    for (index, line) in lines.iter().enumerate() {
+        match mod_number(line) {
+            Some(n) if n == number => return None,
+            Some(n) if n > number => {
+                insert_at = index;
+                break;
+            }
+            Some(_) => insert_at = index + 1,
+            None => {}
+        }
+    }
+
+    let mut new_text = String::with_capacity(text.len() + 16);
+
+    for (index, line) in lines.iter().enumerate() {
+        if index == insert_at {
+            new_text.push_str(&format!("mod {prefix}{number};\n"));
+        }
+
+        new_text.push_str(line);
+
+        if index + 1 == insert_at && !line.ends_with('\n') {
+            new_text.push('\n');
+        }
+    }
+
+    if insert_at == lines.len() {
+        new_text.push_str(&format!("mod {prefix}{number};\n"));
+    }
+
+    Some(new_text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_src_dir() -> tempfile::TempDir {
+        let src_dir = tempfile::tempdir().unwrap();
+
+        std::fs::write(
+            src_dir.path().join("main.rs"),
+            "mod y2023;\nmod y2025;\n\nmod scaffold;\n\nfn main() {}\n",
+        )
+        .unwrap();
+
+        std::fs::create_dir(src_dir.path().join("y2023")).unwrap();
+        std::fs::write(
+            src_dir.path().join("y2023/mod.rs"),
+            "mod day1;\nmod day2;\nmod day10;\n",
+        )
+        .unwrap();
+
+        src_dir
+    }
+
+    #[test]
+    fn solver_source_uses_year_and_day() {
+        let source = solver_source(Year(2024), Day(17));
+
+        assert!(source.contains("func: day_17_1,"));
+        assert!(source.contains("func: day_17_2,"));
+        assert!(source.contains("pub fn day_17_1(_args: &yt::SolverArgs)"));
+        assert!(source.contains("pub fn day_17_2(_args: &yt::SolverArgs)"));
+        assert!(source.contains("#[distributed_slice(SOLVERS)]"));
+        assert!(source.contains("#[cfg(test)]\nmod tests {"));
+        assert!(source.contains("year 2024 day 17"));
+        assert!(!source.contains("{DAY}"));
+        assert!(!source.contains("{YEAR}"));
+    }
+
+    #[test]
+    fn create_solver_for_existing_year() {
+        let src_dir = create_src_dir();
+        let path = create_solver(src_dir.path(), Year(2023), Day(3)).unwrap();
+
+        assert_eq!(path, src_dir.path().join("y2023/day3.rs"));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            solver_source(Year(2023), Day(3))
+        );
+        assert_eq!(
+            std::fs::read_to_string(src_dir.path().join("y2023/mod.rs")).unwrap(),
+            "mod day1;\nmod day2;\nmod day3;\nmod day10;\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(src_dir.path().join("main.rs")).unwrap(),
+            "mod y2023;\nmod y2025;\n\nmod scaffold;\n\nfn main() {}\n"
+        );
+    }
+
+    #[test]
+    fn create_solver_for_new_year() {
+        let src_dir = create_src_dir();
+        create_solver(src_dir.path(), Year(2024), Day(1)).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(src_dir.path().join("y2024/mod.rs")).unwrap(),
+            "mod day1;\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(src_dir.path().join("main.rs")).unwrap(),
+            "mod y2023;\nmod y2024;\nmod y2025;\n\nmod scaffold;\n\nfn main() {}\n"
+        );
+    }
+
+    #[test]
+    fn create_solver_keeps_other_lines() {
+        let src_dir = create_src_dir();
+        std::fs::write(
+            src_dir.path().join("y2023/mod.rs"),
+            "//! Solvers for 2023.\nmod day1;\nmod day5;\n\npub use day1::parse;\n",
+        )
+        .unwrap();
+        std::fs::write(
+            src_dir.path().join("main.rs"),
+            "// Solver years.\nmod y2023;\n#[allow(dead_code)]\nmod y2025;\nuse std::io;\n\nfn main() {}",
+        )
+        .unwrap();
+
+        create_solver(src_dir.path(), Year(2023), Day(3)).unwrap();
+        create_solver(src_dir.path(), Year(2026), Day(1)).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(src_dir.path().join("y2023/mod.rs")).unwrap(),
+            "//! Solvers for 2023.\nmod day1;\nmod day3;\nmod day5;\n\npub use day1::parse;\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(src_dir.path().join("main.rs")).unwrap(),
+            "// Solver years.\nmod y2023;\n#[allow(dead_code)]\nmod y2025;\nmod y2026;\nuse std::io;\n\nfn main() {}"
+        );
+    }
+
+    #[test]
+    fn create_solver_does_not_overwrite_existing_file() {
+        let src_dir = create_src_dir();
+        let existing_file = src_dir.path().join("y2023/day1.rs");
+        std::fs::write(&existing_file, "existing solver").unwrap();
+
+        assert!(matches!(
+            create_solver(src_dir.path(), Year(2023), Day(1)),
+            Err(ScaffoldError::SolverFileExists(p)) if p == existing_file
+        ));
+        assert_eq!(
+            std::fs::read_to_string(&existing_file).unwrap(),
+            "existing solver"
+        );
+    }
+}
@@ -11,6 +11,10 @@ use crate::SOLVERS;
 #[distributed_slice(SOLVERS)]
 static SOLVER: yt::SolverAutoRegister = yt::SolverAutoRegister {
     modpath: std::module_path!(),
+    name: None,
+    title: None,
+    description: None,
+    skip_submit: false,
     part_one: yt::SolverPart {
         func: day_1_1,
         examples: &[yt::Example {
@@ -46,7 +50,7 @@ pub fn day_1_1(args: &yt::SolverArgs) -> yt::Result<aoc::Answer> {
     // Sum the distance between each pair.
     let mut total_distance = 0;
 
-    for (a, b) in left.into_iter().zip(right.into_iter()) {
+    for (a, b) in left.into_iter().zip(right) {
         let distance = (a - b).abs();
         total_distance += distance;
     }
@@ -11,6 +11,10 @@ use crate::SOLVERS;
 #[distributed_slice(SOLVERS)]
 static SOLVER: yt::SolverAutoRegister = yt::SolverAutoRegister {
     modpath: std::module_path!(),
+    name: None,
+    title: None,
+    description: None,
+    skip_submit: false,
     part_one: yt::SolverPart {
         func: day_10_1,
         examples: &[yt::Example {
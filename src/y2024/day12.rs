@@ -11,6 +11,10 @@ use crate::SOLVERS;
 #[distributed_slice(SOLVERS)]
 static SOLVER: yt::SolverAutoRegister = yt::SolverAutoRegister {
     modpath: std::module_path!(),
+    name: None,
+    title: None,
+    description: None,
+    skip_submit: false,
     part_one: yt::SolverPart {
         func: day_12_1,
         examples: &[yt::Example {
@@ -29,8 +33,9 @@ MMMISSJEEE",
     },
     part_two: yt::SolverPart {
         func: day_12_2,
-        examples: &[yt::Example {
-            input: "RRRRIICCFF
+        examples: &[
+            yt::Example {
+                input: "RRRRIICCFF
 RRRRIICCCF
 VVRRRCCFFF
 VVRCCCJFFF
@@ -40,8 +45,26 @@ VVIIICJJEE
 MIIIIIJJEE
 MIIISIJEEE
 MMMISSJEEE",
-            expected: aoc::Answer::Int(1206),
-        }],
+                expected: aoc::Answer::Int(1206),
+            },
+            yt::Example {
+                input: "EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE",
+                expected: aoc::Answer::Int(236),
+            },
+            yt::Example {
+                input: "AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA",
+                expected: aoc::Answer::Int(368),
+            },
+        ],
     },
 };
 
@@ -187,8 +210,6 @@ pub fn day_12_2(args: &yt::SolverArgs) -> yt::Result<aoc::Answer> {
 
 #[cfg(test)]
 mod tests {
-    use yuletide::SolverArgs;
-
     use super::*;
 
     fn parse(input: &str) -> Grid<Tile> {
@@ -314,23 +335,15 @@ EXXXX
 EEEEE";
         assert_eq!(17, visit_region(Point2::new(0, 0), &mut parse(s)).area);
         assert_eq!(12, visit_region(Point2::new(0, 0), &mut parse(s)).corners);
-        assert_eq!(
-            aoc::Answer::Int(236),
-            day_12_2(&SolverArgs { input: s }).unwrap()
-        );
     }
 
     #[test]
-    fn part_two_example() {
-        let s = "AAAAAA
-AAABBA
-AAABBA
-ABBAAA
-ABBAAA
-AAAAAA";
-        assert_eq!(
-            aoc::Answer::Int(368),
-            day_12_2(&SolverArgs { input: s }).unwrap()
-        );
+    fn part_one_examples() {
+        yt::assert_all_examples!(SOLVER.part_one);
+    }
+
+    #[test]
+    fn part_two_examples() {
+        yt::assert_all_examples!(SOLVER.part_two);
     }
 }
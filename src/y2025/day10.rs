@@ -9,6 +9,10 @@ use crate::SOLVERS;
 #[distributed_slice(SOLVERS)]
 static SOLVER: yt::SolverAutoRegister = yt::SolverAutoRegister {
     modpath: std::module_path!(),
+    name: None,
+    title: None,
+    description: None,
+    skip_submit: false,
     part_one: yt::SolverPart {
         func: day_10_1,
         examples: &[yt::Example {
@@ -25,6 +29,7 @@ static SOLVER: yt::SolverAutoRegister = yt::SolverAutoRegister {
     },
 };
 
+#[allow(dead_code)]
 #[derive(Debug, PartialEq)]
 struct Machine {
     lights: Vec<bool>,
@@ -32,6 +37,7 @@ struct Machine {
     joltages: Vec<usize>,
 }
 
+#[allow(dead_code)]
 fn parse_manual(input: &str) -> Vec<Machine> {
     // [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
     input
@@ -61,7 +67,7 @@ fn parse_manual(input: &str) -> Vec<Machine> {
         .collect::<Vec<_>>()
 }
 
-pub fn day_10_1(args: &yt::SolverArgs) -> yt::Result<aoc::Answer> {
+pub fn day_10_1(_args: &yt::SolverArgs) -> yt::Result<aoc::Answer> {
     Err(yt::SolverError::NotFinished)
 }
 
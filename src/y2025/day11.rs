@@ -14,6 +14,10 @@ const EXAMPLE_INPUT_2: &str = "svr: aaa bbb\naaa: fft\nfft: ccc\nbbb: tty\ntty:
 #[distributed_slice(SOLVERS)]
 static SOLVER: yt::SolverAutoRegister = yt::SolverAutoRegister {
     modpath: std::module_path!(),
+    name: None,
+    title: None,
+    description: None,
+    skip_submit: false,
     part_one: yt::SolverPart {
         func: day_11_1,
         examples: &[yt::Example {
@@ -9,6 +9,10 @@ use crate::SOLVERS;
 #[distributed_slice(SOLVERS)]
 static SOLVER: yt::SolverAutoRegister = yt::SolverAutoRegister {
     modpath: std::module_path!(),
+    name: None,
+    title: None,
+    description: None,
+    skip_submit: false,
     part_one: yt::SolverPart {
         func: day_2_1,
         examples: &[yt::Example {
@@ -35,7 +39,7 @@ fn parse_ranges(input: &str) -> Vec<(usize, usize)> {
 
 fn is_valid_id_p1(id: usize) -> bool {
     let id: Vec<char> = id.to_string().chars().collect();
-    if !id.is_empty() && id.len() % 2 == 0 {
+    if !id.is_empty() && id.len().is_multiple_of(2) {
         let mid: usize = id.len() / 2;
 
         for i in 0..mid {
@@ -60,7 +64,7 @@ fn is_valid_id_p2(id: usize) -> bool {
         // try patterns of size `k` starting from 1 .. (Eg, one digit).
         for k in 1..=mid {
             // skip if this number can't be divided into chunks of size k?
-            if id.len() % k != 0 {
+            if !id.len().is_multiple_of(k) {
                 continue;
             }
 
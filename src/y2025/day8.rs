@@ -11,6 +11,10 @@ const EXAMPLE_INPUT: &str =  "162,817,812\n57,618,57\n906,360,560\n592,479,940\n
 #[distributed_slice(SOLVERS)]
 static SOLVER: yt::SolverAutoRegister = yt::SolverAutoRegister {
     modpath: std::module_path!(),
+    name: None,
+    title: None,
+    description: None,
+    skip_submit: false,
     part_one: yt::SolverPart {
         func: day_8_1,
         examples: &[yt::Example {
@@ -63,7 +67,7 @@ pub fn day_8_1(args: &yt::SolverArgs) -> yt::Result<aoc::Answer> {
     };
 
     for (_dist, a, b) in pairs.into_iter().take(iteration_count) {
-        uf.union(&a, &b);
+        uf.union(a, b);
     }
 
     Ok(uf
@@ -83,7 +87,7 @@ pub fn day_8_2(args: &yt::SolverArgs) -> yt::Result<aoc::Answer> {
     let mut last = 0;
 
     for (_dist, a, b) in pairs.into_iter() {
-        if uf.union(&a, &b).is_some() {
+        if uf.union(a, b) {
             last = a.x * b.x;
         }
     }
@@ -1,6 +1,6 @@
 use advent_of_code_data::{
-    cache::{CacheError, PuzzleCache, PuzzleFsCache},
-    data::{Answers, CheckResult},
+    cache::{CacheError, PuzzleCache, PuzzleFsCache, SessionCache, SessionFsCache},
+    data::{Answers, CheckResult, Puzzle, Session},
     Answer, Day, Part, Year,
 };
 use tempfile::tempdir;
@@ -129,7 +129,7 @@ fn load_input_err_if_wrong_password() {
 
     assert!(matches!(
         read_cached_input(Day(19), Year(2000)),
-        Err(CacheError::Decryption(_))
+        Err(CacheError::Decryption { .. })
     ));
 }
 
@@ -248,7 +248,7 @@ fn load_answers_cache_exists() {
     );
     assert_eq!(
         answers_part_two.check(&Answer::Int(100000)),
-        Some(CheckResult::TooHigh)
+        Some(CheckResult::TooHigh { hint: None })
     );
     assert_eq!(
         answers_part_two.check(&Answer::Int(12345)),
@@ -272,3 +272,145 @@ fn load_answers_returns_none_if_cache_missing() {
     assert!(read_cached_input(Part::One, Day(19), Year(2000)).is_none());
     assert!(read_cached_input(Part::Two, Day(19), Year(2000)).is_none());
 }
+
+#[test]
+fn load_input_error_includes_path_of_corrupt_file() {
+    let cache_dir = tempdir().unwrap();
+    let input_path = PuzzleFsCache::input_file_path(cache_dir.path(), Day(4), Year(2022), true);
+
+    std::fs::create_dir_all(input_path.parent().unwrap()).unwrap();
+    std::fs::write(&input_path, "this is not base64!").unwrap();
+
+    let puzzle_cache = PuzzleFsCache::new(cache_dir.path(), Some("TEST"));
+    let error = puzzle_cache.load_input(Day(4), Year(2022)).unwrap_err();
+
+    assert!(matches!(&error, CacheError::DecodeBase64 { path, .. } if *path == input_path));
+    assert!(error
+        .to_string()
+        .contains(&input_path.display().to_string()));
+}
+
+#[test]
+fn load_answers_error_includes_path_of_unreadable_file() {
+    let cache_dir = tempdir().unwrap();
+    let answers_path =
+        PuzzleFsCache::answers_file_path(cache_dir.path(), Part::One, Day(4), Year(2022));
+
+    // A directory where the answers file should be can't be read as a file.
+    std::fs::create_dir_all(&answers_path).unwrap();
+
+    let puzzle_cache = PuzzleFsCache::new(cache_dir.path(), Some("TEST"));
+    let error = puzzle_cache
+        .load_answers(Part::One, Day(4), Year(2022))
+        .unwrap_err();
+
+    assert!(matches!(&error, CacheError::Io { path, .. } if *path == answers_path));
+    assert!(error
+        .to_string()
+        .contains(&answers_path.display().to_string()));
+}
+
+/// Create a puzzle cache where saving input for day 4 year 2022 fails because a directory exists
+/// where the input file should be.
+fn create_cache_with_unwritable_input(cache_dir: &std::path::Path) -> (PuzzleFsCache, Puzzle) {
+    let input_path = PuzzleFsCache::input_file_path(cache_dir, Day(4), Year(2022), false);
+    std::fs::create_dir_all(&input_path).unwrap();
+
+    let mut answers = Answers::new();
+    answers.set_correct_answer(Answer::Int(12));
+
+    let puzzle = Puzzle {
+        day: Day(4),
+        year: Year(2022),
+        input: "input".to_string(),
+        part_one_answers: answers.clone(),
+        part_two_answers: answers,
+    };
+
+    (PuzzleFsCache::new(cache_dir, None::<String>), puzzle)
+}
+
+#[test]
+fn save_stops_at_first_error() {
+    let cache_dir = tempdir().unwrap();
+    let (puzzle_cache, puzzle) = create_cache_with_unwritable_input(cache_dir.path());
+
+    assert!(matches!(
+        puzzle_cache.save(puzzle),
+        Err(CacheError::Io { .. })
+    ));
+
+    for part in [Part::One, Part::Two] {
+        assert!(
+            !PuzzleFsCache::answers_file_path(cache_dir.path(), part, Day(4), Year(2022)).exists()
+        );
+    }
+}
+
+#[test]
+fn save_all_parts_returns_every_error() {
+    let cache_dir = tempdir().unwrap();
+    let (puzzle_cache, puzzle) = create_cache_with_unwritable_input(cache_dir.path());
+
+    let errors = puzzle_cache.save_all_parts(puzzle);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], CacheError::Io { .. }));
+
+    // Answers are still saved after the input failed to save.
+    for part in [Part::One, Part::Two] {
+        assert!(puzzle_cache
+            .load_answers(part, Day(4), Year(2022))
+            .unwrap()
+            .is_some_and(|a| a.correct_answer_ref().is_some()));
+    }
+}
+
+#[test]
+fn session_file_name_does_not_contain_session_id() {
+    let cache_dir = tempdir().unwrap();
+    let session_cache = SessionFsCache::new(cache_dir.path());
+
+    let mut session = Session::new("secret_session_id");
+    session.submit_wait_until = Some(chrono::Utc::now());
+    session_cache.save(&session).unwrap();
+
+    let file_names: Vec<String> = std::fs::read_dir(cache_dir.path())
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(file_names.len(), 1);
+    assert!(!file_names[0].contains("secret_session_id"));
+
+    let loaded = session_cache
+        .try_load("secret_session_id")
+        .unwrap()
+        .unwrap();
+    assert_eq!(loaded.session_id, "secret_session_id");
+    assert_eq!(loaded.submit_wait_until, session.submit_wait_until);
+    assert!(session_cache
+        .try_load("other_session_id")
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn legacy_session_file_is_migrated_when_loaded() {
+    let cache_dir = tempdir().unwrap();
+    let session_cache = SessionFsCache::new(cache_dir.path());
+    let legacy_path = cache_dir.path().join("old_session.json");
+
+    std::fs::write(
+        &legacy_path,
+        r#"{"session_id":"old_session","submit_wait_until":null}"#,
+    )
+    .unwrap();
+
+    let loaded = session_cache.try_load("old_session").unwrap().unwrap();
+    assert_eq!(loaded.session_id, "old_session");
+
+    assert!(!legacy_path.exists());
+    assert!(session_cache.session_data_filepath("old_session").is_file());
+    assert!(!session_cache
+        .migrate_legacy_session_file("old_session")
+        .unwrap());
+}
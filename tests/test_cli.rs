@@ -0,0 +1,202 @@
+use std::process::Command;
+
+/// Run the solver binary with `args` and return its standard output.
+fn run_cli(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_advent-of-code-rust"))
+        .args(args)
+        .output()
+        .expect("failed to spawn the solver binary");
+
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// A row in the `list` command output.
+#[derive(Debug, PartialEq)]
+struct ListRow {
+    year: usize,
+    day: usize,
+    part_one_examples: bool,
+    part_two_examples: bool,
+}
+
+fn parse_list_output(output: &str) -> Vec<ListRow> {
+    let mut rows = Vec::new();
+    let mut year = None;
+
+    for line in output.lines().filter(|l| !l.trim().is_empty()) {
+        if let Some(y) = line.strip_prefix("Year ") {
+            year = Some(y.parse().unwrap());
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+
+        if fields[0] == "Day" {
+            assert_eq!(
+                &fields[..7],
+                ["Day", "Part", "1", "examples", "Part", "2", "examples"]
+            );
+            continue;
+        }
+
+        let parse_bool = |s: &str| match s {
+            "yes" => true,
+            "no" => false,
+            _ => panic!("expected yes or no but got `{s}`"),
+        };
+
+        rows.push(ListRow {
+            year: year.expect("solver rows must come after a year header"),
+            day: fields[0].parse().unwrap(),
+            part_one_examples: parse_bool(fields[1]),
+            part_two_examples: parse_bool(fields[2]),
+        });
+    }
+
+    rows
+}
+
+#[test]
+fn list_prints_registered_solvers() {
+    let rows = parse_list_output(&run_cli(&["list"]));
+
+    assert!(rows.contains(&ListRow {
+        year: 2024,
+        day: 1,
+        part_one_examples: true,
+        part_two_examples: true,
+    }));
+}
+
+#[test]
+fn list_is_sorted_by_year_and_day() {
+    let rows = parse_list_output(&run_cli(&["list"]));
+    let keys: Vec<_> = rows.iter().map(|r| (r.year, r.day)).collect();
+
+    let mut sorted_keys = keys.clone();
+    sorted_keys.sort();
+
+    assert!(!keys.is_empty());
+    assert_eq!(keys, sorted_keys);
+}
+
+#[test]
+fn list_does_not_need_config_or_cache() {
+    let empty_dir = tempfile::tempdir().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_advent-of-code-rust"))
+        .arg("list")
+        .env_clear()
+        .env("HOME", empty_dir.path())
+        .env("XDG_CONFIG_HOME", empty_dir.path())
+        .env("XDG_CACHE_HOME", empty_dir.path())
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Year 2024"));
+}
+
+#[test]
+fn validate_runs_examples_without_config_or_cache() {
+    let empty_dir = tempfile::tempdir().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_advent-of-code-rust"))
+        .args(["validate", "--year", "2024", "--days", "1"])
+        .env_clear()
+        .env("HOME", empty_dir.path())
+        .env("XDG_CONFIG_HOME", empty_dir.path())
+        .env("XDG_CACHE_HOME", empty_dir.path())
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Validating day 1 year 2024"));
+    assert!(stdout.contains("Part One example 1 passed"));
+    assert!(stdout.contains("Part Two example 1 passed"));
+}
+
+#[test]
+fn validate_fails_for_unknown_day() {
+    let output = Command::new(env!("CARGO_BIN_EXE_advent-of-code-rust"))
+        .args(["validate", "--year", "2024", "--days", "26"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+}
+
+#[test]
+fn part_flag_rejects_invalid_parts() {
+    for command in ["run", "check"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_advent-of-code-rust"))
+            .args([command, "--part", "3"])
+            .output()
+            .unwrap();
+
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("[possible values: 1, 2]"));
+    }
+}
+
+#[test]
+fn day_and_year_flags_reject_invalid_values() {
+    for (args, expected) in [
+        (
+            ["validate", "--days", "0"],
+            "0 is not an Advent of Code day",
+        ),
+        (
+            ["validate", "--days", "26"],
+            "26 is not an Advent of Code day",
+        ),
+        (
+            ["validate", "--year", "2014"],
+            "2014 is not an Advent of Code year",
+        ),
+        (["input", "--day", "x1"], "`x1` is not a number"),
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_advent-of-code-rust"))
+            .args(args)
+            .output()
+            .unwrap();
+
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains(expected));
+    }
+}
+
+#[test]
+fn completions_are_generated_for_supported_shells() {
+    for shell in ["bash", "zsh", "fish", "powershell"] {
+        let completions = run_cli(&["completions", shell]);
+
+        assert!(!completions.is_empty(), "no completions for {shell}");
+        assert!(
+            completions.contains("validate"),
+            "{shell} is missing subcommands"
+        );
+        // Shells spell long flags differently (e.g. `--year` or `-l year`).
+        for flag in ["year", "days", "part"] {
+            assert!(completions.contains(flag), "{shell} is missing --{flag}");
+        }
+    }
+}
+
+#[test]
+fn completions_reject_unknown_shell() {
+    let output = Command::new(env!("CARGO_BIN_EXE_advent-of-code-rust"))
+        .args(["completions", "not-a-shell"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+}
@@ -1,15 +1,14 @@
-use std::{cell::RefCell, path::PathBuf, rc::Rc};
+use std::{cell::RefCell, rc::Rc};
 
+use advent_of_code_data::testing::{FakePuzzleCache, FakeSessionCache};
 use advent_of_code_data::{
     aoc_service::{ServiceConnector, ServiceError},
-    cache::{PuzzleCache, PuzzleFsCache, SessionCache, SessionFsCache},
-    client::{Client, ClientError, WebClient},
+    client::{Client, ClientError, PrefetchStatus, PrefetchSummary, WebClient},
     config::{Config, ConfigBuilder},
     data::{Answers, CheckResult, Session},
     Answer, Day, Part, Year,
 };
 use chrono::Duration;
-use tempfile::{tempdir, TempDir};
 
 const WRONG_ANSWER_WAIT_ONE_MINUTE: &str = "That's not the right answer.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again.";
 const WRONG_ANSWER_WAIT_TWO_MINUTES: &str = "That's not the right answer - please wait 2 minutes";
@@ -17,11 +16,8 @@ const WRONG_ANSWER_WAIT_FIVE_M: &str = "That's not the right answer - You have 5
 const WRONG_ANSWER_WAIT_SIX_M_TEN_S: &str =
     "That's not the right answer - You have 6m 10s left to wait";
 
-fn make_test_config(session: Option<&str>, temp_dir: &TempDir) -> Config {
-    let mut builder = ConfigBuilder::new()
-        .with_passphrase("1234")
-        .with_puzzle_dir(PathBuf::from(temp_dir.path()))
-        .with_sessions_dir(PathBuf::from(temp_dir.path()));
+fn make_test_config(session: Option<&str>) -> Config {
+    let mut builder = ConfigBuilder::new().with_passphrase("1234");
 
     if let Some(session) = session {
         builder = builder.with_session_id(session);
@@ -30,57 +26,72 @@ fn make_test_config(session: Option<&str>, temp_dir: &TempDir) -> Config {
     builder.build().unwrap()
 }
 
-fn get_cached_answers(config: &Config, part: Part, day: Day, year: Year) -> Option<Answers> {
-    let puzzle_cache = PuzzleFsCache::new(
-        config.puzzle_dir.clone(),
-        Some(config.passphrase.to_string()),
-    );
-
-    puzzle_cache.load_answers(part, day, year).unwrap()
-}
-
-fn write_answers(config: &Config, answers: &Answers, part: Part, day: Day, year: Year) {
-    PuzzleFsCache::new(
-        config.puzzle_dir.clone(),
-        Some(config.passphrase.to_string()),
+/// Create a client that uses `service` in place of the Advent of Code website, and empty in-memory
+/// caches.
+fn make_test_client(config: Config, service: TestAdventOfCodeService) -> WebClient {
+    make_test_client_with_caches(
+        config,
+        FakePuzzleCache::new(),
+        FakeSessionCache::new(),
+        service,
     )
-    .save_answers(answers, part, day, year)
-    .unwrap()
 }
 
-fn get_cached_input(config: &Config, day: Day, year: Year) -> Option<String> {
-    let puzzle_cache = PuzzleFsCache::new(
-        config.puzzle_dir.clone(),
-        Some(config.passphrase.to_string()),
-    );
+/// Create a client that uses `service` in place of the Advent of Code website, and the given
+/// in-memory caches.
+fn make_test_client_with_caches(
+    config: Config,
+    puzzle_cache: FakePuzzleCache,
+    session_cache: FakeSessionCache,
+    service: TestAdventOfCodeService,
+) -> WebClient {
+    let mut client = WebClient::with_custom_impl(config, Box::new(service));
+    client.puzzle_cache = Box::new(puzzle_cache);
+    client.session_cache = Box::new(session_cache);
+
+    client
+}
 
-    puzzle_cache.load_input(day, year).unwrap()
+fn get_cached_answers(client: &WebClient, part: Part, day: Day, year: Year) -> Option<Answers> {
+    client.puzzle_cache.load_answers(part, day, year).unwrap()
 }
 
-fn write_input(config: &Config, input: &str, day: Day, year: Year) {
-    PuzzleFsCache::new(
-        config.puzzle_dir.clone(),
-        Some(config.passphrase.to_string()),
-    )
-    .save_input(input, day, year)
-    .unwrap()
+fn get_cached_input(client: &WebClient, day: Day, year: Year) -> Option<String> {
+    client.puzzle_cache.load_input(day, year).unwrap()
 }
 
-fn get_cached_session(config: &Config) -> Option<Session> {
-    SessionFsCache::new(config.sessions_dir.clone())
-        .try_load(config.session_id.as_ref().unwrap())
+fn get_cached_session(client: &WebClient) -> Option<Session> {
+    client
+        .session_cache
+        .try_load(client.config.session_id.as_ref().unwrap())
         .unwrap()
 }
 
-fn write_session(config: &Config, session: &Session) {
-    SessionFsCache::new(config.sessions_dir.clone())
-        .save(session)
-        .unwrap();
-}
+type MockGetInputFn = Box<dyn Fn(Day, Year, &str) -> Result<String, ServiceError>>;
+type MockSubmitAnswerFn =
+    Box<dyn Fn(&Answer, Part, Day, Year, &str) -> Result<String, ServiceError>>;
+type MockGetPuzzlePageFn = Box<dyn Fn(Day, Year, &str) -> Result<String, ServiceError>>;
 
 struct TestAdventOfCodeService {
-    mock_get_input: Box<dyn Fn(Day, Year, &str) -> Result<String, ServiceError>>,
-    mock_submit_answer: Box<dyn Fn(&Answer, Part, Day, Year, &str) -> Result<String, ServiceError>>,
+    mock_get_input: MockGetInputFn,
+    mock_submit_answer: MockSubmitAnswerFn,
+    mock_get_puzzle_page: MockGetPuzzlePageFn,
+}
+
+/// Mocks that a test does not replace panic when they are called, so each test only needs to set up
+/// the service calls it expects.
+impl Default for TestAdventOfCodeService {
+    fn default() -> Self {
+        Self {
+            mock_get_input: Box::new(|_, _, _| unimplemented!("unexpected call to get_input")),
+            mock_submit_answer: Box::new(|_, _, _, _, _| {
+                unimplemented!("unexpected call to submit_answer")
+            }),
+            mock_get_puzzle_page: Box::new(|_, _, _| {
+                unimplemented!("unexpected call to get_puzzle_page")
+            }),
+        }
+    }
 }
 
 impl ServiceConnector for TestAdventOfCodeService {
@@ -98,12 +109,15 @@ impl ServiceConnector for TestAdventOfCodeService {
     ) -> Result<String, ServiceError> {
         (self.mock_submit_answer)(answer, part, day, year, session)
     }
+
+    fn get_puzzle_page(&self, day: Day, year: Year, session: &str) -> Result<String, ServiceError> {
+        (self.mock_get_puzzle_page)(day, year, session)
+    }
 }
 
 #[test]
 fn get_input_calls_endpoint() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
     // Create mock get_input that records parameters.
     struct MockArgs {
@@ -115,9 +129,9 @@ fn get_input_calls_endpoint() {
     let mock_args: Rc<RefCell<Option<MockArgs>>> = Rc::new(RefCell::new(None));
     let mock_args_clone = mock_args.clone();
 
-    let client = WebClient::with_custom_impl(
+    let client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
+        TestAdventOfCodeService {
             mock_get_input: Box::new(move |day, year, session| -> Result<String, ServiceError> {
                 mock_args_clone.replace(Some(MockArgs {
                     day,
@@ -126,8 +140,8 @@ fn get_input_calls_endpoint() {
                 }));
                 Ok("hello world".to_string())
             }),
-            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| unimplemented!()),
-        }),
+            ..Default::default()
+        },
     );
 
     // Get input and validate that the endpoint was called with expected arguments.
@@ -151,17 +165,16 @@ fn get_input_calls_endpoint() {
 
 #[test]
 fn get_input_ok() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
-    let client = WebClient::with_custom_impl(
+    let client = make_test_client(
         config,
-        Box::new(TestAdventOfCodeService {
+        TestAdventOfCodeService {
             mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
                 Ok("hello world".to_string())
             }),
-            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| unimplemented!()),
-        }),
+            ..Default::default()
+        },
     );
 
     assert_eq!(
@@ -172,18 +185,16 @@ fn get_input_ok() {
 
 #[test]
 fn get_input_skips_cache_if_answer_in_cache() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
-    write_input(&config, "testing 123", Day(12), Year(1812));
+    let config = make_test_config(Some("session123"));
+    let puzzle_cache = FakePuzzleCache::builder()
+        .with_input(Day(12), Year(1812), "testing 123")
+        .build();
 
-    let client = WebClient::with_custom_impl(
+    let client = make_test_client_with_caches(
         config,
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
-            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| unimplemented!()),
-        }),
+        puzzle_cache,
+        FakeSessionCache::new(),
+        TestAdventOfCodeService::default(),
     );
 
     assert_eq!(
@@ -194,22 +205,21 @@ fn get_input_skips_cache_if_answer_in_cache() {
 
 #[test]
 fn get_input_writes_to_cache() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
-    let client = WebClient::with_custom_impl(
+    let client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
+        TestAdventOfCodeService {
             mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
                 Ok("hello world".to_string())
             }),
-            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| unimplemented!()),
-        }),
+            ..Default::default()
+        },
     );
 
     // Check that there is no cache prior to calling get_input.
     assert!(
-        get_cached_input(&config, Day(12), Year(1812)).is_none(),
+        get_cached_input(&client, Day(12), Year(1812)).is_none(),
         "there should be no cached input before get_input"
     );
 
@@ -219,7 +229,7 @@ fn get_input_writes_to_cache() {
         "hello world"
     );
 
-    if let Some(input) = get_cached_input(&config, Day(12), Year(1812)) {
+    if let Some(input) = get_cached_input(&client, Day(12), Year(1812)) {
         assert_eq!(&input, "hello world");
     } else {
         panic!("expected answer cache to exist after submit_answer");
@@ -228,105 +238,177 @@ fn get_input_writes_to_cache() {
 
 #[test]
 fn get_input_missing_session_error() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(None, &temp_dir);
+    let config = make_test_config(None);
 
-    let client = WebClient::with_custom_impl(
+    let client = make_test_client(
         config,
-        Box::new(TestAdventOfCodeService {
+        TestAdventOfCodeService {
             mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
                 Err(ServiceError::HttpStatusError(400))
             }),
-            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| unimplemented!()),
-        }),
+            ..Default::default()
+        },
     );
 
-    assert!(matches!(
+    assert_eq!(
         client.get_input(Day(1), Year(2000)),
         Err(ClientError::SessionIdRequired)
-    ));
+    );
 }
 
 #[test]
 fn get_input_invalid_session_error() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
-    let client = WebClient::with_custom_impl(
+    let client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
+        TestAdventOfCodeService {
             mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
                 Err(ServiceError::HttpStatusError(400))
             }),
-            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| unimplemented!()),
-        }),
+            ..Default::default()
+        },
     );
 
-    match client.get_input(Day(1), Year(2000)) {
-        Err(ClientError::BadSessionId(session)) => {
-            assert_eq!(session, config.session_id.unwrap());
-        }
-        x => {
-            panic!("incorrect result {:?}", x);
-        }
-    }
+    assert_eq!(
+        client.get_input(Day(1), Year(2000)),
+        Err(ClientError::BadSessionId(config.session_id.unwrap()))
+    );
 }
 
 #[test]
 fn get_input_not_found_err_if_http_404() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("sssion123"), &temp_dir);
+    let config = make_test_config(Some("sssion123"));
 
-    let client = WebClient::with_custom_impl(
+    let client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
+        TestAdventOfCodeService {
             mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
                 Err(ServiceError::HttpStatusError(404))
             }),
-            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| unimplemented!()),
-        }),
+            ..Default::default()
+        },
     );
 
-    match client.get_input(Day(23), Year(1992)) {
-        Err(ClientError::PuzzleNotFound(day, year)) => {
-            assert_eq!(day, Day(23));
-            assert_eq!(year, Year(1992));
-        }
-        x => {
-            panic!("incorrect result {:?}", x);
-        }
-    }
+    assert_eq!(
+        client.get_input(Day(23), Year(1992)),
+        Err(ClientError::PuzzleNotFound(Day(23), Year(1992)))
+    );
 }
 
 #[test]
 fn get_input_other_http_err() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("sssion123"), &temp_dir);
+    let config = make_test_config(Some("sssion123"));
 
-    let client = WebClient::with_custom_impl(
+    let client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
+        TestAdventOfCodeService {
             mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
                 Err(ServiceError::HttpStatusError(418))
             }),
-            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| unimplemented!()),
-        }),
+            ..Default::default()
+        },
     );
 
-    match client.get_input(Day(23), Year(1992)) {
-        Err(ClientError::ServerHttpError(status_code)) => {
-            assert_eq!(status_code, 418);
-        }
-        x => {
-            panic!("incorrect result {:?}", x);
-        }
-    }
+    assert_eq!(
+        client.get_input(Day(23), Year(1992)),
+        Err(ClientError::ServerHttpError(418))
+    );
+}
+
+/// Create a config that retries requests to an unavailable service without waiting.
+fn make_test_config_without_retry_backoff() -> Config {
+    ConfigBuilder::new()
+        .with_passphrase("1234")
+        .with_session_id("session123")
+        .with_retry_backoff(std::time::Duration::ZERO)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn get_input_retries_when_service_unavailable() {
+    let call_count = Rc::new(RefCell::new(0));
+    let call_count_clone = call_count.clone();
+
+    let client = make_test_client(
+        make_test_config_without_retry_backoff(),
+        TestAdventOfCodeService {
+            mock_get_input: Box::new(move |_day, _year, _session| {
+                *call_count_clone.borrow_mut() += 1;
+
+                if *call_count_clone.borrow() < 3 {
+                    Err(ServiceError::ServiceUnavailable)
+                } else {
+                    Ok("1 2 3".to_string())
+                }
+            }),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(client.get_input(Day(5), Year(2020)).unwrap(), "1 2 3");
+    assert_eq!(*call_count.borrow(), 3);
+}
+
+#[test]
+fn get_input_err_if_service_stays_unavailable() {
+    let call_count = Rc::new(RefCell::new(0));
+    let call_count_clone = call_count.clone();
+
+    let client = make_test_client(
+        make_test_config_without_retry_backoff(),
+        TestAdventOfCodeService {
+            mock_get_input: Box::new(move |_day, _year, _session| {
+                *call_count_clone.borrow_mut() += 1;
+                Err(ServiceError::ServiceUnavailable)
+            }),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        client.get_input(Day(5), Year(2020)),
+        Err(ClientError::ServerHttpError(503))
+    );
+
+    // The first request is retried three times.
+    assert_eq!(*call_count.borrow(), 4);
+}
+
+#[test]
+fn submit_answer_retries_when_service_unavailable() {
+    let call_count = Rc::new(RefCell::new(0));
+    let call_count_clone = call_count.clone();
+
+    let mut client = make_test_client(
+        make_test_config_without_retry_backoff(),
+        TestAdventOfCodeService {
+            mock_submit_answer: Box::new(move |_answer, _part, _day, _year, _session| {
+                *call_count_clone.borrow_mut() += 1;
+
+                if *call_count_clone.borrow() < 2 {
+                    Err(ServiceError::ServiceUnavailable)
+                } else {
+                    Ok("That's the right answer! You are one star closer".to_string())
+                }
+            }),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        client
+            .submit_answer(Answer::Int(42), Part::One, Day(1), Year(2000))
+            .unwrap(),
+        CheckResult::Correct
+    );
+    assert_eq!(*call_count.borrow(), 2);
 }
 
 #[test]
 fn submit_answer_calls_endpoint() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
     // Create mock submit_answer that records parameters.
     struct MockArgs {
@@ -340,12 +422,9 @@ fn submit_answer_calls_endpoint() {
     let mock_args: Rc<RefCell<Option<MockArgs>>> = Rc::new(RefCell::new(None));
     let mock_args_clone = mock_args.clone();
 
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(move |answer, part, day, year, session| {
                 mock_args_clone.replace(Some(MockArgs {
                     answer: answer.clone(),
@@ -357,7 +436,8 @@ fn submit_answer_calls_endpoint() {
 
                 Ok("That's the right answer! You are one star closer".to_string())
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     // Submit the answer and validate that the endpoint was called with expected arguments.
@@ -394,25 +474,22 @@ fn submit_answer_calls_endpoint() {
 
 #[test]
 fn submit_correct_answer() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
     // Create mock submit_answer
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
                 Ok("That's the right answer! You are one star closer".to_string())
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     // Check there is no cached answers before calling submit_answer.
     assert!(
-        get_cached_answers(&config, Part::One, Day(1), Year(2000)).is_none(),
+        get_cached_answers(&client, Part::One, Day(1), Year(2000)).is_none(),
         "there should be no cached answers before submit_answer"
     );
 
@@ -425,7 +502,7 @@ fn submit_correct_answer() {
     );
 
     // Check submit_answer added the submitted answer to the cache.
-    if let Some(answers) = get_cached_answers(&config, Part::One, Day(1), Year(2000)) {
+    if let Some(answers) = get_cached_answers(&client, Part::One, Day(1), Year(2000)) {
         assert!(answers
             .correct_answer_ref()
             .as_ref()
@@ -438,24 +515,21 @@ fn submit_correct_answer() {
 
 #[test]
 fn submit_wrong_answer() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
                 Ok("That's not the right answer".to_string())
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     // Check there is no cached answers before calling submit_answer.
     assert!(
-        get_cached_answers(&config, Part::One, Day(1), Year(2000)).is_none(),
+        get_cached_answers(&client, Part::One, Day(1), Year(2000)).is_none(),
         "there should be no cached answers before submit_answer"
     );
 
@@ -468,7 +542,7 @@ fn submit_wrong_answer() {
     );
 
     // Check submit_answer added the submitted answer to the cache.
-    if let Some(answers) = get_cached_answers(&config, Part::One, Day(1), Year(2000)) {
+    if let Some(answers) = get_cached_answers(&client, Part::One, Day(1), Year(2000)) {
         assert!(answers
             .wrong_answers_ref()
             .first()
@@ -481,24 +555,21 @@ fn submit_wrong_answer() {
 
 #[test]
 fn submit_wrong_answer_too_low() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
                 Ok("<p>Your answer is too low.</p>\n<p>If you're stuck, ".to_string())
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     // Check there is no cached answers before calling submit_answer.
     assert!(
-        get_cached_answers(&config, Part::One, Day(1), Year(2000)).is_none(),
+        get_cached_answers(&client, Part::One, Day(1), Year(2000)).is_none(),
         "there should be no cached answers before submit_answer"
     );
 
@@ -507,11 +578,11 @@ fn submit_wrong_answer_too_low() {
         client
             .submit_answer(Answer::Int(42), Part::One, Day(1), Year(2000))
             .unwrap(),
-        CheckResult::TooLow
+        CheckResult::TooLow { hint: None }
     );
 
     // Check submit_answer added the submitted answer to the cache.
-    if let Some(answers) = get_cached_answers(&config, Part::One, Day(1), Year(2000)) {
+    if let Some(answers) = get_cached_answers(&client, Part::One, Day(1), Year(2000)) {
         assert!(answers.low_bounds_ref().map(|a| a == 42).unwrap_or(false))
     } else {
         panic!("expected answer cache to exist after submit_answer");
@@ -520,24 +591,22 @@ fn submit_wrong_answer_too_low() {
 
 #[test]
 fn submit_uses_answer_cache() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
     // Write a wrong answer to the cache.
     let mut answers = Answers::new();
     answers.add_wrong_answer(Answer::Int(42));
 
-    write_answers(&config, &answers, Part::One, Day(17), Year(2012));
+    let puzzle_cache = FakePuzzleCache::builder()
+        .with_answers(Part::One, Day(17), Year(2012), answers)
+        .build();
 
     // Submit a wrong answer and verify the service backend is never called.
-    let mut client = WebClient::with_custom_impl(
-        config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
-            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| unimplemented!()),
-        }),
+    let mut client = make_test_client_with_caches(
+        config,
+        puzzle_cache,
+        FakeSessionCache::new(),
+        TestAdventOfCodeService::default(),
     );
 
     assert_eq!(
@@ -550,30 +619,31 @@ fn submit_uses_answer_cache() {
 
 #[test]
 fn submit_uses_service_if_cache_missing_answer() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
     // Write a correct answer to the cache.
     let mut answers = Answers::new();
     answers.set_correct_answer(Answer::Int(42));
 
-    write_answers(&config, &answers, Part::One, Day(17), Year(2012));
+    let puzzle_cache = FakePuzzleCache::builder()
+        .with_answers(Part::One, Day(17), Year(2012), answers)
+        .build();
 
     // Submit a correct answer and verify the service backend is called.
     let was_called: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
     let was_called_clone = was_called.clone();
 
-    let mut client = WebClient::with_custom_impl(
-        config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+    let mut client = make_test_client_with_caches(
+        config,
+        puzzle_cache,
+        FakeSessionCache::new(),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(move |_answer, _part, _day, _year, _session| {
                 was_called_clone.replace(true);
                 Ok("That's the right answer! You are one star closer".to_string())
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     assert_eq!(
@@ -587,62 +657,54 @@ fn submit_uses_service_if_cache_missing_answer() {
 
 #[test]
 fn submit_answer_does_not_call_backend_if_timeout_set() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
     // Create a session with timeout in the future.
     let mut session = Session::new(config.session_id.clone().unwrap());
     session.submit_wait_until = Some(config.start_time + Duration::minutes(1));
 
-    write_session(&config, &session);
+    let session_cache = FakeSessionCache::new();
+    session_cache.pre_load_session(session.clone());
 
     // Make sure the client returns an error with the submission timeout inside, and verify that the
     // service endpoint is never contacted.
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client_with_caches(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
-            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| unimplemented!()),
-        }),
+        FakePuzzleCache::new(),
+        session_cache,
+        TestAdventOfCodeService::default(),
     );
 
     match client.submit_answer(Answer::Int(42), Part::One, Day(1), Year(2000)) {
-        Err(ClientError::SubmitTimeOut(timeout)) => {
-            assert_eq!(
-                timeout,
-                session.submit_wait_until.unwrap() - config.start_time
-            );
-        }
-        x => {
-            panic!("incorrect result {:?}", x);
+        Err(ClientError::AnswerSubmitCooldown(wait)) => {
+            assert!(wait > Duration::seconds(59) && wait <= Duration::minutes(1));
         }
+        result => panic!("expected a submit cooldown error, got {result:?}"),
     }
 }
 
 #[test]
 fn submit_answer_proceeds_if_timeout_is_in_the_past() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
     // Create a session with timeout in the future.
     let mut session = Session::new(config.session_id.clone().unwrap());
     session.submit_wait_until = Some(config.start_time - Duration::minutes(1));
 
-    write_session(&config, &session);
+    let session_cache = FakeSessionCache::new();
+    session_cache.pre_load_session(session.clone());
 
     // Submit an answer and verify the backend was called.
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client_with_caches(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        FakePuzzleCache::new(),
+        session_cache,
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
                 Ok("That's not the right answer".to_string())
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     assert_eq!(
@@ -655,19 +717,16 @@ fn submit_answer_proceeds_if_timeout_is_in_the_past() {
 
 #[test]
 fn submit_answer_one_minute_timeout() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
                 Ok(WRONG_ANSWER_WAIT_ONE_MINUTE.to_string())
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     // Check submit_answer returns expected response.
@@ -679,7 +738,7 @@ fn submit_answer_one_minute_timeout() {
     );
 
     // Look for a timeout in the user's session data.
-    let actual_time = get_cached_session(&config)
+    let actual_time = get_cached_session(&client)
         .expect("session must be written after a submit with a timeout")
         .submit_wait_until
         .expect("wait until time must be written after a submit with a timeout");
@@ -694,19 +753,16 @@ fn submit_answer_one_minute_timeout() {
 
 #[test]
 fn submit_answer_multiple_minute_timeout() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
                 Ok(WRONG_ANSWER_WAIT_TWO_MINUTES.to_string())
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     // Check submit_answer returns expected response.
@@ -718,7 +774,7 @@ fn submit_answer_multiple_minute_timeout() {
     );
 
     // Look for a timeout in the user's session data.
-    let actual_time = get_cached_session(&config)
+    let actual_time = get_cached_session(&client)
         .expect("session must be written after a submit with a timeout")
         .submit_wait_until
         .expect("wait until time must be written after a submit with a timeout");
@@ -733,19 +789,16 @@ fn submit_answer_multiple_minute_timeout() {
 
 #[test]
 fn submit_answer_wait_five_m() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
                 Ok(WRONG_ANSWER_WAIT_FIVE_M.to_string())
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     // Check submit_answer returns expected response.
@@ -757,7 +810,7 @@ fn submit_answer_wait_five_m() {
     );
 
     // Look for a timeout in the user's session data.
-    let actual_time = get_cached_session(&config)
+    let actual_time = get_cached_session(&client)
         .expect("session must be written after a submit with a timeout")
         .submit_wait_until
         .expect("wait until time must be written after a submit with a timeout");
@@ -772,19 +825,16 @@ fn submit_answer_wait_five_m() {
 
 #[test]
 fn submit_answer_wait_six_m() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("session123"), &temp_dir);
+    let config = make_test_config(Some("session123"));
 
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
                 Ok(WRONG_ANSWER_WAIT_SIX_M_TEN_S.to_string())
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     // Check submit_answer returns expected response.
@@ -796,7 +846,7 @@ fn submit_answer_wait_six_m() {
     );
 
     // Look for a timeout in the user's session data.
-    let actual_time = get_cached_session(&config)
+    let actual_time = get_cached_session(&client)
         .expect("session must be written after a submit with a timeout")
         .submit_wait_until
         .expect("wait until time must be written after a submit with a timeout");
@@ -811,107 +861,410 @@ fn submit_answer_wait_six_m() {
 
 #[test]
 fn submit_answer_err_if_no_session() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(None, &temp_dir);
+    let config = make_test_config(None);
 
-    let mut client = WebClient::with_custom_impl(
-        config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
-            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| unimplemented!()),
-        }),
-    );
+    let mut client = make_test_client(config.clone(), TestAdventOfCodeService::default());
 
     // Check submit_answer returns expected response.
-    assert!(matches!(
+    assert_eq!(
         client.submit_answer(Answer::Int(42), Part::One, Day(1), Year(2000)),
         Err(ClientError::SessionIdRequired)
-    ));
+    );
 }
 
 #[test]
 fn submit_answer_bad_session_err_if_http_400() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("sssion123"), &temp_dir);
+    let config = make_test_config(Some("sssion123"));
 
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
                 Err(ServiceError::HttpStatusError(400))
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     // Check submit_answer returns expected response.
-    match client.submit_answer(Answer::Int(42), Part::One, Day(1), Year(2000)) {
-        Err(ClientError::BadSessionId(session)) => {
-            assert_eq!(session, config.session_id.unwrap());
-        }
-        x => {
-            panic!("incorrect result {:?}", x);
-        }
-    }
+    assert_eq!(
+        client.submit_answer(Answer::Int(42), Part::One, Day(1), Year(2000)),
+        Err(ClientError::BadSessionId(config.session_id.unwrap()))
+    );
 }
 
 #[test]
 fn submit_answer_not_found_err_if_http_404() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("sssion123"), &temp_dir);
+    let config = make_test_config(Some("sssion123"));
 
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
                 Err(ServiceError::HttpStatusError(404))
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     // Check submit_answer returns expected response.
-    match client.submit_answer(Answer::Int(42), Part::One, Day(23), Year(1992)) {
-        Err(ClientError::PuzzleNotFound(day, year)) => {
-            assert_eq!(day, Day(23));
-            assert_eq!(year, Year(1992));
-        }
-        x => {
-            panic!("incorrect result {:?}", x);
-        }
-    }
+    assert_eq!(
+        client.submit_answer(Answer::Int(42), Part::One, Day(23), Year(1992)),
+        Err(ClientError::PuzzleNotFound(Day(23), Year(1992)))
+    );
 }
 
 #[test]
 fn submit_answer_other_http_err() {
-    let temp_dir = tempdir().unwrap();
-    let config = make_test_config(Some("sssion123"), &temp_dir);
+    let config = make_test_config(Some("sssion123"));
 
-    let mut client = WebClient::with_custom_impl(
+    let mut client = make_test_client(
         config.clone(),
-        Box::new(TestAdventOfCodeService {
-            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
-                unimplemented!()
-            }),
+        TestAdventOfCodeService {
             mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
                 Err(ServiceError::HttpStatusError(418))
             }),
-        }),
+            ..Default::default()
+        },
     );
 
     // Check submit_answer returns expected response.
-    match client.submit_answer(Answer::Int(42), Part::One, Day(23), Year(1992)) {
-        Err(ClientError::ServerHttpError(status_code)) => {
-            assert_eq!(status_code, 418);
+    assert_eq!(
+        client.submit_answer(Answer::Int(42), Part::One, Day(23), Year(1992)),
+        Err(ClientError::ServerHttpError(418))
+    );
+}
+
+#[test]
+fn unexpected_service_errors_are_returned() {
+    let config = make_test_config(Some("sssion123"));
+
+    // A custom connector may return errors that only apply to other requests.
+    let mut client = make_test_client(
+        config.clone(),
+        TestAdventOfCodeService {
+            mock_get_input: Box::new(|_day, _year, _session| -> Result<String, ServiceError> {
+                Err(ServiceError::PuzzleTitleNotFound)
+            }),
+            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
+                Err(ServiceError::PuzzleTitleNotFound)
+            }),
+            ..Default::default()
+        },
+    );
+
+    const MESSAGE: &str = "the puzzle page did not contain a puzzle title";
+
+    assert_eq!(
+        client.get_input(Day(23), Year(1992)),
+        Err(ClientError::UnexpectedServiceError(MESSAGE.to_string()))
+    );
+    assert_eq!(
+        client.submit_answer(Answer::Int(42), Part::One, Day(23), Year(1992)),
+        Err(ClientError::UnexpectedServiceError(MESSAGE.to_string()))
+    );
+}
+
+/// Create a client where every fetched input is recorded in `requests`.
+fn make_recording_client(
+    config: Config,
+    puzzle_cache: FakePuzzleCache,
+    requests: Rc<RefCell<Vec<(Day, Year)>>>,
+) -> WebClient {
+    make_test_client_with_caches(
+        config,
+        puzzle_cache,
+        FakeSessionCache::new(),
+        TestAdventOfCodeService {
+            mock_get_input: Box::new(move |day, year, _session| -> Result<String, ServiceError> {
+                requests.borrow_mut().push((day, year));
+                Ok(format!("input for day {day} year {year}"))
+            }),
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn prefetch_year_fetches_requested_days() {
+    let config = make_test_config(Some("session123"));
+    let requests = Rc::new(RefCell::new(Vec::new()));
+    let client = make_recording_client(config, FakePuzzleCache::new(), requests.clone());
+
+    let mut progress = Vec::new();
+    let summary = client
+        .prefetch_year(Year(2020), Some(&[Day(3), Day(7)]), &mut |day, status| {
+            progress.push((day, status))
+        })
+        .unwrap();
+
+    assert_eq!(
+        *requests.borrow(),
+        vec![(Day(3), Year(2020)), (Day(7), Year(2020))]
+    );
+    assert_eq!(
+        progress,
+        vec![
+            (Day(3), PrefetchStatus::Fetched),
+            (Day(7), PrefetchStatus::Fetched)
+        ]
+    );
+    assert_eq!(
+        summary,
+        PrefetchSummary {
+            fetched: 2,
+            already_cached: 0
         }
-        x => {
-            panic!("incorrect result {:?}", x);
+    );
+
+    assert_eq!(
+        get_cached_input(&client, Day(7), Year(2020)),
+        Some("input for day 7 year 2020".to_string())
+    );
+}
+
+#[test]
+fn prefetch_year_skips_cached_inputs() {
+    let config = make_test_config(Some("session123"));
+    let puzzle_cache = FakePuzzleCache::builder()
+        .with_input(Day(2), Year(2019), "cached")
+        .build();
+
+    let requests = Rc::new(RefCell::new(Vec::new()));
+    let client = make_recording_client(config, puzzle_cache, requests.clone());
+
+    let mut progress = Vec::new();
+    let summary = client
+        .prefetch_year(
+            Year(2019),
+            Some(&[Day(1), Day(2), Day(3)]),
+            &mut |day, status| progress.push((day, status)),
+        )
+        .unwrap();
+
+    assert_eq!(
+        *requests.borrow(),
+        vec![(Day(1), Year(2019)), (Day(3), Year(2019))]
+    );
+    assert_eq!(progress[1], (Day(2), PrefetchStatus::AlreadyCached));
+    assert_eq!(
+        summary,
+        PrefetchSummary {
+            fetched: 2,
+            already_cached: 1
         }
+    );
+}
+
+#[test]
+fn prefetch_year_defaults_to_all_days() {
+    let config = make_test_config(Some("session123"));
+    let requests = Rc::new(RefCell::new(Vec::new()));
+    let client = make_recording_client(config, FakePuzzleCache::new(), requests.clone());
+
+    let summary = client
+        .prefetch_year(Year(2018), None, &mut |_, _| {})
+        .unwrap();
+
+    let expected: Vec<_> = (1..=25).map(|d| (Day(d), Year(2018))).collect();
+    assert_eq!(*requests.borrow(), expected);
+    assert_eq!(summary.fetched, 25);
+}
+
+#[test]
+fn prefetch_year_requires_session() {
+    let config = make_test_config(None);
+    let requests = Rc::new(RefCell::new(Vec::new()));
+    let client = make_recording_client(config, FakePuzzleCache::new(), requests.clone());
+
+    let result = client.prefetch_year(Year(2018), Some(&[Day(1)]), &mut |_, _| {});
+
+    assert_eq!(result, Err(ClientError::SessionIdRequired));
+    assert!(requests.borrow().is_empty());
+}
+
+#[test]
+fn get_puzzle_title_parses_puzzle_page() {
+    let service = TestAdventOfCodeService {
+        mock_get_puzzle_page: Box::new(|day, year, _session| {
+            Ok(format!(
+                "<main>\n<article class=\"day-desc\"><h2>--- Day {day}: Gear Ratios ---</h2><p>You and the Elf eventually reach a gondola lift station in {year}.</p></article>\n</main>"
+            ))
+        }),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        service
+            .get_puzzle_title(Day(3), Year(2023), "session123")
+            .unwrap(),
+        "Gear Ratios"
+    );
+}
+
+#[test]
+fn get_puzzle_title_errors_when_page_has_no_title() {
+    let service = TestAdventOfCodeService {
+        mock_get_puzzle_page: Box::new(|_day, _year, _session| {
+            Ok("<main><p>Please log in.</p></main>".to_string())
+        }),
+        ..Default::default()
+    };
+
+    assert!(matches!(
+        service.get_puzzle_title(Day(3), Year(2023), "session123"),
+        Err(ServiceError::PuzzleTitleNotFound)
+    ));
+}
+
+/// Create a client that caches puzzles and sessions on disk under `cache_dir`, as if it were a
+/// new process using the same cache directory as previous runs.
+fn make_test_client_with_cache_dir(
+    cache_dir: &std::path::Path,
+    start_time: chrono::DateTime<chrono::Utc>,
+    service: TestAdventOfCodeService,
+) -> WebClient {
+    let config = ConfigBuilder::new()
+        .with_passphrase("1234")
+        .with_session_id("session123")
+        .with_puzzle_dir(cache_dir.join("puzzles"))
+        .with_sessions_dir(cache_dir.join("sessions"))
+        .with_fake_time(start_time)
+        .build()
+        .unwrap();
+
+    WebClient::with_custom_impl(config, Box::new(service))
+}
+
+#[test]
+fn submit_timeout_is_enforced_after_restart() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let start_time = chrono::Utc::now();
+
+    // Submit a wrong answer that starts a two minute timeout.
+    {
+        let mut client = make_test_client_with_cache_dir(
+            cache_dir.path(),
+            start_time,
+            TestAdventOfCodeService {
+                mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
+                    Ok(WRONG_ANSWER_WAIT_TWO_MINUTES.to_string())
+                }),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            client.submit_answer(Answer::Int(42), Part::One, Day(1), Year(2000)),
+            Ok(CheckResult::Wrong)
+        );
+    }
+
+    // A new client using the same cache directory must refuse to submit until the timeout
+    // expires, without contacting the service.
+    let mut client = make_test_client_with_cache_dir(
+        cache_dir.path(),
+        start_time,
+        TestAdventOfCodeService {
+            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
+                panic!("the service must not be called while the submit timeout is active")
+            }),
+            ..Default::default()
+        },
+    );
+
+    match client.submit_answer(Answer::Int(43), Part::One, Day(1), Year(2000)) {
+        Err(ClientError::AnswerSubmitCooldown(wait)) => {
+            // Each client's clock starts at `start_time` when it is created, so allow for the
+            // time the first client spent before saving the timeout.
+            assert!(
+                wait > Duration::minutes(1) && wait <= Duration::minutes(2) + Duration::seconds(1)
+            );
+        }
+        result => panic!("expected a submit cooldown error, got {result:?}"),
     }
+
+    // Answers that are already known are still checked with the cache.
+    assert_eq!(
+        client.submit_answer(Answer::Int(42), Part::One, Day(1), Year(2000)),
+        Ok(CheckResult::Wrong)
+    );
+}
+
+#[test]
+fn submit_timeout_is_measured_from_current_time() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let start_time = chrono::Utc::now() - Duration::hours(1);
+
+    let mut client = make_test_client_with_cache_dir(
+        cache_dir.path(),
+        start_time,
+        TestAdventOfCodeService {
+            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
+                Ok(WRONG_ANSWER_WAIT_ONE_MINUTE.to_string())
+            }),
+            ..Default::default()
+        },
+    );
+
+    // Time keeps moving after the client is created, so the timeout must end later than one
+    // minute after the client's start time.
+    std::thread::sleep(std::time::Duration::from_millis(50));
+
+    client
+        .submit_answer(Answer::Int(42), Part::One, Day(1), Year(2000))
+        .unwrap();
+
+    let wait_until = get_cached_session(&client)
+        .unwrap()
+        .submit_wait_until
+        .unwrap();
+
+    assert!(wait_until >= start_time + Duration::minutes(1) + Duration::milliseconds(50));
+}
+
+#[test]
+fn expired_submit_timeout_is_removed_after_restart() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let start_time = chrono::Utc::now();
+
+    {
+        let mut client = make_test_client_with_cache_dir(
+            cache_dir.path(),
+            start_time,
+            TestAdventOfCodeService {
+                mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
+                    Ok(WRONG_ANSWER_WAIT_TWO_MINUTES.to_string())
+                }),
+                ..Default::default()
+            },
+        );
+
+        client
+            .submit_answer(Answer::Int(42), Part::One, Day(1), Year(2000))
+            .unwrap();
+        assert!(get_cached_session(&client)
+            .unwrap()
+            .submit_wait_until
+            .is_some());
+    }
+
+    // A client started after the timeout has expired submits to the service, and removes the
+    // expired timeout from the session cache.
+    let mut client = make_test_client_with_cache_dir(
+        cache_dir.path(),
+        start_time + Duration::minutes(3),
+        TestAdventOfCodeService {
+            mock_submit_answer: Box::new(|_answer, _part, _day, _year, _session| {
+                Ok("<p>That's the right answer!</p>".to_string())
+            }),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        client.submit_answer(Answer::Int(43), Part::One, Day(1), Year(2000)),
+        Ok(CheckResult::Correct)
+    );
+    assert_eq!(get_cached_session(&client).unwrap().submit_wait_until, None);
 }
@@ -1,6 +1,9 @@
 use std::{io::Write, path::PathBuf, str::FromStr};
 
-use advent_of_code_data::config::{read_config_from_env_vars, read_config_from_file};
+use advent_of_code_data::{
+    client::{ClientError, WebClient},
+    config::{read_config_from_env_vars, read_config_from_file, ConfigBuilder},
+};
 use tempfile::NamedTempFile;
 
 #[test]
@@ -50,3 +53,47 @@ fn config_from_toml_file() {
     );
     assert_eq!(config.passphrase, Some("foobar".to_string()));
 }
+
+#[test]
+fn strict_client_ignores_env_vars() {
+    std::env::set_var("AOC_SESSION", "my_session");
+
+    let result = WebClient::with_config_strict(ConfigBuilder::new().with_passphrase("my_password"));
+    assert_eq!(result.err(), Some(ClientError::SessionIdRequired));
+}
+
+#[test]
+fn strict_client_requires_passphrase() {
+    let result = WebClient::with_config_strict(ConfigBuilder::new().with_session_id("my_session"));
+    assert!(matches!(result, Err(ClientError::SettingsError(_))));
+}
+
+#[test]
+fn strict_client_with_all_settings() {
+    let client = WebClient::with_config_strict(
+        ConfigBuilder::new()
+            .with_session_id("my_session")
+            .with_passphrase("my_password"),
+    )
+    .unwrap();
+
+    assert_eq!(client.config.session_id, Some("my_session".to_string()));
+    assert_eq!(&client.config.passphrase, "my_password");
+}
+
+#[test]
+fn strict_client_with_settings_from_profile() {
+    let mut builder = ConfigBuilder::new().with_profile("work");
+    builder.profiles.insert(
+        "work".to_string(),
+        ConfigBuilder::new()
+            .with_session_id("work_session")
+            .with_passphrase("work_password"),
+    );
+
+    let client = WebClient::with_config_strict(builder).unwrap();
+
+    assert_eq!(client.config.session_id, Some("work_session".to_string()));
+    assert_eq!(&client.config.passphrase, "work_password");
+    assert_eq!(client.config.profile, Some("work".to_string()));
+}